@@ -0,0 +1,64 @@
+#ifndef DENT_H
+#define DENT_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit. */
+
+#include <stddef.h>
+
+// The outcome of a call, `DENT_STATUS_OK` on success, or else the error.
+typedef enum DentStatus {
+  DENT_STATUS_OK = 0,
+  // A pointer argument was null.
+  DENT_STATUS_NULL_POINTER,
+  DENT_STATUS_EMPTY_SAMPLE,
+  DENT_STATUS_NON_FINITE,
+  DENT_STATUS_UNDEFINED,
+  // Any other error of the statistic.
+  DENT_STATUS_FAILED,
+} DentStatus;
+
+// The summary statistics of a sample, as `summary::Summary`.
+typedef struct DentSummary {
+  size_t size;
+  double min;
+  double min_adjacent;
+  double lower_quartile;
+  double median;
+  double upper_quartile;
+  double max_adjacent;
+  double max;
+  double mean;
+  double unbiased_variance;
+  double standard_deviation;
+  double standard_error;
+} DentSummary;
+
+// The results of a t-test, as `t_test::TTest`.
+typedef struct DentTTest {
+  double p;
+  double t;
+  double df;
+} DentTTest;
+
+// Summarize the `len` values at `data`, writing the summary to `out`.
+//
+// # Safety
+//
+// `data` must point to `len` readable values, unless `len` is 0, and `out`
+// to a writable `DentSummary`.
+enum DentStatus dent_summary(const double *data, size_t len, struct DentSummary *out);
+
+// Conduct Welch's t-test of two summaries, writing the results to `out`.
+//
+// # Safety
+//
+// `s1` and `s2` must point to readable summaries, and `out` to a writable
+// `DentTTest`.
+enum DentStatus dent_welch_t_test(const struct DentSummary *s1,
+                                  const struct DentSummary *s2,
+                                  struct DentTTest *out);
+
+// A description of `status`, as a static, null-terminated string.
+const char *dent_status_message(enum DentStatus status);
+
+#endif  /* DENT_H */
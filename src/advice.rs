@@ -0,0 +1,144 @@
+use anova::{Anova, brown_forsythe};
+use error::Error;
+use fmt;
+use normality::{ShapiroWilk, shapiro_wilk};
+use summary::Summarizer;
+
+
+/// A test of a difference between two samples.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Test {
+    Welch,
+    MannWhitney,
+    Permutation,
+}
+
+impl Test {
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Test::Welch => "Welch's t-test",
+            Test::MannWhitney => "Mann-Whitney U test",
+            Test::Permutation => "Permutation test",
+        }
+    }
+}
+
+/// Samples of at least this size have means near enough to normally
+/// distributed, by the central limit theorem, for a t-test to be robust to
+/// non-normal data.
+pub const LARGE_SAMPLE: usize = 30;
+
+/// The checks of the assumptions of tests of two samples, and the test they
+/// lead to.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Advice {
+    pub test: Test,
+
+    /// The sizes of the samples.
+    pub sizes: [usize; 2],
+
+    /// The Shapiro-Wilk test of each sample, unless it has too few values or
+    /// no variation.
+    pub normality: [Option<ShapiroWilk>; 2],
+
+    /// The Brown-Forsythe test of equal variances, unless it is undefined.
+    pub variances: Option<Anova>,
+
+    /// The significance level of the checks.
+    pub alpha: f64,
+}
+
+impl Advice {
+    /// Whether sample `i` is consistent with a normal distribution.
+    fn is_normal(&self, i: usize) -> bool {
+        self.normality[i].as_ref().is_some_and(|sw| sw.p >= self.alpha)
+    }
+
+    /// Explain the choice of test in a sentence, naming the samples `names`.
+    pub fn explain(&self, names: [&str; 2]) -> String {
+        let p = |p: f64| fmt::stat(p, Some(2));
+
+        if let Some(i) = (0..2).find(|&i| self.sizes[i] < 3) {
+            return format!("{} has fewer than 3 values, too few to check for normality, so a \
+                            permutation test, which assumes no distribution, is used",
+                           names[i]);
+        }
+
+        if self.is_normal(0) && self.is_normal(1) {
+            let (a, b) = (self.normality[0].as_ref(), self.normality[1].as_ref());
+            return format!("Both samples are consistent with a normal distribution \
+                            (Shapiro-Wilk p = {} and {}), so Welch's t-test applies, without \
+                            assuming equal variances",
+                           p(a.map_or(1.0, |sw| sw.p)), p(b.map_or(1.0, |sw| sw.p)));
+        }
+
+        let non_normal: Vec<String> = (0..2)
+            .filter(|&i| !self.is_normal(i))
+            .map(|i| match self.normality[i] {
+                Some(ref sw) => format!("{} (Shapiro-Wilk p = {})", names[i], p(sw.p)),
+                None => format!("{} (without variation)", names[i]),
+            })
+            .collect();
+        let non_normal = format!("{} {} not consistent with a normal distribution",
+                                 non_normal.join(" and "),
+                                 if non_normal.len() > 1 { "are" } else { "is" });
+
+        match self.test {
+            Test::Welch => format!("{}, but both samples have at least {} values, enough for \
+                                    Welch's t-test to be robust",
+                                   non_normal, LARGE_SAMPLE),
+            Test::MannWhitney => format!("{}, and the samples are too small for a t-test to be \
+                                          robust, but their spreads are similar \
+                                          (Brown-Forsythe p = {}), so the rank-based \
+                                          Mann-Whitney U test applies",
+                                         non_normal,
+                                         p(self.variances.as_ref().map_or(1.0, |v| v.p))),
+            Test::Permutation => {
+                let spread = match self.variances {
+                    Some(ref v) => format!("their spreads differ (Brown-Forsythe p = {})", p(v.p)),
+                    None => "their spreads cannot be compared".to_string(),
+                };
+
+                format!("{}, the samples are too small for a t-test to be robust, and {}, \
+                         which a rank test would confound with a shift, so a permutation test \
+                         of the difference in means is used",
+                        non_normal, spread)
+            },
+        }
+    }
+}
+
+/// Check the normality, equality of variances, and sizes of two samples at
+/// significance level `alpha`, and choose a test of their difference:
+///
+/// - Welch's t-test, if both samples are consistent with normality, or both
+///   are large enough for the t-test to be robust without it.
+/// - The Mann-Whitney U test, if either is not normal and they are small, but
+///   their spreads are similar.
+/// - A permutation test otherwise, or if either has too few values to check.
+pub fn advise(s1: &Summarizer, s2: &Summarizer, alpha: f64) -> Result<Advice, Error> {
+    if !(alpha > 0.0 && alpha < 1.0) {
+        return Err(Error::Undefined);
+    }
+
+    let sizes = [s1.as_slice().len(), s2.as_slice().len()];
+    let normality = [shapiro_wilk(s1).ok(), shapiro_wilk(s2).ok()];
+    let variances = brown_forsythe(&[s1, s2]).ok();
+
+    let mut advice = Advice { test: Test::Permutation, sizes, normality, variances, alpha };
+
+    advice.test = if sizes.iter().any(|&n| n < 3) {
+        Test::Permutation
+    } else if (advice.is_normal(0) && advice.is_normal(1))
+        || sizes.iter().all(|&n| n >= LARGE_SAMPLE) {
+        Test::Welch
+    } else if advice.variances.as_ref().is_some_and(|v| v.p >= alpha) {
+        Test::MannWhitney
+    } else {
+        Test::Permutation
+    };
+
+    Ok(advice)
+}
@@ -0,0 +1,101 @@
+use std;
+
+use dist::{Distribution, FisherF};
+use error::Error;
+use fmt;
+use summary::{Summarizer, Summary};
+
+
+/// The results and parameters of a one-way analysis of variance.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Anova {
+    pub f: f64,
+    pub df_between: f64,
+    pub df_within: f64,
+    pub p: f64,
+}
+
+/// Formats the results as `dent` prints them, one per line. A precision, as
+/// in `{:.3}`, gives the number of significant digits of each.
+impl std::fmt::Display for Anova {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+
+        writeln!(f, "{:>w$} = {}", "F", fmt::stat(self.f, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "DF between", fmt::stat(self.df_between, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "DF within", fmt::stat(self.df_within, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "p", fmt::stat(self.p, p), w = w)
+    }
+}
+
+/// Conduct a one-way analysis of variance, testing the null hypothesis that
+/// each sample was drawn from a population with the same mean [1]. The samples
+/// are assumed to be normally distributed with equal population variances.
+///
+/// [1]: http://www.itl.nist.gov/div898/handbook/prc/section4/prc43.htm
+pub fn one_way_anova(summaries: &[&Summary]) -> Result<Anova, Error> {
+    if summaries.len() < 2 {
+        return Err(Error::Undefined);
+    }
+
+    let k = summaries.len() as f64;
+    let n: f64 = summaries.iter().map(|s| s.size()).sum();
+
+    let grand_mean = summaries
+        .iter()
+        .map(|s| s.size() * s.mean())
+        .sum::<f64>() / n;
+
+    let ss_between: f64 = summaries
+        .iter()
+        .map(|s| s.size() * (s.mean() - grand_mean).powi(2))
+        .sum();
+
+    // A sample of size 1 contributes nothing to the within-group sum of
+    // squares, but its variance is undefined, so skip it explicitly.
+    let ss_within: f64 = summaries
+        .iter()
+        .filter(|s| s.size() > 1.0)
+        .map(|s| (s.size() - 1.0) * s.unbiased_variance())
+        .sum();
+
+    let df_between = k - 1.0;
+    let df_within = n - k;
+
+    if df_within < 1.0 {
+        return Err(Error::Undefined);
+    }
+
+    let f = (ss_between / df_between) / (ss_within / df_within);
+
+    if !f.is_finite() {
+        return Err(Error::Undefined);
+    }
+
+    let p = FisherF::new(df_between, df_within)?.sf(f)?;
+
+    Ok(Anova { f, df_between, df_within, p })
+}
+
+/// Test the null hypothesis that each sample was drawn from a population with
+/// the same variance, by the Brown-Forsythe test [1]: a one-way analysis of
+/// variance of the absolute deviations of each sample from its median. Unlike
+/// an F-test of variances, it is robust to non-normal data.
+///
+/// [1]: https://www.itl.nist.gov/div898/handbook/eda/section3/eda35a.htm
+pub fn brown_forsythe(samples: &[&Summarizer]) -> Result<Anova, Error> {
+    let deviations = samples
+        .iter()
+        .map(|s| {
+            let median = s.median();
+            let d: Vec<f64> = s.as_slice().iter().map(|x| (x - median).abs()).collect();
+
+            Summary::new(&d)
+        })
+        .collect::<Result<Vec<Summary>, Error>>()?;
+    let refs: Vec<&Summary> = deviations.iter().collect();
+
+    one_way_anova(&refs)
+}
@@ -0,0 +1,63 @@
+use dent::summary::{Summarizer, Summary};
+
+use std::error;
+use std::io::Read;
+
+use field::Field;
+use input;
+use json::{self, Value};
+
+
+/// A summary of a sample saved earlier, to which later samples are compared.
+pub struct Baseline {
+    pub value: Value,
+    pub unit: Option<String>,
+    pub summary: Summary,
+}
+
+/// Render a summary of a sample as a baseline file, with the unit of its data,
+/// if known. Only the statistics of the sample are saved, not its data.
+pub fn render(source: &str, s: &Summarizer, unit: Option<&str>) -> String {
+    let mut fields = vec![];
+    fields.extend(unit.map(|u| ("unit", Value::String(u.to_string()))));
+    fields.push(("baseline", json::summary(source, s, &Field::all_defaults())));
+
+    json::object(fields).render()
+}
+
+/// Read a baseline file written by `render`.
+pub fn load(path: &str) -> Result<Baseline, Box<dyn error::Error>> {
+    let mut text = String::new();
+    input::open_file(path)?.read_to_string(&mut text)?;
+    let file = json::parse(&text)?;
+
+    let value = file
+        .get("baseline")
+        .ok_or_else(|| format!("Expected a \"baseline\" summary in {:?}", path))?;
+    let stat = |f: Field| {
+        value
+            .get(&f.key())
+            .and_then(Value::as_f64)
+            .ok_or_else(|| format!("Expected the {} of the baseline in {:?}", f.key(), path))
+    };
+
+    let size = stat(Field::Size)?;
+    if size.fract() != 0.0 || size < 1.0 {
+        return Err(format!("Invalid size of the baseline in {:?}: {}", path, size).into());
+    }
+
+    let summary = Summary::from_parts(
+        size as usize,
+        stat(Field::Mean)?,
+        stat(Field::Variance)?,
+        (stat(Field::Min)?, stat(Field::Max)?),
+        (stat(Field::MinAdjacent)?, stat(Field::MaxAdjacent)?),
+        (stat(Field::LowerQuartile)?, stat(Field::Median)?, stat(Field::UpperQuartile)?),
+    )?;
+
+    Ok(Baseline {
+        value: value.clone(),
+        unit: file.get("unit").and_then(Value::as_str).map(String::from),
+        summary,
+    })
+}
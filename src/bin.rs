@@ -1,20 +1,86 @@
 #[macro_use] extern crate clap;
+#[cfg(feature = "tui")] extern crate crossterm;
 extern crate dent;
+#[cfg(feature = "gzip")] extern crate flate2;
+#[cfg(unix)] extern crate libc;
+#[cfg(feature = "mmap")] extern crate memmap2;
+#[cfg(feature = "parallel")] extern crate rayon;
 extern crate term;
 extern crate term_size;
+#[cfg(feature = "zstd")] extern crate zstd;
 
-use clap::{App, Arg};
-use dent::plot;
-use dent::summary::Summary;
-use dent::t_test::{TTest, welch_t_test};
+use clap::ArgMatches;
+use dent::advice::{Test, advise};
+use dent::anova::{Anova, one_way_anova};
+use dent::bland_altman::BlandAltman;
+use dent::effect_size::{cliffs_delta, cohens_d};
+use dent::fit::{Fit, fit_all};
+use dent::frequency::frequencies;
+use dent::fmt;
+use dent::change::{CONFIDENCE, Change, geometric_mean_ratio, harmonic_mean_change, mean_change,
+                   median_change};
+use dent::changepoint::changepoints;
+use dent::dist::{Distribution, Exponential, LogNormal, Normal, Uniform};
+use dent::histogram::{self, Histogram};
+use dent::kappa::{ConfusionMatrix, Weights, cohens_kappa};
+use dent::lr::LinearRegression;
+use dent::mann_whitney::{MannWhitney, hodges_lehmann, mann_whitney};
+use dent::non_inferiority::{self, Margin, NonInferiority};
+use dent::permutation::{Permutation, RESAMPLES, permutation_test};
+use dent::plot::{self, PlotOptions};
+use dent::random::Rng;
+use dent::roc::Roc;
+use dent::summary::{Summarizer, Summary};
+use dent::survival::{KaplanMeier, log_rank};
+use dent::t_test::{Alternative, TTest, paired_t_test, regression_t_test, student_t_test,
+                    welch_t_test};
 
+use std::collections::BTreeMap;
+use std::env;
 use std::error;
 use std::fs::File;
-use std::io::{self, BufRead, BufReader};
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-mod fmt;
+use field::Field;
+use slo::Target;
+use table::{Align, Table};
+use transform::Transform;
+
+mod baseline;
+mod cli;
+mod color;
+mod config;
+mod delimited;
+mod field;
+mod import;
+mod input;
+mod json;
 mod log;
+mod long;
+mod markdown;
+mod ministat;
+mod progress;
+mod report;
+mod runner;
+mod slo;
+mod table;
+mod timestamp;
+mod transform;
+#[cfg(feature = "tui")] mod tui;
+mod unit;
+
 
+/// Exit status on error, distinct from that of a significant difference with
+/// `--fail-on-reject` or `--max-regression`.
+const ERROR_STATUS: i32 = 2;
+
+/// Whether a test has rejected its null hypothesis.
+static REJECTED: AtomicBool = AtomicBool::new(false);
 
 macro_rules! ok {
     ($r: expr) => {
@@ -22,313 +88,2864 @@ macro_rules! ok {
             Ok(t) => t,
             Err(e) => {
                 log::error(&format!("{}", e));
-                std::process::exit(1);
+                std::process::exit(ERROR_STATUS);
             }
         }
     }
 }
 
-fn print_summary(s: &Summary, outliers: bool) {
+/// Note whether a test with p-value `p` rejects its null hypothesis at level
+/// `alpha`, to decide the exit status with `--fail-on-reject`.
+fn decide(p: f64, alpha: f64) {
+    if p < alpha {
+        REJECTED.store(true, Ordering::Relaxed);
+    }
+}
+
+fn welch(s1: &Summary, s2: &Summary, alternative: Alternative, alpha: f64) -> TTest {
+    let t_test = ok!(welch_t_test(s1, s2).and_then(|t| t.with_alternative(alternative)));
+    decide(t_test.p, alpha);
+
+    t_test
+}
+
+fn one_way(summaries: &[&Summary], alpha: f64) -> Anova {
+    let anova = ok!(one_way_anova(summaries));
+    decide(anova.p, alpha);
+
+    anova
+}
+
+fn print_summary(s: &Summarizer, fields: &[Field], precision: Option<usize>) {
     let width = 10;
     let size_width = 6;
 
-    if outliers {
-        println!(
-            "{n:>nw$}  {min:>w$}  {q1:>w$}  {med:>w$}  {q3:>w$}  {max:>w$}  {mean:>w$}  {std:>w$}",
-            w = width,
-            nw = size_width,
-            n = "Size",
-            min = "Min",
-            q1 = "Q1",
-            med = "Median",
-            q3 = "Q3",
-            max = "Max",
-            mean = "Mean",
-            std = "Std Dev",
-        );
-        println!(
-            "{n:>nw$}  {min:>w$}  {q1:>w$}  {med:>w$}  {q3:>w$}  {max:>w$}  {mean:>w$}  {std:>w$}",
-            w = width,
-            nw = size_width,
-            n = fmt::f(s.size(), width),
-            min = fmt::f(s.min(), width),
-            q1 = fmt::f(s.lower_quartile(), width),
-            med = fmt::f(s.median(), width),
-            q3 = fmt::f(s.upper_quartile(), width),
-            max = fmt::f(s.max(), width),
-            mean = fmt::f(s.mean(), width),
-            std = fmt::f(s.standard_deviation(), width),
-        );
-    } else {
-        println!(
-            "{n:>nw$}  {min:>w$}  {q1:>w$}  {med:>w$}  {q3:>w$}  {max:>w$}  {mean:>w$}  {std:>w$}",
-            w = width,
-            nw = size_width,
-            n = "Size",
-            min = "Min Adj",
-            q1 = "Q1",
-            med = "Median",
-            q3 = "Q3",
-            max = "Max Adj",
-            mean = "Mean",
-            std = "Std Dev",
-        );
-        println!(
-            "{n:>nw$}  {min:>w$}  {q1:>w$}  {med:>w$}  {q3:>w$}  {max:>w$}  {mean:>w$}  {std:>w$}",
-            w = width,
-            nw = size_width,
-            n = fmt::f(s.size(), width),
-            min = fmt::f(s.min_adjacent(), width),
-            q1 = fmt::f(s.lower_quartile(), width),
-            med = fmt::f(s.median(), width),
-            q3 = fmt::f(s.upper_quartile(), width),
-            max = fmt::f(s.max_adjacent(), width),
-            mean = fmt::f(s.mean(), width),
-            std = fmt::f(s.standard_deviation(), width),
-        );
-    }
-}
-
-fn print_t_test(t_test: &TTest, s1: &Summary, s2: &Summary) {
+    let mut t = fields.iter().fold(Table::new(), |t, f| {
+        t.column(&f.title(), Align::Right, if *f == Field::Size { size_width } else { width })
+    });
+    t.row(fields.iter().map(|f| fmt::cell(f.value(s), width, precision)).collect());
+
+    print!("{}", t.text());
+}
+
+/// Print a paired t-test, given the summary of the differences `b - a`.
+fn print_paired_t_test(t_test: &TTest, diffs: &Summary, display: &Display) {
     let width = 12;
+    let f = |x| fmt::stat(x, display.precision);
 
-    let m1 = s1.mean();
-    let m2 = s2.mean();
-    let se1 = s1.standard_error();
-    let se2 = s1.standard_error();
+    println!("{l:>w$} = {v}", w = width, l = "n", v = f(diffs.size()));
+    println!("{l:>w$} = {v} ± {se}", w = width, l = "m₂ - m₁ ± SE",
+             v = f(diffs.mean()), se = f(diffs.standard_error()));
+    print_t_statistics(t_test, width, display);
+}
+
+fn print_t_test(t_test: &TTest, s1: &Summary, s2: &Summary, display: &Display) {
+    let width = 12;
 
-    let del = m2 - m1;
-    let se_del = (se1.powi(2) + se1.powi(2)).sqrt();
+    print_estimates("m", (s1.mean(), s1.standard_error()), (s2.mean(), s2.standard_error()),
+                    width, display.precision);
+    print_t_statistics(t_test, width, display);
 
-    println!("{l:>w$} = {v} ± {se}", w = width, l = "m₁ ± SE", v = m1, se = se1);
-    println!("{l:>w$} = {v} ± {se}", w = width, l = "m₂ ± SE", v = m2, se = se2);
-    println!("{l:>w$} = {v} ± {se}", w = width, l = "m₂ - m₁ ± SE", v = del, se = se_del);
-    println!("{l:>w$} = {v}", w = width, l = "p", v = t_test.p);
-    println!("{l:>w$} = {v}", w = width, l = "t", v = t_test.t);
-    println!("{l:>w$} = {v}", w = width, l = "DF", v = t_test.df);
+    let (mean, median) = changes(s1, s2, display.level);
+    print_changes("Δ mean", &mean, &median, width, display.precision);
 }
 
-fn summarize_file(path: &str, lax_parsing: bool) -> Result<Summary, Box<error::Error>> {
-    let f = File::open(path).or_else(|e| {
-        log::error(&format!("Could not open file: {:?}", path));
-        Err(e)
-    })?;
-    let reader = BufReader::new(f);
+/// Print a t-test of rates `s1` and `s2` by their harmonic means, the work
+/// over their mean durations, which it tests as the means of the reciprocals.
+fn print_rate_t_test(t_test: &TTest, s1: &Summarizer, s2: &Summarizer, display: &Display) {
+    let width = 12;
+    // By the delta method, the standard error of the reciprocal of a mean m
+    // is se/m².
+    let harmonic = |s: &Summarizer| {
+        let r = Summary::from(&ok!(s.reciprocals()));
+        (r.mean().recip(), r.standard_error() / r.mean().powi(2))
+    };
 
-    let data = read_data(reader, lax_parsing)?;
+    println!("T-test of the harmonic means, the work over the mean durations:");
+    print_estimates("h", harmonic(s1), harmonic(s2), width, display.precision);
+    print_t_statistics(t_test, width, display);
 
-    Ok(Summary::new(&data)?)
+    let hmean = ok!(harmonic_mean_change(s1, s2, display.level));
+    let median = ok!(median_change(&Summary::from(s1), &Summary::from(s2), display.level));
+    print_changes("Δ hmean", &hmean, &median, width, display.precision);
 }
 
-fn read_data<R>(reader: R, lax_parsing: bool) -> Result<Vec<f64>, Box<error::Error>>
-    where R: BufRead {
-    let mut data: Vec<f64> = vec![];
+/// Print two estimates of a statistic named `x`, such as "m" for means, and
+/// their difference, each with its standard error.
+fn print_estimates(x: &str, (x1, se1): (f64, f64), (x2, se2): (f64, f64), width: usize,
+                   precision: Option<usize>) {
+    let f = |x| fmt::stat(x, precision);
+    let se_del = (se1.powi(2) + se2.powi(2)).sqrt();
 
-    for l in reader.lines() {
-        let s = l?.trim().to_string();
+    println!("{l:>w$} = {v} ± {se}", w = width, l = format!("{}₁ ± SE", x), v = f(x1),
+             se = f(se1));
+    println!("{l:>w$} = {v} ± {se}", w = width, l = format!("{}₂ ± SE", x), v = f(x2),
+             se = f(se2));
+    println!("{l:>w$} = {v} ± {se}", w = width, l = format!("{x}₂ - {x}₁ ± SE", x = x),
+             v = f(x2 - x1), se = f(se_del));
+}
 
-        if s.is_empty() {
-            continue;
-        }
+/// Print the p-value of a t-test, in the color of its significance if
+/// coloring, then its t statistic and degrees of freedom.
+fn print_t_statistics(t_test: &TTest, width: usize, display: &Display) {
+    let f = |x| fmt::stat(x, display.precision);
 
-        match s.parse() {
-            Ok(d) => data.push(d),
-            err => if !lax_parsing { err?; }
-        }
+    display.print_p(&format!("{l:>w$} = {v}", w = width, l = "p", v = f(t_test.p)), t_test.p);
+    println!("{l:>w$} = {v}", w = width, l = "t", v = f(t_test.t));
+    println!("{l:>w$} = {v}", w = width, l = "DF", v = f(t_test.df));
+}
+
+/// Print the changes of a mean, labeled `label`, and of the median.
+fn print_changes(label: &str, mean: &Change, median: &Change, width: usize,
+                 precision: Option<usize>) {
+    println!();
+    println!("Change, with {} confidence intervals:", fmt::level(mean.confidence));
+    print_change(label, mean, width, precision);
+    print_change("Δ median", median, width, precision);
+}
+
+/// The changes of the mean and median of `s2` from those of `s1`, with
+/// confidence intervals at `level`.
+fn changes(s1: &Summary, s2: &Summary, level: f64) -> (Change, Change) {
+    (ok!(mean_change(s1, s2, level)), ok!(median_change(s1, s2, level)))
+}
+
+fn print_change(label: &str, c: &Change, width: usize, precision: Option<usize>) {
+    let f = |x| fmt::stat(x, precision);
+
+    println!("{l:>w$} = {v} ± {m} ({r} ± {rm})", w = width, l = label, v = f(c.absolute),
+             m = f(c.absolute_margin), r = fmt::signed_percent(c.relative, precision),
+             rm = fmt::percent(c.relative_margin, precision));
+}
+
+/// A sentence stating whether a test with p-value `p` finds a significant
+/// `difference`, such as "Difference in means is significant at α = 0.05 (p =
+/// 0.003)", followed by `detail`, if any.
+fn verdict(difference: &str, p: f64, detail: Option<String>, display: &Display) -> String {
+    let significant = if display.significant(p) { "significant" } else { "not significant" };
+    // A p-value that underflowed is too small to state.
+    let p_value = if p == 0.0 { "p ≈ 0".to_string() } else {
+        format!("p = {}", fmt::stat(p, display.precision.or(Some(2))))
+    };
+    let s = format!("{} is {} at α = {} ({})", difference, significant, display.alpha, p_value);
+
+    match detail {
+        Some(d) if display.significant(p) => format!("{}; {}", s, d),
+        _ => s,
     }
+}
 
-    Ok(data)
+/// How the mean of the sample from `new` compares to that from `old`, by the
+/// `change` of the mean, such as "new is 6.2% faster than old" for durations,
+/// or of the harmonic mean for rates, which are faster when greater. Means of
+/// otherwise transformed data are not compared, since their ratio means little.
+fn direction(old: &str, new: &str, change: &Change, display: &Display) -> Option<String> {
+    let relative = change.relative;
+    let rates = display.rates();
+    if (display.transform.is_some() && !rates) || !relative.is_finite() || relative == 0.0 {
+        return None;
+    }
+
+    let amount = fmt::percent(relative.abs(), display.precision);
+    if rates {
+        let faster = if relative > 0.0 { "faster" } else { "slower" };
+        return Some(format!("{} is {} {} than {}", new, amount, faster, old));
+    }
+
+    let durations = display.unit.is_some_and(|u| unit::TimeUnit::parse(u).is_ok());
+
+    Some(match (durations, relative < 0.0) {
+        (true, true) => format!("{} is {} faster than {}", new, amount, old),
+        (true, false) => format!("{} is {} slower than {}", new, amount, old),
+        (false, true) => format!("the mean of {} is {} less than that of {}", new, amount, old),
+        (false, false) => format!("the mean of {} is {} greater than that of {}", new, amount,
+                                  old),
+    })
+}
+
+/// The difference of means which a t-test against `alternative` tests for, of
+/// pairs if `paired`, as the subject of its verdict.
+fn difference(alternative: Alternative, paired: bool) -> &'static str {
+    match (alternative, paired) {
+        (Alternative::TwoSided, false) => "Difference in means",
+        (Alternative::Less, false) => "Decrease in means",
+        (Alternative::Greater, false) => "Increase in means",
+        (Alternative::TwoSided, true) => "Mean difference",
+        (Alternative::Less, true) => "Mean decrease",
+        (Alternative::Greater, true) => "Mean increase",
+    }
+}
+
+/// The difference of the harmonic means of rates which a t-test against
+/// `alternative` tests for, as the subject of its verdict.
+fn rate_difference(alternative: Alternative) -> &'static str {
+    match alternative {
+        Alternative::TwoSided => "Difference in harmonic means",
+        Alternative::Less => "Decrease in harmonic means",
+        Alternative::Greater => "Increase in harmonic means",
+    }
+}
+
+/// Print a verdict, in the color of its significance if coloring.
+fn print_verdict(verdict: &str, p: f64, display: &Display) {
+    println!();
+    display.print_p(verdict, p);
+}
+
+fn sample_file(path: &str, opts: &input::Options, transform: Option<Transform>)
+               -> Result<Summarizer, Box<dyn error::Error>> {
+    let data = input::read_file(path, opts)?;
+
+    sample(data, transform)
+}
+
+fn sample(data: Vec<f64>, transform: Option<Transform>)
+          -> Result<Summarizer, Box<dyn error::Error>> {
+    let data = transform::apply(transform, data)?;
+
+    Ok(Summarizer::new(&data)?)
 }
 
-fn summarize_stdin(lax_parsing: bool) -> Result<Summary, Box<error::Error>> {
-    let stdin = io::stdin();
-    let data = read_data(stdin.lock(), lax_parsing)?;
+/// Transform samples analyzed together, as by `transform::apply_jointly`, and
+/// summarize each.
+fn summarize(data: Vec<Vec<f64>>, transform: Option<Transform>)
+             -> Result<Vec<Summarizer>, Box<dyn error::Error>> {
+    let data = transform::apply_jointly(transform, data)?;
+
+    Ok(map_inputs(&data, |d| Summarizer::new(d)).into_iter().collect::<Result<_, _>>()?)
+}
 
-    Ok(Summary::new(&data)?)
+fn summaries(samples: &[Summarizer]) -> Vec<Summary> {
+    samples.iter().map(Summary::from).collect()
 }
 
-fn display_t_test(
-    summary1: &Summary,
-    summary2: &Summary,
+/// Options controlling how summaries and test results are displayed.
+struct Display {
+    color: bool,
     draw_plot: bool,
-    width: usize,
-    ascii: bool,
     outliers: bool,
-) {
-    let t_test = ok!(welch_t_test(&summary1, &summary2));
+    fields: Option<Vec<Field>>,
+    plot: PlotOptions,
+    precision: Option<usize>,
+    /// Name each sample in plain-text output, as when one input is split
+    /// into several samples.
+    labels: bool,
+    /// Symbol of the unit of the sample data, if they had unit suffixes.
+    unit: Option<&'static str>,
+    /// How the sample data were transformed before analysis, if they were.
+    transform: Option<Transform>,
+    /// The level of the confidence intervals of means which summaries add
+    /// to their default fields, if given by `--confidence`.
+    confidence: Option<f64>,
+    /// The level of confidence intervals, from `--confidence` or its default.
+    level: f64,
+    /// The alternative hypothesis of t-tests comparing two samples.
+    alternative: Alternative,
+    /// The significance level of tests, from `--alpha`.
+    alpha: f64,
+}
 
-    if draw_plot {
-        let p = ok!(plot::comparison_plot(&[summary1, summary2], width, ascii, true, outliers));
-        println!("{}\n", p);
+impl Display {
+    /// Whether a test with p-value `p` rejects its null hypothesis.
+    fn significant(&self, p: f64) -> bool {
+        p < self.alpha
     }
 
-    print_summary(&summary1, outliers);
-    println!();
-    print_summary(&summary2, outliers);
+    /// Print `s`, stating a result with p-value `p`, in the color of its
+    /// significance if coloring.
+    fn print_p(&self, s: &str, p: f64) {
+        if self.color {
+            println!("{}", color::significance(s, self.significant(p)));
+        } else {
+            println!("{}", s);
+        }
+    }
+
+    /// Fields of human-readable summary tables.
+    fn table_fields(&self) -> Vec<Field> {
+        let defaults = || self.with_interval(Field::table_defaults(self.outliers));
+        self.fields.clone().unwrap_or_else(defaults)
+    }
+
+    /// Fields of machine-readable summaries.
+    fn all_fields(&self) -> Vec<Field> {
+        self.fields.clone().unwrap_or_else(|| self.with_interval(Field::all_defaults()))
+    }
+
+    /// Default fields, with the bounds of the confidence interval of the mean
+    /// after the mean if asked, and the harmonic mean before them if the data
+    /// are rates.
+    fn with_interval(&self, mut fields: Vec<Field>) -> Vec<Field> {
+        let mean = fields.iter().position(|&f| f == Field::Mean);
+        if let (Some(level), Some(i)) = (self.confidence, mean) {
+            fields.splice(i + 1..i + 1, vec![Field::MeanLower(level), Field::MeanUpper(level)]);
+        }
+        if let (true, Some(i)) = (self.rates(), mean) {
+            fields.insert(i + 1, Field::HarmonicMean);
+        }
+
+        fields
+    }
+
+    /// Whether the samples are rates, from `--as-rate`.
+    fn rates(&self) -> bool {
+        self.transform.is_some_and(|t| t.is_rate())
+    }
+
+    /// The unit of the analyzed values, which a transform changes.
+    fn unit_label(&self) -> Option<String> {
+        transform::unit(self.transform, self.unit)
+    }
+}
+
+/// Label the unit of the values in human-readable output, or their transform
+/// if they have no unit.
+fn print_unit(display: &Display) {
+    match (display.unit_label(), display.transform) {
+        (Some(unit), _) => println!("Unit: {}\n", unit),
+        (None, Some(t)) => println!("Transform: {}\n", t.name()),
+        (None, None) => {},
+    }
+}
+
+fn display_plot(summaries: &[&Summary], sources: &[String], display: &Display) {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+
+    if display.labels {
+        let opts = display.plot.clone().labels(sources);
+        ok!(plot::comparison_plot_to(&mut out, summaries, &opts));
+    } else {
+        ok!(plot::comparison_plot_to(&mut out, summaries, &display.plot));
+    }
+    ok!(writeln!(out));
+}
+
+fn create_file(path: &str) -> Result<File, Box<dyn error::Error>> {
+    File::create(path).map_err(|e| format!("Could not create file {:?}: {}", path, e).into())
+}
+
+fn write_svg(path: &str, samples: &[Summarizer], opts: &PlotOptions)
+             -> Result<(), Box<dyn error::Error>> {
+    let summaries = summaries(samples);
+    let summary_refs: Vec<&Summary> = summaries.iter().collect();
+    let mut f = create_file(path)?;
+
+    Ok(plot::svg::comparison_plot_to(&mut f, &summary_refs, opts)?)
+}
+
+/// Compare two samples by a t-test of their means, or of rates by one of their
+/// harmonic means, since the arithmetic mean of rates of equal work is not the
+/// overall rate.
+fn display_t_test(samples: &[Summarizer], sources: &[String], display: &Display) {
+    let summary1 = Summary::from(&samples[0]);
+    let summary2 = Summary::from(&samples[1]);
+    let t_test = if display.rates() {
+        // The harmonic means are greater as the means of the reciprocals are
+        // less, so test the reciprocals in the opposite order.
+        let r1 = Summary::from(&ok!(samples[0].reciprocals()));
+        let r2 = Summary::from(&ok!(samples[1].reciprocals()));
+        welch(&r2, &r1, display.alternative, display.alpha)
+    } else {
+        welch(&summary1, &summary2, display.alternative, display.alpha)
+    };
+
+    if display.draw_plot {
+        display_plot(&[&summary1, &summary2], sources, display);
+    }
+
+    print_summaries(samples, sources, display);
     println!();
-    print_t_test(&t_test, &summary1, &summary2);
+
+    let (change, subject) = if display.rates() {
+        print_rate_t_test(&t_test, &samples[0], &samples[1], display);
+        (ok!(harmonic_mean_change(&samples[0], &samples[1], display.level)),
+         rate_difference(display.alternative))
+    } else {
+        print_t_test(&t_test, &summary1, &summary2, display);
+        (ok!(mean_change(&summary1, &summary2, display.level)),
+         difference(display.alternative, false))
+    };
+    let detail = direction(&sources[0], &sources[1], &change, display);
+    print_verdict(&verdict(subject, t_test.p, detail, display), t_test.p, display);
 }
 
-fn display_summaries(
-    summaries: &[Summary],
-    draw_plot: bool,
-    width: usize,
-    ascii: bool,
-    outliers: bool,
-) {
-    if draw_plot {
+fn display_summaries(samples: &[Summarizer], sources: &[String], display: &Display) {
+    if display.draw_plot {
+        let summaries = summaries(samples);
         let summary_refs: Vec<&Summary> = summaries
             .iter()
             .collect();
 
-        let plot = ok!(plot::comparison_plot(&summary_refs, width, ascii, true, outliers));
-        println!("{}\n", plot);
+        display_plot(&summary_refs, sources, display);
     }
 
-    for i in 0..summaries.len() {
+    print_summaries(samples, sources, display);
+}
+
+/// Print a summary table of each sample, preceded by its name if labeling.
+fn print_summaries(samples: &[Summarizer], sources: &[String], display: &Display) {
+    let fields = display.table_fields();
+
+    print_unit(display);
+
+    for (i, (s, src)) in samples.iter().zip(sources).enumerate() {
         if i > 0 {
             println!();
         }
-        print_summary(&summaries[i], outliers);
-    }
-}
-
-fn display_summaries_tsv(summaries: &[Summary], sources: &[&str]) {
-    let parts = vec![
-        "Source",
-        "Size",
-        "Mean",
-        "Median",
-        "StandardDeviation",
-        "Variance",
-        "StandardError",
-        "Min",
-        "Max",
-        "Range",
-        "LowerQuartile",
-        "UpperQuartile",
-        "IQR",
-        "MinAdjacent",
-        "MaxAdjacent",
-    ];
-    let header = parts.join("\t");
-    println!("{}", header);
-
-    for (summ, src) in summaries.iter().zip(sources) {
-        print_summary_tsv(summ, src);
-    }
-}
-
-fn print_summary_tsv(summary: &Summary, source: &str) {
-    let values = vec![
-        summary.size(),
-        summary.mean(),
-        summary.median(),
-        summary.standard_deviation(),
-        summary.unbiased_variance(),
-        summary.standard_error(),
-        summary.min(),
-        summary.max(),
-        summary.range(),
-        summary.lower_quartile(),
-        summary.upper_quartile(),
-        summary.iqr(),
-        summary.min_adjacent(),
-        summary.max_adjacent(),
-    ];
-    let fields: Vec<String> = values.iter().map(|x| format!("{}", x)).collect();
-    println!("{}\t{}", source, fields.join("\t"));
+        if display.labels {
+            println!("{}", src);
+        }
+        print_summary(s, &fields, display.precision);
+    }
 }
 
-fn main() {
-    let matches = App::new("dent")
-        .version(crate_version!())
-        .author("Joe Ranweiler <joe@lemma.co>")
-        .about("A tiny tool for t-tests &c.")
-        .arg(Arg::with_name("stdin")
-             .short("s")
-             .long("stdin")
-             .help("Read and summarize data from stdin"))
-        .arg(Arg::with_name("files")
-             .multiple(true)
-             .value_name("FILES")
-             .takes_value(true)
-             .required_unless("stdin")
-             .help("Path to one or more files of sample data"))
-        .arg(Arg::with_name("lax")
-             .long("lax")
-             .help("Ignore non-numeric input lines"))
-        .arg(Arg::with_name("tsv")
-             .long("tsv")
-             .help("Print summary data to stdout in TSV format"))
-        .arg(Arg::with_name("plot_outliers")
-             .long("outliers")
-             .help("Include outliers and use min/max for outer fences of boxplot"))
-        .arg(Arg::with_name("plot")
-             .short("p")
-             .long("plot")
-             .help("Print standard boxplots"))
-        .arg(Arg::with_name("ascii")
-             .long("ascii")
-             .help("Use only ASCII characters in boxplots"))
-        .arg(Arg::with_name("width")
-             .short("w")
-             .long("width")
-             .value_name("WIDTH")
-             .takes_value(true)
-             .help("Width of boxplot"))
-        .get_matches();
-
-    let ascii = matches.is_present("ascii");
-    let lax_parsing = matches.is_present("lax");
-    let draw_plot = matches.is_present("plot");
-    let use_stdin = matches.is_present("stdin");
-    let outliers = matches.is_present("plot_outliers");
-    let tsv = matches.is_present("tsv");
+fn display_markdown(samples: &[Summarizer], sources: &[String], display: &Display, compare: bool) {
+    let summaries = summaries(samples);
 
-    let width = matches
-        .value_of("width")
-        .and_then(|w| w.parse::<usize>().ok())
-        .or(term_size::dimensions().map(|(w, _)| w))
-        .unwrap_or(80);
+    if display.draw_plot {
+        let summary_refs: Vec<&Summary> = summaries.iter().collect();
+        println!("{}", ok!(markdown::plot_block(&summary_refs, &display.plot)));
+    }
+
+    print_unit(display);
+
+    let fields = display.table_fields();
+    print!("{}", markdown::summary_table(sources, samples, &fields, display.precision));
+
+    if let (true, [s1, s2]) = (compare, summaries.as_slice()) {
+        reject_rates(display.rates(), "in Markdown");
+        let t_test = welch(s1, s2, display.alternative, display.alpha);
+        println!();
+        print!("{}", markdown::t_test_table(&t_test, s1, s2, display.precision));
+
+        let (mean, median) = changes(s1, s2, display.level);
+        println!();
+        print!("{}", markdown::change_table(&mean, &median, display.precision));
+    }
+}
+
+fn display_json(samples: &[Summarizer], sources: &[String], display: &Display, compare: bool) {
+    let summaries = summaries(samples);
+    let mut fields = json_unit(display);
+    fields.push(("summaries", json::summaries(sources, samples, &display.all_fields())));
+
+    if let (true, [s1, s2]) = (compare, summaries.as_slice()) {
+        reject_rates(display.rates(), "in JSON");
+        let t_test = welch(s1, s2, display.alternative, display.alpha);
+        fields.push(("t_test", json::t_test(&t_test, s1, s2, display.alternative)));
+        fields.extend(json_changes(s1, s2, display.level));
+    }
+
+    print!("{}", json::object(fields).render());
+}
 
-    let (sources, summaries) = if use_stdin {
-        (vec!["stdin"], vec![ok!(summarize_stdin(lax_parsing))])
+/// The unit of the sample data, if known, and their transform, if any, as
+/// fields of a JSON object.
+fn json_unit(display: &Display) -> Vec<(&'static str, json::Value)> {
+    let unit = display.unit_label().map(|u| ("unit", json::Value::String(u)));
+    let transform = display
+        .transform
+        .map(|t| ("transform", json::Value::String(t.name().to_string())));
+
+    unit.into_iter().chain(transform).collect()
+}
+
+/// The changes of the mean and median of `s2` from those of `s1`, with
+/// confidence intervals at `level`, as fields of a JSON object.
+fn json_changes(s1: &Summary, s2: &Summary, level: f64) -> Vec<(&'static str, json::Value)> {
+    let (mean, median) = changes(s1, s2, level);
+
+    vec![("mean_change", json::change(&mean)), ("median_change", json::change(&median))]
+}
+
+fn display_long(samples: &[Summarizer], sources: &[String], display: &Display, compare: bool) {
+    let fields = display.all_fields();
+
+    let unit = display.unit_label().map(|u| format!("unit: {}\n", u));
+    let transform = display.transform.map(|t| format!("transform: {}\n", t.name()));
+    if unit.is_some() || transform.is_some() {
+        println!("{}{}", unit.unwrap_or_default(), transform.unwrap_or_default());
+    }
+
+    for (i, (src, s)) in sources.iter().zip(samples).enumerate() {
+        if i > 0 {
+            println!();
+        }
+        print!("{}", long::summary(src, s, &fields, display.precision));
+    }
+
+    if let (true, [s1, s2]) = (compare, summaries(samples).as_slice()) {
+        reject_rates(display.rates(), "in long output");
+        let t_test = welch(s1, s2, display.alternative, display.alpha);
+        println!();
+        print!("{}", long::t_test(&t_test, s1, s2, display.precision));
+
+        let (mean, median) = changes(s1, s2, display.level);
+        print!("{}", long::change("mean", &mean, display.precision));
+        print!("{}", long::change("median", &median, display.precision));
+    }
+}
+
+/// The field separator of a delimited output `format`, if it is one.
+fn separator(format: Option<&str>) -> Option<char> {
+    match format {
+        Some("csv") => Some(','),
+        Some("tsv") => Some('\t'),
+        _ => None,
+    }
+}
+
+fn display_delimited(samples: &[Summarizer], sources: &[String], display: &Display, compare: bool,
+                     sep: char) {
+    let summaries = summaries(samples);
+    let fields = display.all_fields();
+    let unit = display.unit_label();
+
+    if let (true, [s1, s2]) = (compare, summaries.as_slice()) {
+        reject_rates(display.rates(), "in delimited output");
+        let t_test = welch(s1, s2, display.alternative, display.alpha);
+        print!("{}", delimited::t_test_table(sources, samples, &fields, unit.as_deref(), &t_test,
+                                             sep));
     } else {
-        // Required if `stdin` is not present, so we can unwrap.
-        let files = matches
-            .values_of("files")
-            .unwrap_or_else(|| unreachable!());
+        print!("{}", delimited::summary_table(sources, samples, &fields, unit.as_deref(), sep));
+    }
+}
+
+/// Pair the `i`th values of two files of measurements, and analyze their
+/// agreement.
+fn bland_altman(path1: &str, path2: &str, opts: &input::Options)
+                -> Result<BlandAltman, Box<dyn error::Error>> {
+    let a = input::read_file(path1, opts)?;
+    let b = input::read_file(path2, opts)?;
+
+    if a.len() != b.len() {
+        return Err(format!(
+            "Paired samples must have equal sizes, but {:?} has {} values and {:?} has {}",
+            path1, a.len(), path2, b.len(),
+        ).into());
+    }
+
+    let pairs: Vec<(f64, f64)> = a.into_iter().zip(b).collect();
+
+    Ok(BlandAltman::new(&pairs)?)
+}
+
+fn display_bland_altman(matches: &ArgMatches, ba: &BlandAltman, display: &Display) {
+    if let Some(path) = matches.value_of("svg") {
+        let mut f = ok!(create_file(path));
+        ok!(plot::svg::bland_altman_plot_to(&mut f, ba, &display.plot));
+    }
 
-        let summaries = files.clone().map(|f| ok!(summarize_file(f, lax_parsing))).collect();
-        (files.collect(), summaries)
+    let width = 12;
+    let f = |x| fmt::stat(x, display.precision);
+
+    if display.draw_plot {
+        println!("{}\n", ok!(plot::bland_altman_plot(ba, &display.plot)));
+    }
+
+    println!("{l:>w$} = {v}", w = width, l = "n", v = f(ba.size()));
+    println!("{l:>w$} = {v}", w = width, l = "Bias", v = f(ba.bias()));
+    println!("{l:>w$} = {v}", w = width, l = "SD", v = f(ba.standard_deviation()));
+    println!("{l:>w$} = {v}", w = width, l = "Lower LoA", v = f(ba.lower_limit()));
+    println!("{l:>w$} = {v}", w = width, l = "Upper LoA", v = f(ba.upper_limit()));
+}
+
+fn run_import(matches: &ArgMatches) {
+    let mut display = display_options(matches);
+    display.labels = true;
+
+    // Required, so we can unwrap.
+    let path = matches.value_of("path").unwrap_or_else(|| unreachable!());
+    let benches = match matches.value_of("tool") {
+        Some("criterion") => ok!(import::criterion(path)),
+        _ => unreachable!(),
     };
 
-    if tsv {
-        return display_summaries_tsv(&summaries, &sources);
+    let mut sources = vec![];
+    let mut samples = vec![];
+    let mut sizes = vec![];
+    let names: Vec<String> = benches.iter().map(|b| b.name.clone()).collect();
+    for b in &benches {
+        if let Some(ref base) = b.base {
+            sources.push(format!("{}/base", b.name));
+            samples.push(ok!(Summarizer::new(base)));
+        }
+        sources.push(format!("{}/new", b.name));
+        samples.push(ok!(Summarizer::new(&b.new)));
+        sizes.push(if b.base.is_some() { 2 } else { 1 });
+    }
+
+    display.unit = Some("ns");
+    if display_speedups(matches, &names, &sizes, &samples, &display) {
+        return;
+    }
+    if display_alternatives(matches, &sources, &samples, &display, false) {
+        return;
     }
 
-    match summaries.len() {
-        0 => unreachable!(),
-        // We want match 1 with the case `len()` > 2.
-        2 => {
-            display_t_test(
-                &summaries[0],
-                &summaries[1],
-                draw_plot,
-                width,
-                ascii,
-                outliers,
-            );
-        }
-        _ => {
-            display_summaries(
-                &summaries,
-                draw_plot,
-                width,
-                ascii,
-                outliers,
-            );
+    display_benchmarks(&sizes, &samples, &sources, display);
+}
+
+/// With `--geomean`, print the speedup of each benchmark with two samples, a
+/// baseline and a new run, as the ratio of their means, and the geometric mean
+/// of those speedups. The benchmarks are named by `names`, and their samples
+/// are consecutive runs of `samples` of the given `sizes`. Returns `true` if
+/// the speedups were printed.
+fn display_speedups(matches: &ArgMatches, names: &[String], sizes: &[usize],
+                    samples: &[Summarizer], display: &Display) -> bool {
+    if !matches.is_present("geomean") {
+        return false;
+    }
+
+    let mut speedups = vec![];
+    let mut i = 0;
+    for (name, &k) in names.iter().zip(sizes) {
+        if k == 2 {
+            let (old, new) = (samples[i].mean(), samples[i + 1].mean());
+            speedups.push((name, old, new, old / new));
+        }
+        i += k;
+    }
+
+    let ratios: Vec<f64> = speedups.iter().map(|s| s.3).collect();
+    let geomean = match geometric_mean_ratio(&ratios, display.level) {
+        Ok(g) => g,
+        Err(_) if ratios.len() < 2 => ok!(Err(format!(
+            "A geometric mean speedup needs at least two benchmarks in both results, not {}",
+            ratios.len(),
+        ))),
+        Err(_) => ok!(Err("Speedups must be ratios of positive means")),
+    };
+
+    match matches.value_of("format") {
+        Some("json") => {
+            let speedups = speedups
+                .iter()
+                .map(|&(name, old, new, ratio)| json::object(vec![
+                    ("benchmark", json::Value::String(name.to_string())),
+                    ("old_mean", json::Value::Number(old)),
+                    ("new_mean", json::Value::Number(new)),
+                    ("speedup", json::Value::Number(ratio)),
+                ]))
+                .collect();
+
+            let mut fields = json_unit(display);
+            fields.push(("speedups", json::Value::Array(speedups)));
+            fields.push(("geometric_mean_speedup", json::geometric_mean_ratio(&geomean)));
+            print!("{}", json::object(fields).render());
+        },
+        Some("text") | None => {
+            let width = 10;
+            let ratio = |x| format!("{}×", fmt::sig(x, display.precision.unwrap_or(4)));
+
+            let mut t = Table::new()
+                .column("Benchmark", Align::Left, 0)
+                .column("Old Mean", Align::Right, width)
+                .column("New Mean", Align::Right, width)
+                .column("Speedup", Align::Right, 0);
+            for &(name, old, new, r) in &speedups {
+                t.row(vec![
+                    name.to_string(),
+                    fmt::cell(old, width, display.precision),
+                    fmt::cell(new, width, display.precision),
+                    ratio(r),
+                ]);
+            }
+
+            print_unit(display);
+            print!("{}", t.text());
+            println!();
+            println!("Geometric mean speedup: {} ({} CI {} to {}), of {} benchmarks",
+                     ratio(geomean.ratio), fmt::level(geomean.confidence), ratio(geomean.lower),
+                     ratio(geomean.upper), speedups.len());
+
+            let unpaired = names.len() - speedups.len();
+            if unpaired > 0 {
+                println!("Skipped {} benchmarks not in both results", unpaired);
+            }
         },
+        Some(_) => ok!(Err("Speedups are written only as text or JSON")),
+    }
+
+    true
+}
+
+/// Display consecutive runs of `samples`, one run per benchmark, of the given
+/// `sizes`. A benchmark with two samples, such as a baseline and a latest
+/// run, is compared with a t-test; any other is summarized.
+fn display_benchmarks(sizes: &[usize], samples: &[Summarizer], sources: &[String],
+                      mut display: Display) {
+    // Print the unit once, rather than with each benchmark.
+    print_unit(&display);
+    display.unit = None;
+    display.transform = None;
+
+    let mut i = 0;
+    for (n, &k) in sizes.iter().enumerate() {
+        if n > 0 {
+            println!();
+        }
+
+        let (bench_samples, bench_sources) = (&samples[i..i + k], &sources[i..i + k]);
+
+        if k == 2 {
+            display_t_test(bench_samples, bench_sources, &display);
+        } else {
+            display_summaries(bench_samples, bench_sources, &display);
+        }
+        i += k;
+    }
+}
+
+/// Compare two sets of benchmark results read with `--from`, as benchstat
+/// does: each benchmark in both is compared with a t-test, and any in only one
+/// is summarized.
+fn compare_benchmarks(matches: &ArgMatches, tool: &str, files: &[&str], opts: &input::Options,
+                      mut display: Display) {
+    let split = Split::Import(tool);
+
+    let names = input_names(matches, &[Some(files[0]), Some(files[1])]);
+    let old = ok!(split_samples(Some(files[0]), &split, opts));
+    let mut new = ok!(split_samples(Some(files[1]), &split, opts));
+
+    let mut sources = vec![];
+    let mut data = vec![];
+    let mut sizes = vec![];
+    let mut benchmarks = vec![];
+    for (name, d) in old {
+        sources.push(format!("{}:{}", names[0], name));
+        data.push(d);
+        benchmarks.push(name.clone());
+
+        match new.iter().position(|(n, _)| *n == name) {
+            Some(i) => {
+                sources.push(format!("{}:{}", names[1], name));
+                data.push(new.remove(i).1);
+                sizes.push(2);
+            },
+            None => sizes.push(1),
+        }
+    }
+    for (name, d) in new {
+        sources.push(format!("{}:{}", names[1], name));
+        data.push(d);
+        sizes.push(1);
+        benchmarks.push(name);
+    }
+
+    // Transform the samples of each benchmark together, apart from the rest.
+    let mut data = data.into_iter();
+    let mut samples = vec![];
+    for &k in &sizes {
+        samples.extend(ok!(summarize(data.by_ref().take(k).collect(), display.transform)));
+    }
+
+    display.unit = opts.unit();
+    if display_speedups(matches, &benchmarks, &sizes, &samples, &display) {
+        return;
+    }
+    if display_alternatives(matches, &sources, &samples, &display, false) {
+        return;
+    }
+
+    display_benchmarks(&sizes, &samples, &sources, display);
+}
+
+fn run_commands(matches: &ArgMatches) {
+    let mut display = display_options(matches);
+    display.labels = true;
+    let opts = input_options(matches);
+
+    // Required, so we can unwrap.
+    let commands: Vec<&str> = matches.values_of("commands").unwrap_or_else(|| unreachable!()).collect();
+    let runs = match matches.value_of("runs").map(str::parse::<usize>) {
+        Some(Ok(n)) if n >= 2 => n,
+        _ => ok!(Err("The number of runs must be an integer of at least 2")),
+    };
+    let warmup = match matches.value_of("warmup").map(str::parse::<usize>) {
+        Some(Ok(n)) => n,
+        _ => ok!(Err("The number of warmup runs must be an integer")),
+    };
+
+    let inputs: Vec<Option<&str>> = commands.iter().cloned().map(Some).collect();
+    let sources = input_names(matches, &inputs);
+    let samples: Vec<Summarizer> = commands
+        .iter()
+        .map(|c| {
+            let times = ok!(runner::time(c, runs, warmup));
+            let times = ok!(times
+                .into_iter()
+                .map(|t| opts.normalize(t, Some(unit::Unit::Seconds)))
+                .collect::<Result<Vec<_>, _>>());
+
+            ok!(Summarizer::new(&times))
+        })
+        .collect();
+    display.unit = opts.unit();
+    let regression = regression(matches, &samples);
+    let non_inferiority = non_inferiority(matches, &opts, &samples);
+
+    if !display_alternatives(matches, &sources, &samples, &display, true) {
+        display_comparison(&samples, &sources, &display);
+        print_regression(regression.as_ref(), &display);
+        print_non_inferiority(non_inferiority.as_ref(), &display);
+    }
+
+    exit_on_regression(regression.as_ref(), display.alpha);
+    exit_unless_non_inferior(non_inferiority.as_ref());
+}
+
+/// Read the one input of a baseline subcommand, named by `--label` or its path.
+fn baseline_input(matches: &ArgMatches, opts: &input::Options) -> (String, Summarizer) {
+    let path = matches.value_of("file");
+    let name = input_names(matches, &[path]).remove(0);
+
+    let data = match path {
+        Some(path) => ok!(input::read_file(path, opts)),
+        None => ok!(input::read_stdin(opts)),
+    };
+
+    (name, ok!(sample(data, transform(matches))))
+}
+
+fn run_baseline_save(matches: &ArgMatches) {
+    let opts = input_options(matches);
+    let (source, sample) = baseline_input(matches, &opts);
+
+    // Required, so we can unwrap.
+    let path = matches.value_of("baseline").unwrap_or_else(|| unreachable!());
+    let mut f = ok!(create_file(path));
+
+    let unit = transform::unit(transform(matches), opts.unit());
+    ok!(f.write_all(baseline::render(&source, &sample, unit.as_deref()).as_bytes()));
+}
+
+fn run_baseline_compare(matches: &ArgMatches) {
+    let mut display = display_options(matches);
+    display.labels = true;
+    let opts = input_options(matches);
+
+    // Required, so we can unwrap.
+    let path = matches.value_of("baseline").unwrap_or_else(|| unreachable!());
+    reject_rates(display.rates(), "against a baseline");
+    let base = ok!(baseline::load(path));
+    let (source, sample) = baseline_input(matches, &opts);
+    display.unit = opts.unit();
+
+    let unit = display.unit_label();
+    if base.unit != unit {
+        let describe = |u: Option<&str>| u.map_or("unitless".to_string(), |u| format!("in {}", u));
+        ok!(Err(format!("The baseline is {}, but the data are {}",
+                        describe(base.unit.as_deref()), describe(unit.as_deref()))));
+    }
+
+    let summary = Summary::from(&sample);
+    let t_test = welch(&base.summary, &summary, display.alternative, display.alpha);
+    let regression = regression_of(matches, &base.summary, &summary);
+    let non_inferiority = non_inferiority_of(matches, &opts, &base.summary, &summary);
+
+    if matches.value_of("format") == Some("json") {
+        let mut fields = json_unit(&display);
+        fields.push(("baseline", base.value));
+        fields.push(("summaries", json::summaries(&[source], &[sample], &display.all_fields())));
+        let t_test = json::t_test(&t_test, &base.summary, &summary, display.alternative);
+        fields.push(("t_test", t_test));
+        fields.extend(json_changes(&base.summary, &summary, display.level));
+        print!("{}", json::object(fields).render());
+    } else {
+        let source_of_base = base.value.get("source").and_then(json::Value::as_str).unwrap_or("");
+        let change = ok!(mean_change(&base.summary, &summary, display.level));
+        let detail = direction("the baseline", &source, &change, &display);
+        println!("Baseline: {} ({})\n", source_of_base, path);
+        print_summaries(&[sample], &[source], &display);
+        println!();
+        print_t_test(&t_test, &base.summary, &summary, &display);
+        print_verdict(&verdict(difference(display.alternative, false), t_test.p, detail,
+                               &display), t_test.p, &display);
+        print_regression(regression.as_ref(), &display);
+        print_non_inferiority(non_inferiority.as_ref(), &display);
+    }
+
+    exit_on_regression(regression.as_ref(), display.alpha);
+    exit_unless_non_inferior(non_inferiority.as_ref());
+}
+
+fn run_report(matches: &ArgMatches) {
+    let opts = input_options(matches);
+
+    // Required, so we can unwrap.
+    let path = matches.value_of("html").unwrap_or_else(|| unreachable!());
+    let sources: Vec<&str> = matches
+        .values_of("files")
+        .unwrap_or_else(|| unreachable!())
+        .collect();
+
+    let summaries: Vec<Summary> = sources
+        .iter()
+        .map(|f| Summary::from(&ok!(sample_file(f, &opts, None))))
+        .collect();
+
+    let t_test = match summaries.len() {
+        2 => Some(welch(&summaries[0], &summaries[1], Alternative::TwoSided, alpha(matches))),
+        _ => None,
     };
+
+    let opts = PlotOptions::new()
+        .log_scale(matches.is_present("log"))
+        .outliers(matches.is_present("plot_outliers"));
+
+    let html = ok!(report::html(&sources, &summaries, t_test.as_ref(), &opts));
+
+    let mut f = ok!(create_file(path));
+    ok!(f.write_all(html.as_bytes()));
+}
+
+fn print_anova(anova: &Anova, display: &Display) {
+    let width = 12;
+    let f = |x| fmt::stat(x, display.precision);
+
+    println!("{l:>w$} = {v}", w = width, l = "F", v = f(anova.f));
+    println!("{l:>w$} = {v}", w = width, l = "DF between", v = f(anova.df_between));
+    println!("{l:>w$} = {v}", w = width, l = "DF within", v = f(anova.df_within));
+    display.print_p(&format!("{l:>w$} = {v}", w = width, l = "p", v = f(anova.p)), anova.p);
+}
+
+fn print_linear_regression(lr: &LinearRegression, precision: Option<usize>) {
+    print_stats(lr, precision);
+}
+
+fn plot_width(matches: &ArgMatches) -> usize {
+    terminal_width(matches).unwrap_or(80)
+}
+
+/// Width of plots given by `--width`, or else that of the terminal, if known.
+fn terminal_width(matches: &ArgMatches) -> Option<usize> {
+    matches
+        .value_of("width")
+        .and_then(|w| w.parse::<usize>().ok())
+        .or(term_size::dimensions().map(|(w, _)| w))
+}
+
+/// How to read sample data.
+fn input_options(matches: &ArgMatches) -> input::Options {
+    let csv = matches.is_present("csv");
+    let delimiter = match matches.value_of("delimiter") {
+        Some(d) => ok!(input::Delimiter::parse(d)),
+        None if csv => input::Delimiter::Csv,
+        None => input::Delimiter::default(),
+    };
+    let columnar = csv || matches.is_present("delimiter");
+
+    let column = match (matches.value_of("col"), matches.value_of("field")) {
+        (Some(_), _) if !columnar => ok!(Err("--col requires --csv or --delimiter")),
+        (Some(col), _) => Some(ok!(input::Column::parse(col))),
+        (None, Some(field)) => match ok!(input::Column::parse(field)) {
+            input::Column::Name(_) => ok!(Err("--field must be a positive integer")),
+            index => Some(index),
+        },
+        // Default to the first column.
+        (None, None) if columnar => Some(input::Column::Index(1)),
+        (None, None) => None,
+    };
+
+    let mut opts = input::Options::new(matches.is_present("lax"), column, delimiter);
+    if let Some(u) = matches.value_of("time_unit") {
+        opts.time_unit = ok!(unit::TimeUnit::parse(u));
+    }
+
+    opts
+}
+
+/// The level of confidence intervals, given by `--confidence`, or else by the
+/// configuration.
+/// The significance level of tests, from `--alpha` or its configured default.
+fn alpha(matches: &ArgMatches) -> f64 {
+    match matches.value_of("alpha").map(str::parse::<f64>) {
+        Some(Ok(a)) if a > 0.0 && a < 1.0 => a,
+        Some(_) => ok!(Err("The significance level must be between 0 and 1")),
+        None => config::get().alpha.unwrap_or(cli::DEFAULT_ALPHA),
+    }
+}
+
+fn confidence(matches: &ArgMatches) -> f64 {
+    match matches.value_of("confidence").map(str::parse::<f64>) {
+        Some(Ok(c)) if c > 0.0 && c < 1.0 => c,
+        Some(_) => ok!(Err("The confidence level must be between 0 and 1")),
+        None => config::get().confidence.unwrap_or(CONFIDENCE),
+    }
+}
+
+/// Significant digits of numbers in human-readable output, if limited.
+fn precision(matches: &ArgMatches) -> Option<usize> {
+    matches.value_of("precision").map(|p| match p.parse::<usize>() {
+        Ok(p) if (1..=17).contains(&p) => p,
+        _ => ok!(Err("Precision must be from 1 to 17 significant digits")),
+    })
+}
+
+/// Plot options common to all plots.
+fn plot_options(matches: &ArgMatches) -> PlotOptions {
+    let opts = PlotOptions::new()
+        .ascii(matches.is_present("ascii") || config::get().ascii)
+        .width(plot_width(matches));
+
+    match precision(matches) {
+        Some(p) => opts.precision(p),
+        None => opts,
+    }
+}
+
+fn display_options(matches: &ArgMatches) -> Display {
+    let color = color::enabled(matches.value_of("color").unwrap_or("auto"));
+    let outliers = matches.is_present("plot_outliers");
+    let precision = precision(matches);
+    let level = confidence(matches);
+    let fields = matches.value_of("fields").map(|f| {
+        ok!(Field::parse_list(f)).into_iter().map(|f| f.at_confidence(level)).collect()
+    });
+
+    let transform = transform(matches);
+
+    let plot_options = plot_options(matches)
+        .color(color)
+        // Log-transformed data are already on a log scale, so plot them linearly.
+        .log_scale(matches.is_present("log") && !transform.is_some_and(|t| t.is_log()))
+        .outliers(outliers);
+
+    Display {
+        color,
+        draw_plot: matches.is_present("plot"),
+        outliers,
+        fields,
+        plot: plot_options,
+        precision,
+        labels: ["cols", "group_by", "paired", "from", "label"]
+            .iter()
+            .any(|a| matches.is_present(a)),
+        unit: None,
+        transform,
+        confidence: if matches.is_present("confidence") { Some(level) } else { None },
+        level,
+        alternative: Alternative::parse(matches.value_of("alternative").unwrap_or("two-sided"))
+            .unwrap_or_else(|| unreachable!()),
+        alpha: alpha(matches),
+    }
+}
+
+/// The transform of sample data given by `--transform`, or by `--log-summary`.
+fn transform(matches: &ArgMatches) -> Option<Transform> {
+    match matches.value_of("transform") {
+        Some(t) => Some(ok!(Transform::parse(t))),
+        None if matches.is_present("log_summary") => Some(Transform::Log10),
+        None => matches.value_of("as_rate").map(|work| match work.parse::<f64>() {
+            // Rates are per second, whatever the unit of the durations.
+            Ok(w) if w > 0.0 && w.is_finite() => {
+                Transform::Rate(w / input_options(matches).time_unit.seconds(1.0))
+            },
+            _ => ok!(Err(format!("Invalid work per sample {:?}; expected a positive number",
+                                 work))),
+        }),
+    }
+}
+
+/// How to split one input into several samples.
+enum Split<'a> {
+    /// Each of several columns is a sample.
+    Columns(Vec<&'a str>),
+    /// Each group of a column of labels is a sample of a column of values.
+    Groups(&'a str, &'a str),
+    /// Each benchmark in the results of a benchmarking tool is a sample.
+    Import(&'a str),
+}
+
+impl<'a> Split<'a> {
+    fn new(matches: &'a ArgMatches) -> Option<Split<'a>> {
+        if let Some(cols) = matches.values_of("cols") {
+            return Some(Split::Columns(cols.collect()));
+        }
+
+        if let Some(tool) = matches.value_of("from") {
+            return Some(Split::Import(tool));
+        }
+
+        // `--value` is required with `--group-by`, so we can unwrap.
+        matches.value_of("group_by").map(|g| {
+            Split::Groups(g, matches.value_of("value").unwrap_or_else(|| unreachable!()))
+        })
+    }
+}
+
+/// Open a file, or stdin.
+fn open_input(path: Option<&str>) -> Result<Box<dyn io::BufRead>, Box<dyn error::Error>> {
+    match path {
+        Some(path) => input::open_file(path),
+        None => input::open_stdin(),
+    }
+}
+
+/// Split a file, or stdin, into named samples.
+fn split_samples(path: Option<&str>, split: &Split, opts: &input::Options)
+                 -> Result<input::NamedSamples, Box<dyn error::Error>> {
+    let reader = open_input(path)?;
+    let located = |e| input::locate(path.unwrap_or("stdin"), e);
+
+    let data = match *split {
+        Split::Columns(ref cols) => {
+            let columns = cols
+                .iter()
+                .map(|c| input::Column::parse(c))
+                .collect::<Result<Vec<_>, _>>()?;
+            let data = input::read_columns(reader, &columns, opts).map_err(located)?;
+
+            cols.iter().map(|c| c.to_string()).zip(data).collect()
+        },
+        Split::Groups(group, value) => {
+            let group = input::Column::parse(group)?;
+            let value = input::Column::parse(value)?;
+
+            input::read_groups(reader, &group, &value, opts).map_err(located)?
+        },
+        Split::Import(tool) => import::read(tool, reader, opts)?,
+    };
+
+    Ok(data)
+}
+
+/// Read each file named on the command line, or stdin if asked to or if
+/// there are none. With `--cols`, `--group-by`, or `--from`, each input is
+/// split into several samples, named by column, group, or benchmark, and by
+/// input too if there are several inputs. Stdin is split into samples at
+/// `# name:` markers, and labeled by them. Inputs are named by `--label`, if
+/// given, or else by path. All the samples are transformed together.
+fn load_samples(matches: &ArgMatches, opts: &input::Options, display: &mut Display)
+                -> (Vec<String>, Vec<Summarizer>) {
+    let transform = display.transform;
+
+    let inputs: Vec<Option<&str>> = match matches.values_of("files") {
+        Some(files) if !matches.is_present("stdin") => files.map(Some).collect(),
+        _ => vec![None],
+    };
+    let names = input_names(matches, &inputs);
+
+    let split = match Split::new(matches) {
+        Some(split) => split,
+        None => {
+            let (sources, data): (Vec<String>, Vec<Vec<f64>>) = match inputs[0] {
+                Some(_) => {
+                    let data = map_inputs(&inputs, |f| {
+                        ok!(input::read_file(f.unwrap_or_else(|| unreachable!()), opts))
+                    });
+                    (names, data)
+                },
+                None => {
+                    let named = ok!(input::read_named(ok!(input::open_stdin()), &names[0], opts)
+                        .map_err(|e| input::locate("stdin", e)));
+                    display.labels |= named.len() > 1;
+                    named.into_iter().unzip()
+                },
+            };
+
+            let samples = ok!(summarize(data, transform));
+            check_rates(transform, opts);
+            if matches.is_present("pool") {
+                return (vec!["pooled".to_string()], vec![ok!(Summarizer::pool(&samples))]);
+            }
+
+            return (sources, samples);
+        },
+    };
+
+    let mut sources = vec![];
+    let mut data = vec![];
+    let split_inputs = map_inputs(&inputs, |i| ok!(split_samples(*i, &split, opts)));
+
+    for (split_input, input_name) in split_inputs.into_iter().zip(&names) {
+        for (name, d) in split_input {
+            sources.push(match inputs.len() {
+                1 => name,
+                _ if matches.is_present("pool") => name,
+                _ => format!("{}:{}", input_name, name),
+            });
+            data.push(d);
+        }
+    }
+
+    if data.is_empty() {
+        ok!(Err("No samples were read"));
+    }
+
+    let samples = ok!(summarize(data, transform));
+    check_rates(transform, opts);
+    if matches.is_present("pool") {
+        return pool_by_name(sources, samples);
+    }
+
+    (sources, samples)
+}
+
+/// Fail if data converted to rates were not durations, as known from their
+/// unit suffixes. Those without units are taken to be durations.
+fn check_rates(transform: Option<Transform>, opts: &input::Options) {
+    if !transform.is_some_and(|t| t.is_rate()) {
+        return;
+    }
+
+    if let Some(u) = opts.unit().filter(|u| unit::TimeUnit::parse(u).is_err()) {
+        ok!(Err(format!("--as-rate requires durations, but the data are in {}", u)));
+    }
+}
+
+/// Fail if `rates`, from `--as-rate`, would be compared `how`, by the
+/// arithmetic means of the rates. Only the t-test of text output compares
+/// them, by their harmonic means.
+fn reject_rates(rates: bool, how: &str) {
+    if rates {
+        ok!(Err(format!("--as-rate compares rates only by the t-test of text output, not {}",
+                        how)));
+    }
+}
+
+/// Pool the samples of the same name, as the same group or column of several
+/// shards of one experiment, in the order each name first appears.
+fn pool_by_name(sources: Vec<String>, samples: Vec<Summarizer>) -> (Vec<String>, Vec<Summarizer>) {
+    let mut groups: Vec<(String, Vec<Summarizer>)> = vec![];
+
+    for (name, sample) in sources.into_iter().zip(samples) {
+        match groups.iter_mut().find(|g| g.0 == name) {
+            Some(g) => g.1.push(sample),
+            None => groups.push((name, vec![sample])),
+        }
+    }
+
+    groups.into_iter().map(|(name, samples)| (name, ok!(Summarizer::pool(&samples)))).unzip()
+}
+
+/// Read or summarize each of `inputs` with `f`, in parallel with the
+/// `parallel` feature, keeping the results in the order of the inputs.
+#[cfg(feature = "parallel")]
+fn map_inputs<I, T, F>(inputs: &[I], f: F) -> Vec<T>
+    where I: Sync, T: Send, F: Fn(&I) -> T + Sync + Send {
+    use rayon::prelude::*;
+
+    inputs.par_iter().map(f).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+fn map_inputs<I, T, F>(inputs: &[I], f: F) -> Vec<T>
+    where F: Fn(&I) -> T {
+    inputs.iter().map(f).collect()
+}
+
+/// Name each input, a path or stdin, by its `--label`, or else by its path.
+fn input_names(matches: &ArgMatches, inputs: &[Option<&str>]) -> Vec<String> {
+    match matches.values_of("label") {
+        Some(labels) => {
+            let labels: Vec<String> = labels.map(String::from).collect();
+
+            if labels.len() != inputs.len() {
+                ok!(Err(format!("Expected one --label per input, but got {} labels for {} inputs",
+                                labels.len(), inputs.len())));
+            }
+
+            labels
+        },
+        None => inputs.iter().map(|i| i.unwrap_or("stdin").to_string()).collect(),
+    }
+}
+
+/// Read pairs of measurements of the same subjects from the first two columns
+/// of one input, or from the two columns selected by `--cols`, named by column.
+fn load_pairs(matches: &ArgMatches, opts: &input::Options) -> (Vec<String>, Vec<(f64, f64)>) {
+
+    let cols: Vec<&str> = match matches.values_of("cols") {
+        Some(cols) => cols.collect(),
+        None => vec!["1", "2"],
+    };
+    if cols.len() != 2 {
+        ok!(Err("Paired input requires exactly two columns"));
+    }
+
+    let path = match matches.values_of("files") {
+        Some(_) if matches.is_present("stdin") => None,
+        Some(mut files) if files.len() == 1 => files.next(),
+        Some(_) => ok!(Err("Paired input is read from one file")),
+        None => None,
+    };
+
+    let a = ok!(input::Column::parse(cols[0]));
+    let b = ok!(input::Column::parse(cols[1]));
+    let reader = ok!(open_input(path));
+    let pairs = ok!(input::read_paired_columns(reader, &a, &b, opts)
+        .map_err(|e| input::locate(path.unwrap_or("stdin"), e)));
+
+    let pairs = match transform(matches) {
+        None => pairs,
+        t => {
+            let (a, b): (Vec<f64>, Vec<f64>) = pairs.into_iter().unzip();
+            let ab = ok!(transform::apply_jointly(t, vec![a, b]));
+            ab[0].iter().cloned().zip(ab[1].iter().cloned()).collect()
+        },
+    };
+
+    (cols.iter().map(|c| c.to_string()).collect(), pairs)
+}
+
+/// Write any SVG requested, then print summaries in a requested alternative
+/// format. Returns `true` if no further output should be printed.
+fn display_alternatives(matches: &ArgMatches, sources: &[String], samples: &[Summarizer],
+                        display: &Display, compare: bool) -> bool {
+    if let Some(path) = matches.value_of("svg") {
+        ok!(write_svg(path, samples, &display.plot));
+    }
+
+    if matches.is_present("tsv") {
+        let fields = display.all_fields();
+        let unit = display.unit_label();
+        print!("{}", delimited::summary_table(sources, samples, &fields, unit.as_deref(), '\t'));
+        return true;
+    }
+
+    let format = matches.value_of("format");
+
+    if matches.value_of("style") == Some("ministat") {
+        if !matches!(format, Some("text") | None) {
+            ok!(Err("The ministat style is only for text output"));
+        }
+
+        reject_rates(display.rates() && compare && samples.len() > 1, "in the ministat style");
+        display_ministat(matches, samples, sources, compare);
+        return true;
+    }
+
+    match format {
+        Some("markdown") => display_markdown(samples, sources, display, compare),
+        Some("json") => display_json(samples, sources, display, compare),
+        Some("long") => display_long(samples, sources, display, compare),
+        _ => match separator(format) {
+            Some(sep) => display_delimited(samples, sources, display, compare, sep),
+            None => return false,
+        },
+    }
+
+    true
+}
+
+/// Display samples in the layout of BSD ministat, its plot as wide as the
+/// terminal less its frame, or 74 columns as in ministat.
+fn display_ministat(matches: &ArgMatches, samples: &[Summarizer], sources: &[String],
+                    compare: bool) {
+    let width = terminal_width(matches).map_or(74, |w| w.saturating_sub(2));
+
+    print!("{}", ok!(ministat::render(sources, samples, compare, confidence(matches), width)));
+
+    if compare {
+        let alpha = alpha(matches);
+        let base = Summary::from(&samples[0]);
+
+        for s in &samples[1..] {
+            if let Ok(t_test) = student_t_test(&Summary::from(s), &base) {
+                decide(t_test.p, alpha);
+            }
+        }
+    }
+}
+
+fn run_default(matches: &ArgMatches) {
+    let mut display = display_options(matches);
+    let opts = input_options(matches);
+
+    if matches.is_present("bland_altman") && matches.is_present("paired") {
+        let (_, pairs) = load_pairs(matches, &opts);
+        return display_bland_altman(matches, &ok!(BlandAltman::new(&pairs)), &display);
+    }
+
+    if matches.is_present("bland_altman") {
+        let files: Vec<&str> = matches.values_of("files").map(|f| f.collect()).unwrap_or_default();
+
+        if matches.is_present("stdin") || files.len() != 2 {
+            ok!(Err("Bland-Altman analysis requires exactly two files"));
+        }
+
+        let ba = ok!(bland_altman(files[0], files[1], &opts));
+        return display_bland_altman(matches, &ba, &display);
+    }
+
+    if matches.is_present("paired") {
+        return run_paired_t_test(matches, display);
+    }
+
+    if matches.is_present("follow") {
+        return run_follow(matches, display);
+    }
+
+    if let Some(tool) = matches.value_of("from") {
+        let files: Vec<&str> = matches.values_of("files").map(|f| f.collect()).unwrap_or_default();
+
+        if !matches.is_present("stdin") && files.len() == 2 {
+            return compare_benchmarks(matches, tool, &files, &opts, display);
+        }
+    }
+
+    if matches.is_present("geomean") {
+        ok!(Err("--geomean compares two files of benchmark results, read with --from"));
+    }
+
+    let (sources, samples) = load_samples(matches, &opts, &mut display);
+    display.unit = opts.unit();
+
+    if let Some(measure) = matches.value_of("effect_size") {
+        return display_effect_sizes(matches, measure, &samples, &sources, &display);
+    }
+
+    if matches.is_present("fit") {
+        return display_fits(matches, &samples, &sources, &display);
+    }
+
+    if matches.is_present("auto") {
+        return display_auto(matches, &samples, &sources, &display);
+    }
+
+    if matches.is_present("robust") {
+        return display_robust(matches, &samples, &sources, &display);
+    }
+
+    let regression = regression(matches, &samples);
+    let non_inferiority = non_inferiority(matches, &opts, &samples);
+
+    if !display_alternatives(matches, &sources, &samples, &display, true) {
+        display_comparison(&samples, &sources, &display);
+        print_regression(regression.as_ref(), &display);
+        print_non_inferiority(non_inferiority.as_ref(), &display);
+    }
+
+    exit_on_regression(regression.as_ref(), display.alpha);
+    exit_unless_non_inferior(non_inferiority.as_ref());
+}
+
+/// Summarize samples, followed by a matrix of the effect size by `measure` of
+/// the sample of each column relative to that of each row.
+fn display_effect_sizes(matches: &ArgMatches, measure: &str, samples: &[Summarizer],
+                        sources: &[String], display: &Display) {
+    if samples.len() < 2 {
+        ok!(Err(format!("Effect sizes compare at least two samples, not {}", samples.len())));
+    }
+
+    let summaries = summaries(samples);
+    let (name, key) = match measure {
+        "cliff" => ("Cliff's delta", "cliffs_delta"),
+        _ => ("Cohen's d", "cohens_d"),
+    };
+    let matrix: Vec<Vec<f64>> = (0..samples.len())
+        .map(|i| {
+            (0..samples.len())
+                .map(|j| match measure {
+                    "cliff" => cliffs_delta(&samples[i], &samples[j]),
+                    _ => cohens_d(&summaries[i], &summaries[j]),
+                })
+                .collect()
+        })
+        .collect();
+
+    if let Some(path) = matches.value_of("svg") {
+        ok!(write_svg(path, samples, &display.plot));
+    }
+
+    let format = matches.value_of("format");
+
+    if format == Some("json") {
+        let mut fields = json_unit(display);
+        fields.push(("summaries", json::summaries(sources, samples, &display.all_fields())));
+        fields.push(("effect_sizes", json::effect_sizes(key, sources, &matrix)));
+        return print!("{}", json::object(fields).render());
+    }
+
+    if format == Some("long") {
+        display_long(samples, sources, display, false);
+        println!();
+        return print!("{}", long::effect_sizes(key, &matrix, display.precision));
+    }
+
+    if let Some(sep) = separator(format) {
+        display_delimited(samples, sources, display, false, sep);
+        println!();
+        return print!("{}", delimited::effect_size_table(sources, &matrix, sep));
+    }
+
+    let mut t = sources.iter().fold(Table::new().column("", Align::Left, 0), |t, src| {
+        t.column(src, Align::Right, 0)
+    });
+    for (src, row) in sources.iter().zip(&matrix) {
+        let mut cells = vec![src.clone()];
+        cells.extend(row.iter().map(|&x| fmt::cell(x, 10, display.precision)));
+        t.row(cells);
+    }
+
+    if format == Some("markdown") {
+        display_markdown(samples, sources, display, false);
+        println!();
+        println!("{} of each column relative to each row:", name);
+        println!();
+        return print!("{}", t.markdown());
+    }
+
+    display_summaries(samples, sources, display);
+    println!();
+    println!("{} of each column relative to each row:", name);
+    print!("{}", t.text());
+}
+
+/// Summarize samples, followed by the maximum-likelihood fit of each family of
+/// distributions to each sample, best first by AIC.
+fn display_fits(matches: &ArgMatches, samples: &[Summarizer], sources: &[String],
+                display: &Display) {
+    let fits: Vec<Vec<Fit>> = samples.iter().map(|s| ok!(fit_all(s.as_slice()))).collect();
+
+    if let Some(path) = matches.value_of("svg") {
+        ok!(write_svg(path, samples, &display.plot));
+    }
+
+    let format = matches.value_of("format");
+
+    if format == Some("json") {
+        let mut fields = json_unit(display);
+        fields.push(("summaries", json::summaries(sources, samples, &display.all_fields())));
+        let fits = sources.iter().zip(&fits).map(|(src, f)| json::fits(src, f)).collect();
+        fields.push(("fits", json::Value::Array(fits)));
+        return print!("{}", json::object(fields).render());
+    }
+
+    let markdown = match format {
+        Some("markdown") => true,
+        Some("text") | None => false,
+        _ => ok!(Err("Fits are only printed as text, Markdown, or JSON")),
+    };
+
+    if markdown {
+        display_markdown(samples, sources, display, false);
+    } else {
+        display_summaries(samples, sources, display);
+    }
+
+    for (src, fits) in sources.iter().zip(&fits) {
+        let mut t = Table::new()
+            .column("Distribution", Align::Left, 0)
+            .column("Parameters", Align::Left, 0)
+            .column("Log-likelihood", Align::Right, 0)
+            .column("AIC", Align::Right, 0)
+            .column("KS D", Align::Right, 0);
+        for f in fits {
+            let parameters: Vec<String> = f.family.parameter_names()
+                .iter()
+                .zip(&f.parameters)
+                .map(|(name, &p)| format!("{} = {}", name, fmt::cell(p, 10, display.precision)))
+                .collect();
+
+            t.row(vec![
+                f.family.name().to_string(),
+                parameters.join(", "),
+                fmt::cell(f.log_likelihood, 10, display.precision),
+                fmt::cell(f.aic, 10, display.precision),
+                fmt::cell(f.ks, 10, display.precision),
+            ]);
+        }
+
+        println!();
+        println!("Fits to {}, best first by AIC:", src);
+        if markdown {
+            println!();
+            print!("{}", t.markdown());
+            println!();
+        } else {
+            print!("{}", t.text());
+        }
+        println!("Best fit: {}", fits[0].family.name());
+    }
+}
+
+/// The result of the test chosen by `--auto`.
+enum AutoResult {
+    Welch(TTest),
+    MannWhitney(MannWhitney),
+    Permutation(Permutation),
+}
+
+/// Check the assumptions of tests of two samples, then compare them by the
+/// test those suit, explaining why it was chosen.
+fn display_auto(matches: &ArgMatches, samples: &[Summarizer], sources: &[String],
+                display: &Display) {
+    if samples.len() != 2 {
+        ok!(Err(format!("--auto compares exactly two samples, not {}", samples.len())));
+    }
+    reject_rates(display.rates(), "with --auto");
+
+    let (s1, s2) = (&samples[0], &samples[1]);
+    let (summary1, summary2) = (Summary::from(s1), Summary::from(s2));
+    let names = [sources[0].as_str(), sources[1].as_str()];
+
+    let advice = ok!(advise(s1, s2, display.alpha));
+    let result = match advice.test {
+        Test::Welch => AutoResult::Welch(ok!(welch_t_test(&summary1, &summary2))),
+        Test::MannWhitney => AutoResult::MannWhitney(ok!(mann_whitney(s1, s2))),
+        Test::Permutation => AutoResult::Permutation(ok!(permutation_test(s1, s2, RESAMPLES))),
+    };
+    let p = match result {
+        AutoResult::Welch(ref t) => t.p,
+        AutoResult::MannWhitney(ref mw) => mw.p,
+        AutoResult::Permutation(ref perm) => perm.p,
+    };
+    decide(p, display.alpha);
+
+    if let Some(path) = matches.value_of("svg") {
+        ok!(write_svg(path, samples, &display.plot));
+    }
+
+    match matches.value_of("format") {
+        Some("json") => {
+            let mut fields = json_unit(display);
+            fields.push(("summaries", json::summaries(sources, samples, &display.all_fields())));
+            fields.push(("advice", json::advice(&advice, names)));
+            fields.push(("test", match result {
+                AutoResult::Welch(ref t) => {
+                    json::t_test(t, &summary1, &summary2, Alternative::TwoSided)
+                },
+                AutoResult::MannWhitney(ref mw) => json::mann_whitney(mw),
+                AutoResult::Permutation(ref perm) => json::permutation(perm),
+            }));
+            return print!("{}", json::object(fields).render());
+        },
+        Some("text") | None => {},
+        _ => ok!(Err("--auto output is only text or JSON")),
+    }
+
+    if display.draw_plot {
+        display_plot(&[&summary1, &summary2], sources, display);
+    }
+    print_summaries(samples, sources, display);
+
+    let w = fmt::LABEL_WIDTH;
+    let f = |x| fmt::stat(x, display.precision.or(Some(3)));
+
+    println!();
+    println!("Checks of assumptions:");
+    for (i, sw) in advice.normality.iter().enumerate() {
+        let label = if i == 0 { "Normality" } else { "" };
+        let check = match *sw {
+            Some(ref sw) => format!("Shapiro-Wilk W = {}, p = {}", f(sw.w), f(sw.p)),
+            None => "not checked".to_string(),
+        };
+        println!("{:>w$}{} {}: {}", label, if i == 0 { ":" } else { " " }, names[i], check, w = w);
+    }
+    let variances = match advice.variances {
+        Some(ref v) => format!("Brown-Forsythe F = {}, p = {}", f(v.f), f(v.p)),
+        None => "not checked".to_string(),
+    };
+    println!("{:>w$}: {}", "Variances", variances, w = w);
+    println!("{:>w$}: {} and {}", "Sizes", advice.sizes[0], advice.sizes[1], w = w);
+
+    println!();
+    println!("Test: {}", advice.test.name());
+    println!("{}.", advice.explain(names));
+    println!();
+
+    let detail = mean_change(&summary1, &summary2, display.level)
+        .ok()
+        .and_then(|c| direction(names[0], names[1], &c, display));
+    match result {
+        AutoResult::Welch(ref t) => {
+            print_t_test(t, &summary1, &summary2, display);
+            print_verdict(&verdict("Difference in means", t.p, detail, display), t.p, display);
+        },
+        AutoResult::MannWhitney(ref mw) => {
+            print_stats(mw, display.precision);
+            print_verdict(&verdict("Difference in distributions", mw.p, None, display),
+                          mw.p, display);
+        },
+        AutoResult::Permutation(ref perm) => {
+            print_stats(perm, display.precision);
+            print_verdict(&verdict("Difference in means", perm.p, detail, display),
+                          perm.p, display);
+        },
+    }
+}
+
+/// Compare two samples robustly, by the difference of their medians, the
+/// Hodges-Lehmann shift with its confidence interval, and the Mann-Whitney U
+/// test, which rank the values rather than average them.
+fn display_robust(matches: &ArgMatches, samples: &[Summarizer], sources: &[String],
+                  display: &Display) {
+    if samples.len() != 2 {
+        ok!(Err(format!("--robust compares exactly two samples, not {}", samples.len())));
+    }
+
+    let (s1, s2) = (&samples[0], &samples[1]);
+    let hl = ok!(hodges_lehmann(s1, s2, confidence(matches)));
+    let mw = ok!(mann_whitney(s1, s2));
+    decide(mw.p, display.alpha);
+
+    if let Some(path) = matches.value_of("svg") {
+        ok!(write_svg(path, samples, &display.plot));
+    }
+
+    match matches.value_of("format") {
+        Some("json") => {
+            let mut fields = json_unit(display);
+            fields.push(("summaries", json::summaries(sources, samples, &display.all_fields())));
+            fields.push(("median_difference", json::Value::Number(s2.median() - s1.median())));
+            fields.push(("hodges_lehmann", json::hodges_lehmann(&hl)));
+            fields.push(("test", json::mann_whitney(&mw)));
+            return print!("{}", json::object(fields).render());
+        },
+        Some("text") | None => {},
+        _ => ok!(Err("--robust output is only text or JSON")),
+    }
+
+    if display.draw_plot {
+        let summaries = summaries(samples);
+        display_plot(&[&summaries[0], &summaries[1]], sources, display);
+    }
+    print_summaries(samples, sources, display);
+
+    println!();
+    println!("{:>w$} = {}", "Δ median", fmt::stat(s2.median() - s1.median(), display.precision),
+             w = fmt::LABEL_WIDTH);
+    print_stats(&hl, display.precision);
+    print_stats(&mw, display.precision);
+    print_verdict(&verdict("Shift in location", mw.p, None, display), mw.p, display);
+}
+
+/// Print test results by their `Display` format, to `precision` significant
+/// digits if given.
+fn print_stats<T: std::fmt::Display>(stats: &T, precision: Option<usize>) {
+    match precision {
+        Some(p) => print!("{:.*}", p, stats),
+        None => print!("{}", stats),
+    }
+}
+
+/// Summarize samples, comparing two with a t-test, and more with an ANOVA if
+/// they are labeled.
+fn display_comparison(samples: &[Summarizer], sources: &[String], display: &Display) {
+    match samples.len() {
+        0 => unreachable!(),
+        // We want match 1 with the case `len()` > 2.
+        2 => display_t_test(samples, sources, display),
+        // Samples split from one input are comparable, so compare them too.
+        n if n > 2 && display.labels => {
+            reject_rates(display.rates(), "by an ANOVA");
+            let summaries = summaries(samples);
+            let summary_refs: Vec<&Summary> = summaries.iter().collect();
+            let anova = one_way(&summary_refs, display.alpha);
+
+            display_anova(&anova, samples, sources, display)
+        },
+        _ => display_summaries(samples, sources, display),
+    };
+}
+
+fn run_summary(matches: &ArgMatches) {
+    let mut display = display_options(matches);
+
+    if matches.is_present("follow") {
+        return run_follow(matches, display);
+    }
+
+    if let Some(width) = matches.value_of("bucket") {
+        return run_buckets(matches, ok!(timestamp::parse_interval(width)), display);
+    }
+
+    let opts = input_options(matches);
+    let (sources, samples) = load_samples(matches, &opts, &mut display);
+    display.unit = opts.unit();
+
+    if matches.is_present("fit") {
+        return display_fits(matches, &samples, &sources, &display);
+    }
+
+    if display_alternatives(matches, &sources, &samples, &display, false) {
+        return;
+    }
+
+    display_summaries(&samples, &sources, &display);
+}
+
+/// Read values from stdin as they arrive, redrawing their summary every
+/// `--interval` seconds if there are new values, and once more at the end.
+/// The screen is cleared before each redraw if stdout is a terminal.
+/// Summarize the values of an input of timestamped values in buckets `width`
+/// seconds long, aligned to the Unix epoch, as samples named by the start of
+/// each bucket. Buckets without values are left out.
+fn run_buckets(matches: &ArgMatches, width: f64, mut display: Display) {
+    let opts = input_options(matches);
+
+    let files: Vec<&str> = matches.values_of("files").map(|f| f.collect()).unwrap_or_default();
+    if files.len() > 1 {
+        ok!(Err("--bucket summarizes a single input"));
+    }
+    let path = files.first().cloned().filter(|_| !matches.is_present("stdin"));
+
+    let time = match matches.value_of("time_col") {
+        Some(col) => ok!(input::Column::parse(col)),
+        None => input::Column::Index(1),
+    };
+    let value = match opts.column {
+        Some(ref col) if matches.is_present("col") || matches.is_present("field") => col.clone(),
+        _ => input::Column::Index(2),
+    };
+
+    let data = ok!(open_input(path)
+        .and_then(|r| input::read_timestamped(r, &time, &value, &opts))
+        .map_err(|e| input::locate(path.unwrap_or("stdin"), e)));
+
+    let mut buckets: BTreeMap<i64, Vec<f64>> = BTreeMap::new();
+    for (t, x) in data {
+        buckets.entry((t / width).floor() as i64).or_default().push(x);
+    }
+
+    let sources: Vec<String> = buckets
+        .keys()
+        .map(|&b| timestamp::format(b as f64 * width))
+        .collect();
+    let samples = ok!(summarize(buckets.into_values().collect(), display.transform));
+
+    display.unit = opts.unit();
+    display.labels = true;
+    if display.fields.is_none() {
+        display.fields = Some(vec![Field::Size, Field::Mean, Field::Percentile(0.95)]);
+    }
+
+    if display_alternatives(matches, &sources, &samples, &display, false) {
+        return;
+    }
+
+    // One row per bucket, rather than a table per sample.
+    let fields = display.table_fields();
+    let mut t = fields
+        .iter()
+        .fold(Table::new().column("Bucket", Align::Left, 0),
+              |t, f| t.column(&f.title(), Align::Right, 0));
+    for (s, src) in samples.iter().zip(&sources) {
+        let mut row = vec![src.clone()];
+        row.extend(fields.iter().map(|f| fmt::cell(f.value(s), 10, display.precision)));
+        t.row(row);
+    }
+
+    print_unit(&display);
+    print!("{}", t.text());
+}
+
+fn run_follow(matches: &ArgMatches, mut display: Display) {
+    let opts = input_options(matches);
+    let transform = display.transform;
+    let name = input_names(matches, &[None]).remove(0);
+    let clear = io::stdout().is_terminal();
+
+    let interval = match matches.value_of("interval").unwrap_or("1").parse::<f64>() {
+        Ok(secs) if secs > 0.0 && secs.is_finite() => Duration::from_secs_f64(secs),
+        _ => ok!(Err("The interval must be a positive number of seconds")),
+    };
+
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut data = vec![];
+    let mut n = 0;
+    let mut drawn = 0;
+    let mut next = Instant::now() + interval;
+    let redraw = |data: &[f64], first: bool, display: &mut Display| {
+        let sample = ok!(sample(data.to_vec(), transform));
+        display.unit = opts.unit();
+
+        if clear {
+            print!("\x1b[2J\x1b[H");
+        } else if !first {
+            println!();
+        }
+        display_summaries(&[sample], std::slice::from_ref(&name), display);
+        ok!(io::stdout().flush());
+    };
+
+    loop {
+        match rx.recv_timeout(next.saturating_duration_since(Instant::now())) {
+            Ok(line) => {
+                n += 1;
+                ok!(input::push_value(ok!(line).as_bytes(), n, &opts, &mut data)
+                    .map_err(|e| input::locate("stdin", e.into())));
+            },
+            Err(RecvTimeoutError::Timeout) => {},
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+
+        if Instant::now() >= next {
+            if data.len() > drawn {
+                redraw(&data, drawn == 0, &mut display);
+                drawn = data.len();
+            }
+            next = Instant::now() + interval;
+        }
+    }
+
+    if data.is_empty() {
+        ok!(Err("No samples were read"));
+    }
+    if data.len() > drawn {
+        redraw(&data, drawn == 0, &mut display);
+    }
+}
+
+fn run_t_test(matches: &ArgMatches) {
+    let mut display = display_options(matches);
+    let opts = input_options(matches);
+
+    if matches.is_present("paired") {
+        return run_paired_t_test(matches, display);
+    }
+
+    let (sources, samples) = load_samples(matches, &opts, &mut display);
+    display.unit = opts.unit();
+
+    if samples.len() != 2 {
+        ok!(Err(format!("A t-test compares exactly two samples, not {}", samples.len())));
+    }
+
+    if matches.is_present("auto") {
+        return display_auto(matches, &samples, &sources, &display);
+    }
+
+    if matches.is_present("robust") {
+        return display_robust(matches, &samples, &sources, &display);
+    }
+
+    let regression = regression(matches, &samples);
+    let non_inferiority = non_inferiority(matches, &opts, &samples);
+
+    if !display_alternatives(matches, &sources, &samples, &display, true) {
+        display_t_test(&samples, &sources, &display);
+        print_regression(regression.as_ref(), &display);
+        print_non_inferiority(non_inferiority.as_ref(), &display);
+    }
+
+    exit_on_regression(regression.as_ref(), display.alpha);
+    exit_unless_non_inferior(non_inferiority.as_ref());
+}
+
+/// A test for a regression of more than a margin, with the margin.
+struct Regression {
+    margin: f64,
+    t_test: TTest,
+}
+
+/// With `--max-regression`, test whether the mean of the second of two
+/// samples exceeds that of the first, the baseline, by more than the margin.
+fn regression(matches: &ArgMatches, samples: &[Summarizer]) -> Option<Regression> {
+    matches.value_of("max_regression")?;
+    reject_rates(transform(matches).is_some_and(|t| t.is_rate()), "with --max-regression");
+
+    if samples.len() != 2 {
+        ok!(Err(format!("A regression test compares exactly two samples, a baseline and a \
+                         new sample, not {}", samples.len())));
+    }
+
+    regression_of(matches, &Summary::from(&samples[0]), &Summary::from(&samples[1]))
+}
+
+fn regression_of(matches: &ArgMatches, baseline: &Summary, new: &Summary) -> Option<Regression> {
+    let margin = matches.value_of("max_regression")?;
+    let margin = match margin.strip_suffix('%') {
+        Some(pct) => pct.parse::<f64>().map(|m| m / 100.0),
+        None => margin.parse::<f64>(),
+    };
+    let margin = match margin {
+        Ok(m) if m.is_finite() && m >= 0.0 => m,
+        _ => ok!(Err("The maximum regression must be a percentage, such as 5%, or a fraction")),
+    };
+
+    let t_test = ok!(regression_t_test(baseline, new, margin));
+
+    Some(Regression { margin, t_test })
+}
+
+fn print_regression(regression: Option<&Regression>, display: &Display) {
+    let r = match regression {
+        Some(r) => r,
+        None => return,
+    };
+
+    // Round away the error of dividing by 100 when parsing.
+    let pct = (r.margin * 100.0 * 1e9).round() / 1e9;
+    let verdict = if display.significant(r.t_test.p) { "yes" } else { "no" };
+    let p = fmt::stat(r.t_test.p, display.precision);
+
+    println!();
+    println!("Regression of more than {}%: {}", pct, verdict);
+    display.print_p(&format!("  one-sided p = {}", p), r.t_test.p);
+}
+
+/// Exit with status 1 if a regression test found a regression significant at
+/// level `alpha`.
+fn exit_on_regression(regression: Option<&Regression>, alpha: f64) {
+    if regression.is_some_and(|r| r.t_test.p < alpha) {
+        process::exit(1);
+    }
+}
+
+/// With `--non-inferiority`, test whether the mean of the second of two
+/// samples is worse than that of the first, the baseline, by less than the
+/// margin, at the confidence level of the significance level.
+fn non_inferiority(matches: &ArgMatches, opts: &input::Options, samples: &[Summarizer])
+                   -> Option<NonInferiority> {
+    matches.value_of("non_inferiority")?;
+    reject_rates(transform(matches).is_some_and(|t| t.is_rate()), "with --non-inferiority");
+
+    if samples.len() != 2 {
+        ok!(Err(format!("A non-inferiority test compares exactly two samples, a baseline and a \
+                         new sample, not {}", samples.len())));
+    }
+
+    non_inferiority_of(matches, opts, &Summary::from(&samples[0]), &Summary::from(&samples[1]))
+}
+
+fn non_inferiority_of(matches: &ArgMatches, opts: &input::Options, baseline: &Summary,
+                      new: &Summary) -> Option<NonInferiority> {
+    let spec = matches.value_of("non_inferiority")?;
+    let invalid = || format!("Invalid margin {:?}; expected a percentage, such as 5%, or a \
+                              difference, such as 2ms", spec);
+
+    // A margin with a unit is converted as the data are, and one without is
+    // taken to be in the unit of the data.
+    let margin = match spec.strip_suffix('%').map(|pct| pct.parse::<f64>()) {
+        Some(Ok(pct)) => Margin::Relative(pct / 100.0),
+        Some(Err(_)) => ok!(Err(invalid())),
+        None => match unit::parse(spec) {
+            Ok((x, None)) => Margin::Absolute(x),
+            Ok((x, unit)) => Margin::Absolute(ok!(opts.normalize(x, unit)
+                .map_err(|e| format!("The margin {:?} {}", spec, e)))),
+            Err(_) => ok!(Err(invalid())),
+        },
+    };
+    if !(margin.value() >= 0.0 && margin.value().is_finite()) {
+        ok!(Err(invalid()));
+    }
+
+    Some(ok!(non_inferiority::non_inferiority(baseline, new, margin, 1.0 - alpha(matches))))
+}
+
+fn print_non_inferiority(non_inferiority: Option<&NonInferiority>, display: &Display) {
+    let ni = match non_inferiority {
+        Some(ni) => ni,
+        None => return,
+    };
+
+    println!();
+    println!("Non-inferior: {}", if ni.non_inferior() { "yes" } else { "no" });
+    print_stats(ni, display.precision);
+}
+
+/// Exit with status 1 if a non-inferiority test did not show the new sample
+/// to be non-inferior.
+fn exit_unless_non_inferior(non_inferiority: Option<&NonInferiority>) {
+    if non_inferiority.is_some_and(|ni| !ni.non_inferior()) {
+        process::exit(1);
+    }
+}
+
+fn run_paired_t_test(matches: &ArgMatches, mut display: Display) {
+    let opts = input_options(matches);
+    let (sources, pairs) = load_pairs(matches, &opts);
+    display.unit = opts.unit();
+    let display = &display;
+    reject_rates(display.rates(), "in pairs");
+    let (a, b): (Vec<f64>, Vec<f64>) = pairs.iter().cloned().unzip();
+    let samples = [ok!(Summarizer::new(&a)), ok!(Summarizer::new(&b))];
+
+    if matches.is_present("differences") {
+        return display_differences(matches, &pairs, &sources, display);
+    }
+
+    if display_alternatives(matches, &sources, &samples, display, false) {
+        return;
+    }
+
+    let t_test = ok!(paired_t_test(&pairs).and_then(|t| t.with_alternative(display.alternative)));
+    decide(t_test.p, display.alpha);
+    let diffs: Vec<f64> = pairs.iter().map(|&(a, b)| b - a).collect();
+    let diffs = ok!(Summary::new(&diffs));
+
+    if display.draw_plot {
+        let summaries = summaries(&samples);
+        display_plot(&[&summaries[0], &summaries[1]], &sources, display);
+    }
+
+    print_summaries(&samples, &sources, display);
+    println!();
+    print_paired_t_test(&t_test, &diffs, display);
+
+    let summaries = summaries(&samples);
+    let change = ok!(mean_change(&summaries[0], &summaries[1], display.level));
+    let detail = direction(&sources[0], &sources[1], &change, display);
+    print_verdict(&verdict(difference(display.alternative, true), t_test.p, detail, display),
+                  t_test.p, display);
+}
+
+/// Analyze the differences `b - a` of pairs themselves: their summary, the
+/// confidence interval of their mean, how many are positive, negative, and
+/// zero, and their histogram.
+fn display_differences(matches: &ArgMatches, pairs: &[(f64, f64)], sources: &[String],
+                       display: &Display) {
+    let diffs: Vec<f64> = pairs.iter().map(|&(a, b)| b - a).collect();
+    let s = ok!(Summarizer::new(&diffs));
+    let source = format!("{} - {}", sources[1], sources[0]);
+
+    let level = confidence(matches);
+    let (lower, upper) = ok!(s.mean_ci(level));
+    let signs = [
+        diffs.iter().filter(|&&d| d > 0.0).count(),
+        diffs.iter().filter(|&&d| d < 0.0).count(),
+        diffs.iter().filter(|&&d| d == 0.0).count(),
+    ];
+    let hist = ok!(Histogram::with_sturges_bins(&diffs));
+
+    if let Some(path) = matches.value_of("svg") {
+        let mut f = ok!(create_file(path));
+        ok!(plot::svg::histogram_plot_to(&mut f, &hist, &display.plot));
+    }
+
+    match matches.value_of("format") {
+        Some("json") => {
+            let mut fields = json_unit(display);
+            fields.push(("differences", json::differences(&source, &s, &display.all_fields(),
+                                                          level, (lower, upper), signs, &hist)));
+            return print!("{}", json::object(fields).render());
+        },
+        Some("text") | None => {},
+        _ => ok!(Err("--differences output is only text or JSON")),
+    }
+
+    let w = fmt::LABEL_WIDTH;
+    let f = |x| fmt::stat(x, display.precision);
+    let share = |n: usize| fmt::percent(n as f64 / diffs.len() as f64, display.precision);
+
+    let mean = s.mean();
+    print_summaries(&[s], &[source], display);
+    println!();
+    println!("{:>w$} = {}", "Mean Δ", f(mean), w = w);
+    println!("{:>w$} = {} to {}", format!("{} CI", fmt::level(level)), f(lower), f(upper), w = w);
+    for (label, &n) in ["Positive", "Negative", "Zero"].iter().zip(&signs) {
+        println!("{:>w$} = {} ({})", label, n, share(n), w = w);
+    }
+    println!();
+    println!("{}", ok!(plot::histogram_plot(&hist, &display.plot)));
+}
+
+fn run_anova(matches: &ArgMatches) {
+    let mut display = display_options(matches);
+    let opts = input_options(matches);
+    let (sources, samples) = load_samples(matches, &opts, &mut display);
+    display.unit = opts.unit();
+    reject_rates(display.rates(), "by an ANOVA");
+    let summaries = summaries(&samples);
+    let summary_refs: Vec<&Summary> = summaries.iter().collect();
+
+    if let Some(path) = matches.value_of("svg") {
+        ok!(write_svg(path, &samples, &display.plot));
+    }
+
+    let anova = one_way(&summary_refs, display.alpha);
+
+    let format = matches.value_of("format");
+
+    if format == Some("json") {
+        let mut fields = json_unit(&display);
+        fields.push(("summaries", json::summaries(&sources, &samples, &display.all_fields())));
+        fields.push(("anova", json::anova(&anova)));
+        return print!("{}", json::object(fields).render());
+    }
+
+    if format == Some("long") {
+        display_long(&samples, &sources, &display, false);
+        println!();
+        return print!("{}", long::anova(&anova, display.precision));
+    }
+
+    if let Some(sep) = separator(format) {
+        let fields = display.all_fields();
+        let unit = display.unit_label();
+        return print!("{}", delimited::anova_table(&sources, &samples, &fields, unit.as_deref(),
+                                                   &anova, sep));
+    }
+
+    display_anova(&anova, &samples, &sources, &display);
+}
+
+fn display_anova(anova: &Anova, samples: &[Summarizer], sources: &[String], display: &Display) {
+    display_summaries(samples, sources, display);
+    println!();
+    print_anova(anova, display);
+    print_verdict(&verdict("Difference in means", anova.p, None, display), anova.p, display);
+}
+
+/// The time since the earliest of timestamped pairs `(t, y)`, in units of
+/// `per` seconds, as x, with the earliest time.
+fn time_since_origin(data: &mut [(f64, f64)], per: f64) -> f64 {
+    let origin = data.iter().map(|&(t, _)| t).fold(f64::INFINITY, f64::min);
+
+    for p in data.iter_mut() {
+        p.0 = (p.0 - origin) / per;
+    }
+
+    origin
+}
+
+fn run_lr(matches: &ArgMatches) {
+    let lax_parsing = matches.is_present("lax");
+    let timestamps = matches.is_present("timestamps");
+    let opts = input_options(matches);
+
+    let read = |r| if timestamps {
+        input::read_timestamped(r, &input::Column::Index(1), &input::Column::Index(2), &opts)
+    } else {
+        input::read_pairs(r, lax_parsing)
+    };
+    let mut data = match matches.value_of("file") {
+        Some(path) => ok!(input::open_file(path)
+            .and_then(read)
+            .map_err(|e| input::locate(path, e))),
+        None => ok!(input::open_stdin().and_then(read).map_err(|e| input::locate("stdin", e))),
+    };
+
+    // With timestamps, x is the time since the earliest, in units of `per`.
+    let per = matches.value_of("per").unwrap_or("day");
+    let origin = if timestamps {
+        let seconds = match per {
+            "second" => 1.0,
+            "minute" => 60.0,
+            "hour" => 3600.0,
+            "day" => 86400.0,
+            _ => 604800.0,
+        };
+        Some(timestamp::format(time_since_origin(&mut data, seconds)))
+    } else {
+        None
+    };
+
+    let lr = ok!(LinearRegression::new(&data));
+
+    let format = matches.value_of("format");
+
+    if format == Some("json") {
+        let mut fields = vec![("linear_regression", json::linear_regression(&lr))];
+        if let Some(ref origin) = origin {
+            fields.push(("per", json::Value::String(per.to_string())));
+            fields.push(("origin", json::Value::String(origin.clone())));
+        }
+        if let Some(unit) = opts.unit() {
+            fields.push(("unit", json::Value::String(unit.to_string())));
+        }
+        return print!("{}", json::object(fields).render());
+    }
+
+    if format == Some("long") {
+        return print!("{}", long::linear_regression(&lr, precision(matches)));
+    }
+
+    if let Some(sep) = separator(format) {
+        return print!("{}", delimited::linear_regression_table(&lr, sep));
+    }
+
+    if let Some(path) = matches.value_of("svg") {
+        let mut f = ok!(create_file(path));
+        ok!(plot::svg::scatter_plot_to(&mut f, &data, &plot_options(matches)));
+    }
+
+    if matches.is_present("plot") {
+        let opts = plot_options(matches);
+
+        println!("{}\n", ok!(plot::scatter_plot(&data, &opts)));
+    }
+
+    if let Some(origin) = origin {
+        println!("x: {}s since {}", per, origin);
+        match opts.unit() {
+            Some(unit) => println!("Unit: {}\n", unit),
+            None => println!(),
+        }
+    }
+
+    print_linear_regression(&lr, precision(matches));
+}
+
+/// Read the values of the `file` argument, or of stdin if there is none, and
+/// apply any `--transform`.
+fn read_input(matches: &ArgMatches, opts: &input::Options) -> Vec<f64> {
+    let data = match matches.value_of("file") {
+        Some(path) => ok!(input::read_file(path, opts)),
+        None => ok!(input::read_stdin(opts)),
+    };
+
+    ok!(transform::apply(transform(matches), data))
+}
+
+fn run_hist(matches: &ArgMatches) {
+    let opts = input_options(matches);
+
+    let data = read_input(matches, &opts);
+
+    let bins = match matches.value_of("bins") {
+        Some(bins) => ok!(bins.parse::<usize>()),
+        None => histogram::sturges_bins(data.len()),
+    };
+    let hist = if matches.is_present("equal_frequency") {
+        ok!(Histogram::with_equal_frequency_bins(&data, bins))
+    } else {
+        ok!(Histogram::new(&data, bins))
+    };
+
+    let opts = plot_options(matches);
+
+    if let Some(path) = matches.value_of("svg") {
+        let mut f = ok!(create_file(path));
+        ok!(plot::svg::histogram_plot_to(&mut f, &hist, &opts));
+    }
+
+    println!("{}", ok!(plot::histogram_plot(&hist, &opts)));
+}
+
+/// Print the z-score of each value of an input, one per line, in order.
+fn run_zscore(matches: &ArgMatches) {
+    let opts = input_options(matches);
+
+    let data = read_input(matches, &opts);
+    let z = ok!(transform::z_scores(data, matches.is_present("robust")));
+    let precision = precision(matches);
+
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+
+    for x in z {
+        ok!(writeln!(out, "{}", fmt::stat(x, precision)));
+    }
+    ok!(out.flush());
+}
+
+/// Print the values of an input as they are analyzed, after parsing and any
+/// transform, one per line, in order, without outliers if asked.
+fn run_clean(matches: &ArgMatches) {
+    let opts = input_options(matches);
+    let mut data = read_input(matches, &opts);
+
+    if matches.is_present("drop_outliers") {
+        let s = ok!(Summarizer::new(&data));
+        let (min, max) = (s.min_adjacent(), s.max_adjacent());
+        data.retain(|x| (min..=max).contains(x));
+    }
+
+    let precision = precision(matches);
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+
+    for x in data {
+        ok!(writeln!(out, "{}", fmt::stat(x, precision)));
+    }
+    ok!(out.flush());
+}
+
+/// Print a table of the count and proportions of each distinct value of an
+/// input, with a bar of each count in text.
+fn run_freq(matches: &ArgMatches) {
+    let opts = input_options(matches);
+    let data = read_input(matches, &opts);
+    let frequencies = ok!(frequencies(&data));
+    let precision = precision(matches);
+
+    let format = matches.value_of("format");
+
+    if format == Some("json") {
+        let fields = vec![("frequencies", json::frequencies(&frequencies))];
+        return print!("{}", json::object(fields).render());
+    }
+
+    let titles = ["Value", "Count", "Percent", "Cumulative"];
+    let rows: Vec<Vec<String>> = frequencies
+        .iter()
+        .map(|f| vec![
+            fmt::stat(f.value, precision),
+            f.count.to_string(),
+            fmt::percent(f.proportion, precision),
+            fmt::percent(f.cumulative, precision),
+        ])
+        .collect();
+
+    let mut t = titles.iter().fold(Table::new(), |t, title| t.column(title, Align::Right, 0));
+
+    if format == Some("markdown") {
+        for row in rows {
+            t.row(row);
+        }
+        return print!("{}", t.markdown());
+    }
+
+    // Scale the bars so that of the most frequent value fills the rest of the
+    // plot width, after the other columns and their separators.
+    let used: usize = (0..titles.len())
+        .map(|i| rows.iter().map(|r| r[i].chars().count()).fold(titles[i].len(), usize::max) + 2)
+        .sum();
+    let bar_width = plot_width(matches).saturating_sub(used).max(1);
+    let bar = if matches.is_present("ascii") { "#" } else { "█" };
+    let max_count = frequencies.iter().map(|f| f.count).max().unwrap_or(1);
+
+    t = t.column("", Align::Left, 0);
+    for (mut row, f) in rows.into_iter().zip(&frequencies) {
+        let len = (f.count as f64 / max_count as f64 * bar_width as f64).round() as usize;
+        row.push(bar.repeat(len.max(1)));
+        t.row(row);
+    }
+
+    // The bars are padded to equal widths, which would leave trailing spaces.
+    for line in t.text().lines() {
+        println!("{}", line.trim_end());
+    }
+}
+
+/// Print Cohen's kappa of two raters, from their labels of each subject or
+/// their confusion matrix.
+fn run_kappa(matches: &ArgMatches) {
+    let opts = input_options(matches);
+    let weights = Weights::parse(matches.value_of("weights").unwrap_or("none"))
+        .unwrap_or_else(|| unreachable!());
+
+    let read = |r: Box<dyn BufRead>| -> Result<ConfusionMatrix, Box<dyn error::Error>> {
+        if matches.is_present("matrix") {
+            let counts = input::read_matrix(r, &opts)?;
+            let categories = (1..=counts.len()).map(|i| i.to_string()).collect();
+            return Ok(ConfusionMatrix { categories, counts });
+        }
+
+        let cols = match matches.values_of("cols") {
+            Some(cols) => cols.map(input::Column::parse).collect::<Result<Vec<_>, _>>()?,
+            None => vec![input::Column::Index(1), input::Column::Index(2)],
+        };
+        let pairs = input::read_label_pairs(r, &cols[0], &cols[1], &opts)?;
+
+        Ok(ConfusionMatrix::new(&pairs))
+    };
+
+    let matrix = match matches.value_of("file") {
+        Some(path) => ok!(input::open_file(path)
+            .and_then(read)
+            .map_err(|e| input::locate(path, e))),
+        None => ok!(input::open_stdin().and_then(read).map_err(|e| input::locate("stdin", e))),
+    };
+
+    if matrix.counts.iter().any(|row| row.len() != matrix.counts.len()) {
+        ok!(Err("A confusion matrix must have as many columns as rows"));
+    }
+
+    let kappa = ok!(cohens_kappa(&matrix.counts, weights));
+
+    if matches.value_of("format") == Some("json") {
+        let fields = vec![("kappa", json::kappa(&kappa, weights, &matrix.categories))];
+        return print!("{}", json::object(fields).render());
+    }
+
+    print_stats(&kappa, precision(matches));
+}
+
+/// Read pairs of a value and a flag, 1 for true or 0 for false, from the
+/// first two columns of an input, or those selected by `--cols`. An invalid
+/// flag is an error naming it `what` and describing its `values`.
+fn read_flagged(matches: &ArgMatches, path: Option<&str>, opts: &input::Options, what: &str,
+                values: &str) -> Vec<(f64, bool)> {
+    let cols = match matches.values_of("cols") {
+        Some(cols) => ok!(cols.map(input::Column::parse).collect::<Result<Vec<_>, _>>()),
+        None => vec![input::Column::Index(1), input::Column::Index(2)],
+    };
+
+    let pairs = ok!(open_input(path)
+        .and_then(|r| input::read_paired_columns(r, &cols[0], &cols[1], opts))
+        .map_err(|e| input::locate(path.unwrap_or("stdin"), e)));
+
+    pairs
+        .into_iter()
+        .map(|(x, flag)| match flag {
+            1.0 => (x, true),
+            0.0 => (x, false),
+            f => ok!(Err(format!("{} {} is not {}", what, f, values))),
+        })
+        .collect()
+}
+
+/// Print the area under the ROC curve of scores of labeled subjects, and plot
+/// the curve if asked.
+fn run_roc(matches: &ArgMatches) {
+    let opts = input_options(matches);
+    let data = read_flagged(matches, matches.value_of("file"), &opts, "Label",
+                            "1 for positive or 0 for negative");
+
+    let roc = ok!(Roc::new(&data, confidence(matches)));
+
+    if matches.value_of("format") == Some("json") {
+        return print!("{}", json::object(vec![("roc", json::roc(&roc))]).render());
+    }
+
+    if matches.is_present("plot") {
+        println!("{}\n", ok!(plot::roc_plot(&roc, &plot_options(matches))));
+    }
+
+    print_stats(&roc, precision(matches));
+}
+
+/// Print the Kaplan-Meier estimate of the survival of each of one or two
+/// inputs of times to events, with the median survival, and compare two by
+/// the log-rank test.
+fn run_survival(matches: &ArgMatches) {
+    let opts = input_options(matches);
+    let inputs: Vec<Option<&str>> = match matches.values_of("files") {
+        Some(files) => files.map(Some).collect(),
+        None => vec![None],
+    };
+    let names = input_names(matches, &inputs);
+
+    let data: Vec<Vec<(f64, bool)>> = inputs
+        .iter()
+        .map(|&path| read_flagged(matches, path, &opts, "Event", "1 for an event or 0 if censored"))
+        .collect();
+    let level = confidence(matches);
+    let estimates: Vec<KaplanMeier> = data
+        .iter()
+        .map(|d| ok!(KaplanMeier::new(d, level)))
+        .collect();
+    let test = match data.len() {
+        2 => Some(ok!(log_rank(&data[0], &data[1]))),
+        _ => None,
+    };
+
+    if matches.value_of("format") == Some("json") {
+        let mut fields = vec![("survival", json::survival(&names, &estimates))];
+        if let Some(ref test) = test {
+            fields.push(("log_rank", json::log_rank(test)));
+        }
+        return print!("{}", json::object(fields).render());
+    }
+
+    let precision = precision(matches);
+    let f = |x| fmt::stat(x, precision);
+
+    for (i, (name, km)) in names.iter().zip(&estimates).enumerate() {
+        if i > 0 {
+            println!();
+        }
+        if estimates.len() > 1 {
+            println!("{}:", name);
+        }
+
+        let titles = ["Time", "At risk", "Events", "Censored", "Survival", "Lower", "Upper"];
+        let mut t = titles.iter().fold(Table::new(), |t, title| t.column(title, Align::Right, 0));
+        for s in &km.steps {
+            t.row(vec![f(s.time), s.at_risk.to_string(), s.events.to_string(),
+                       s.censored.to_string(), f(s.survival), f(s.lower), f(s.upper)]);
+        }
+
+        println!("{}", t.text());
+        print_stats(km, precision);
+    }
+
+    if let Some(test) = test {
+        println!();
+        println!("Log-rank test:");
+        print_stats(&test, precision);
+    }
+}
+
+/// Print percentiles of an input with their confidence intervals, and check
+/// them against any targets, exiting with status 1 if any is missed.
+fn run_slo(matches: &ArgMatches) {
+    let opts = input_options(matches);
+    let data = read_input(matches, &opts);
+    let s = ok!(Summarizer::new(&data));
+
+    let level = confidence(matches);
+    let mut targets: Vec<Target> = matches
+        .values_of("target")
+        .map_or(vec![], |t| t.map(|t| ok!(Target::parse(t))).collect());
+    // A bound with a unit is converted as the data are, and one without is
+    // taken to be in the unit of the data.
+    for t in &mut targets {
+        if t.unit.is_some() {
+            t.bound = ok!(opts.normalize(t.bound, t.unit)
+                .map_err(|e| format!("The bound of target {:?} {}", t.spec, e)));
+        }
+    }
+
+    let mut percentiles: Vec<f64> = ok!(matches.value_of("percentiles").unwrap_or_default()
+        .split(',')
+        .map(|p| match p.trim().parse::<f64>() {
+            Ok(p) if (0.0..=100.0).contains(&p) => Ok(p / 100.0),
+            _ => Err(format!("Invalid percentile {:?}; expected a number from 0 to 100", p)),
+        })
+        .collect::<Result<Vec<f64>, String>>());
+    percentiles.extend(targets.iter().map(|t| t.percentile));
+    percentiles.sort_by(f64::total_cmp);
+    percentiles.dedup();
+
+    // Each percentile, with the bounds of its confidence interval.
+    let estimates: Vec<(f64, f64, Option<f64>, Option<f64>)> = percentiles
+        .iter()
+        .map(|&p| {
+            let (lower, upper) = ok!(s.percentile_interval(p, level));
+            (p, ok!(s.percentile(p)), lower, upper)
+        })
+        .collect();
+
+    // Whether each target is met, and whether it is met or missed with
+    // confidence. A missing bound of an interval is unbounded.
+    let results: Vec<(bool, bool)> = targets
+        .iter()
+        .map(|t| {
+            let &(_, x, lower, upper) = ok!(estimates.iter().find(|e| e.0 == t.percentile)
+                .ok_or("The percentile of a target was not estimated"));
+            let lower = lower.unwrap_or(f64::NEG_INFINITY);
+            let upper = upper.unwrap_or(f64::INFINITY);
+            (t.meets(x), t.meets(lower) == t.meets(upper))
+        })
+        .collect();
+
+    if matches.value_of("format") == Some("json") {
+        let fields = vec![("slo", json::slo(s.size(), level, &estimates, &targets, &results))];
+        print!("{}", json::object(fields).render());
+    } else {
+        let precision = precision(matches);
+        let f = |x: Option<f64>| x.map_or("NA".to_string(), |x| fmt::stat(x, precision));
+
+        if let Some(unit) = opts.unit() {
+            println!("Unit: {}\n", unit);
+        }
+
+        let ci = format!("{} CI", fmt::level(level));
+        let titles = ["Percentile", "Value", &ci];
+        let mut t = titles.iter().fold(Table::new(), |t, title| t.column(title, Align::Right, 0));
+        for &(p, x, lower, upper) in &estimates {
+            t.row(vec![Field::Percentile(p).title(), f(Some(x)),
+                       format!("{} to {}", f(lower), f(upper))]);
+        }
+        print!("{}", t.text());
+
+        if !targets.is_empty() {
+            let titles = ["Target", "Value", "Status", "Confident"];
+            let mut t = titles
+                .iter()
+                .fold(Table::new(), |t, title| t.column(title, Align::Right, 0));
+            for (target, &(met, confident)) in targets.iter().zip(&results) {
+                let x = estimates.iter().find(|e| e.0 == target.percentile).map(|e| e.1);
+                t.row(vec![target.spec.clone(), f(x),
+                           (if met { "pass" } else { "fail" }).to_string(),
+                           (if confident { "yes" } else { "no" }).to_string()]);
+            }
+            print!("\n{}", t.text());
+        }
+    }
+
+    if results.iter().any(|&(met, _)| !met) {
+        process::exit(1);
+    }
+}
+
+/// Print the segments of an input between the changes of its level, with the
+/// relative change of each from the last, or else each value of the input,
+/// in order, with a line marking each change.
+fn run_changepoint(matches: &ArgMatches) {
+    let opts = input_options(matches);
+    let data = read_input(matches, &opts);
+    let penalty = match matches.value_of("penalty").map(str::parse::<f64>) {
+        Some(Ok(x)) => Some(x),
+        Some(Err(_)) => ok!(Err("The value of --penalty must be a number")),
+        None => None,
+    };
+
+    let changes = match changepoints(&data, penalty) {
+        Ok(changes) => changes,
+        Err(_) if penalty.is_some() => ok!(Err("The value of --penalty must not be negative")),
+        Err(e) => ok!(Err(e)),
+    };
+
+    if matches.value_of("format") == Some("json") {
+        return print!("{}", json::object(vec![("changepoint", json::changepoints(&changes))])
+                      .render());
+    }
+
+    let precision = precision(matches);
+    let f = |x| fmt::stat(x, precision);
+
+    if matches.is_present("annotate") {
+        let stdout = io::stdout();
+        let mut out = io::BufWriter::new(stdout.lock());
+
+        for pair in changes.segments.windows(2) {
+            let (before, after) = (pair[0], pair[1]);
+            for &x in &data[before.start..before.end] {
+                ok!(writeln!(out, "{}", f(x)));
+            }
+            ok!(writeln!(out, "# change at {}: {} -> {} ({})", after.start + 1, f(before.mean),
+                         f(after.mean), fmt::signed_percent(after.mean / before.mean - 1.0,
+                                                            precision)));
+        }
+        if let Some(last) = changes.segments.last() {
+            for &x in &data[last.start..last.end] {
+                ok!(writeln!(out, "{}", f(x)));
+            }
+        }
+
+        return ok!(out.flush());
+    }
+
+    let titles = ["Start", "End", "Size", "Mean", "Change"];
+    let mut t = titles.iter().fold(Table::new(), |t, title| t.column(title, Align::Right, 0));
+    let mut previous: Option<f64> = None;
+    for s in &changes.segments {
+        let change = previous.map_or(String::new(), |m| fmt::signed_percent(s.mean / m - 1.0,
+                                                                            precision));
+        t.row(vec![(s.start + 1).to_string(), s.end.to_string(), s.size().to_string(), f(s.mean),
+                   change]);
+        previous = Some(s.mean);
+    }
+
+    println!("{}", t.text());
+    print_stats(&changes, precision);
+}
+
+/// Print random samples of a distribution, one per line.
+fn run_gen(matches: &ArgMatches) {
+    let count = match matches.value_of("count").map(str::parse::<usize>) {
+        Some(Ok(n)) => n,
+        _ => ok!(Err("The number of samples must be a non-negative integer")),
+    };
+    let param = |name: &str| match matches.value_of(name).map(str::parse::<f64>) {
+        Some(Ok(x)) if x.is_finite() => x,
+        _ => ok!(Err(format!("The value of --{} must be a number", name))),
+    };
+    let seed = match matches.value_of("seed").map(str::parse::<u64>) {
+        Some(Ok(seed)) => seed,
+        None => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default(),
+        _ => ok!(Err("The seed must be a non-negative integer")),
+    };
+    let precision = precision(matches);
+
+    let dist: Box<dyn Distribution> = match matches.value_of("distribution") {
+        Some("normal") => Box::new(ok!(Normal::new(param("mean"), param("sd"))
+            .map_err(|_| "The standard deviation must be positive"))),
+        Some("uniform") => Box::new(ok!(Uniform::new(param("min"), param("max"))
+            .map_err(|_| "The minimum must be less than the maximum"))),
+        Some("exponential") => Box::new(ok!(Exponential::new(param("rate"))
+            .map_err(|_| "The rate must be positive"))),
+        Some("lognormal") => Box::new(ok!(LogNormal::new(param("mean"), param("sd"))
+            .map_err(|_| "The standard deviation must be positive"))),
+        // Required, and one of the possible values.
+        _ => unreachable!(),
+    };
+
+    let mut rng = Rng::new(seed);
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+
+    for _ in 0..count {
+        let x = ok!(rng.sample(&*dist));
+        ok!(writeln!(out, "{}", fmt::stat(x, precision)));
+    }
+    ok!(out.flush());
+}
+
+/// Print a completion script for a shell, generated from the definition of the
+/// command line, so it completes the values of options such as `--format`.
+fn run_completions(matches: &ArgMatches) {
+    let shell = ok!(matches.value_of("shell").unwrap_or_default().parse::<clap::Shell>());
+
+    cli::app().gen_completions_to("dent", shell, &mut io::stdout());
+}
+
+#[cfg(feature = "tui")]
+fn run_tui(matches: &ArgMatches) {
+    let mut display = display_options(matches);
+    let opts = input_options(matches);
+    let (sources, samples) = load_samples(matches, &opts, &mut display);
+
+    ok!(tui::run(&sources, &samples, &display.plot, display.alpha));
+}
+
+#[cfg(not(feature = "tui"))]
+fn run_tui(_matches: &ArgMatches) {
+    ok!(Err("The terminal UI was not enabled at build time"));
+}
+
+fn main() {
+    // Exit quietly when output is piped to a program that stops reading it,
+    // such as `head`, rather than failing to write.
+    #[cfg(unix)]
+    unsafe {
+        libc::signal(libc::SIGPIPE, libc::SIG_DFL);
+    }
+
+    let args: Vec<String> = env::args().collect();
+    config::set(ok!(config::load(config::path_arg(&args))));
+
+    let matches = match cli::app().get_matches_from_safe(args) {
+        Ok(m) => m,
+        Err(ref e) if e.use_stderr() => {
+            eprintln!("{}", e.message);
+            process::exit(ERROR_STATUS);
+        },
+        // Help and the version, which are not errors.
+        Err(e) => e.exit(),
+    };
+    let mut sub = &matches;
+    while let (_, Some(m)) = sub.subcommand() {
+        sub = m;
+    }
+    let fail_on_reject = sub.is_present("fail_on_reject");
+
+    // Reject a bad significance level even where no test would use it.
+    alpha(sub);
+
+    if sub.is_present("progress") {
+        progress::set_mode(progress::Mode::Always);
+    }
+
+    match matches.subcommand() {
+        ("summary", Some(m)) => run_summary(m),
+        ("ttest", Some(m)) => run_t_test(m),
+        ("anova", Some(m)) => run_anova(m),
+        ("lr", Some(m)) => run_lr(m),
+        ("hist", Some(m)) => run_hist(m),
+        ("zscore", Some(m)) => run_zscore(m),
+        ("clean", Some(m)) => run_clean(m),
+        ("freq", Some(m)) => run_freq(m),
+        ("kappa", Some(m)) => run_kappa(m),
+        ("roc", Some(m)) => run_roc(m),
+        ("survival", Some(m)) => run_survival(m),
+        ("slo", Some(m)) => run_slo(m),
+        ("changepoint", Some(m)) => run_changepoint(m),
+        ("gen", Some(m)) => run_gen(m),
+        ("import", Some(m)) => run_import(m),
+        ("run", Some(m)) => run_commands(m),
+        ("baseline", Some(m)) => match m.subcommand() {
+            ("save", Some(m)) => run_baseline_save(m),
+            ("compare", Some(m)) => run_baseline_compare(m),
+            _ => unreachable!(),
+        },
+        ("report", Some(m)) => run_report(m),
+        ("tui", Some(m)) => run_tui(m),
+        ("completions", Some(m)) => run_completions(m),
+        _ => run_default(&matches),
+    }
+
+    if fail_on_reject && REJECTED.load(Ordering::Relaxed) {
+        process::exit(1);
+    }
 }
@@ -0,0 +1,86 @@
+use error::Error;
+use summary::Summarizer;
+
+
+/// Multiple of the standard deviation of the differences that bounds the 95%
+/// limits of agreement, assuming the differences are normally distributed.
+const LIMITS_Z: f64 = 1.96;
+
+/// A Bland-Altman analysis of agreement between two methods of measurement,
+/// each applied to the same subjects [1].
+///
+/// [1]: https://doi.org/10.1016/S0140-6736(86)90837-8
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BlandAltman {
+    bias: f64,
+    points: Vec<(f64, f64)>,
+    standard_deviation: f64,
+}
+
+impl BlandAltman {
+    /// Compare paired measurements of the form `(a, b)`, where `a` and `b` are
+    /// measurements of the same subject by the first and second method.
+    ///
+    /// Differences are taken as `b - a`, so a positive bias means the second
+    /// method tends to measure higher than the first. The limits of agreement
+    /// need the standard deviation of the differences, so at least two pairs.
+    pub fn new(data: &[(f64, f64)]) -> Result<Self, Error> {
+        if data.is_empty() {
+            return Err(Error::EmptySample);
+        }
+
+        let points: Vec<(f64, f64)> = data
+            .iter()
+            .map(|&(a, b)| ((a + b) / 2.0, b - a))
+            .collect();
+        let diffs: Vec<f64> = points.iter().map(|&(_, d)| d).collect();
+
+        let summ = Summarizer::new(&diffs)?;
+
+        if summ.size() < 2.0 {
+            return Err(Error::Undefined);
+        }
+
+        // The means are finite if the differences are, unless they overflowed.
+        if points.iter().any(|&(m, _)| !m.is_finite()) {
+            return Err(Error::NonFinite);
+        }
+
+        Ok(BlandAltman {
+            bias: summ.mean(),
+            points,
+            standard_deviation: summ.standard_deviation(),
+        })
+    }
+
+    /// Mean of the differences `b - a`.
+    pub fn bias(&self) -> f64 {
+        self.bias
+    }
+
+    /// Lower 95% limit of agreement.
+    pub fn lower_limit(&self) -> f64 {
+        self.bias - LIMITS_Z * self.standard_deviation
+    }
+
+    /// Upper 95% limit of agreement.
+    pub fn upper_limit(&self) -> f64 {
+        self.bias + LIMITS_Z * self.standard_deviation
+    }
+
+    /// Points `(mean, difference)` of each pair of measurements.
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    /// Number of pairs of measurements.
+    pub fn size(&self) -> f64 {
+        self.points.len() as f64
+    }
+
+    /// Standard deviation of the differences.
+    pub fn standard_deviation(&self) -> f64 {
+        self.standard_deviation
+    }
+}
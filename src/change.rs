@@ -0,0 +1,132 @@
+use dist::{Distribution, Normal};
+use error::Error;
+use summary::{Summarizer, Summary};
+use t_test::{t_critical, welch_satterthwaite_df};
+
+
+/// The default level of confidence intervals.
+pub const CONFIDENCE: f64 = 0.95;
+
+/// Multiple of IQR/√n estimating the standard error of a median: the
+/// asymptotic standard error √(π/2)·σ/√n, with σ estimated as IQR/1.349. As
+/// for the notches of notched box plots [1], it assumes near-normal data.
+///
+/// [1]: https://doi.org/10.1080/00031305.1978.10479236
+const MEDIAN_SE_PER_IQR: f64 = 0.9290831602341976;
+
+/// The change of a statistic of a new sample from that of a baseline, in
+/// absolute terms and relative to the baseline, each with the margin of error
+/// of its confidence interval, at level `confidence`. The intervals are
+/// `absolute ± absolute_margin` and `relative ± relative_margin`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Change {
+    pub absolute: f64,
+    pub absolute_margin: f64,
+    /// A fraction of the baseline, such as -0.073 for a decrease of 7.3%.
+    pub relative: f64,
+    pub relative_margin: f64,
+    pub confidence: f64,
+}
+
+impl Change {
+    /// The change from `x1` to `x2`, given their standard errors and the
+    /// critical value of the test statistic at level `confidence`. The
+    /// relative change is of the magnitude of `x1`, so that it has the sign of
+    /// the absolute change even if `x1` is negative. The relative margin is
+    /// estimated by the delta method, and is undefined (NaN) if `x1` is zero.
+    fn new(x1: f64, se1: f64, x2: f64, se2: f64, critical: f64, confidence: f64) -> Change {
+        let absolute = x2 - x1;
+        let ratio = x2 / x1;
+        let se_ratio = (se2.powi(2) + ratio.powi(2) * se1.powi(2)).sqrt() / x1.abs();
+
+        Change {
+            absolute,
+            absolute_margin: critical * (se1.powi(2) + se2.powi(2)).sqrt(),
+            relative: absolute / x1.abs(),
+            relative_margin: critical * se_ratio,
+            confidence,
+        }
+    }
+}
+
+/// The change of the mean of `new` from that of `baseline`. Its confidence
+/// intervals, at level `confidence`, use the t-distribution with
+/// Welch-Satterthwaite degrees of freedom, as `welch_t_test` does.
+pub fn mean_change(baseline: &Summary, new: &Summary, confidence: f64) -> Result<Change, Error> {
+    let df = welch_satterthwaite_df(baseline.unbiased_variance(), baseline.size(),
+                                    new.unbiased_variance(), new.size());
+    let t = t_critical(confidence, df)?;
+
+    Ok(Change::new(baseline.mean(), baseline.standard_error(),
+                   new.mean(), new.standard_error(), t, confidence))
+}
+
+/// The change of the harmonic mean of positive `new` from that of `baseline`,
+/// as of rates, whose harmonic mean is the work over the mean duration. Its
+/// confidence intervals, at level `confidence`, are by the delta method, from
+/// the standard errors of the means `m` of the reciprocals, `se / m²`, with
+/// Welch-Satterthwaite degrees of freedom, as for `mean_change` of the
+/// reciprocals.
+pub fn harmonic_mean_change(baseline: &Summarizer, new: &Summarizer, confidence: f64)
+                            -> Result<Change, Error> {
+    let r1 = Summary::from(&baseline.reciprocals()?);
+    let r2 = Summary::from(&new.reciprocals()?);
+    let df = welch_satterthwaite_df(r1.unbiased_variance(), r1.size(),
+                                    r2.unbiased_variance(), r2.size());
+    let t = t_critical(confidence, df)?;
+    let se = |r: &Summary| r.standard_error() / r.mean().powi(2);
+
+    Ok(Change::new(r1.mean().recip(), se(&r1), r2.mean().recip(), se(&r2), t, confidence))
+}
+
+/// The change of the median of `new` from that of `baseline`, with confidence
+/// intervals at level `confidence` from the asymptotic standard errors of the
+/// medians.
+pub fn median_change(baseline: &Summary, new: &Summary, confidence: f64)
+                     -> Result<Change, Error> {
+    let se = |s: &Summary| MEDIAN_SE_PER_IQR * s.iqr() / s.size().sqrt();
+
+    if baseline.size() < 2.0 || new.size() < 2.0 || !(confidence > 0.0 && confidence < 1.0) {
+        return Err(Error::Undefined);
+    }
+    let z = Normal::standard().quantile(0.5 + confidence / 2.0)?;
+
+    Ok(Change::new(baseline.median(), se(baseline), new.median(), se(new), z, confidence))
+}
+
+/// The geometric mean of several ratios, such as the speedups of the
+/// benchmarks of a suite, with its confidence interval `[lower, upper]` at
+/// level `confidence`.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GeometricMeanRatio {
+    pub ratio: f64,
+    pub lower: f64,
+    pub upper: f64,
+    pub confidence: f64,
+}
+
+/// The geometric mean of positive `ratios`. Its confidence interval, at level
+/// `confidence`, is that of the mean of their logarithms, from the
+/// t-distribution, so it needs at least two ratios.
+pub fn geometric_mean_ratio(ratios: &[f64], confidence: f64)
+                            -> Result<GeometricMeanRatio, Error> {
+    if ratios.iter().any(|&r| r <= 0.0) {
+        return Err(Error::BadSample);
+    }
+    if ratios.len() < 2 {
+        return Err(Error::Undefined);
+    }
+
+    let logs: Vec<f64> = ratios.iter().map(|r| r.ln()).collect();
+    let s = Summary::new(&logs)?;
+    let margin = t_critical(confidence, s.size() - 1.0)? * s.standard_error();
+
+    Ok(GeometricMeanRatio {
+        ratio: s.mean().exp(),
+        lower: (s.mean() - margin).exp(),
+        upper: (s.mean() + margin).exp(),
+        confidence,
+    })
+}
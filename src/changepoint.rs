@@ -0,0 +1,169 @@
+use std;
+
+use error::Error;
+use fmt;
+use summary::Summarizer;
+
+
+/// The fewest values of a segment between changepoints.
+pub const MIN_SEGMENT: usize = 2;
+
+/// A run of a series without a change of level.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Segment {
+    /// The index of the first value of the segment, and one past its last.
+    pub start: usize,
+    pub end: usize,
+    pub mean: f64,
+}
+
+impl Segment {
+    pub fn size(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+/// The changes of level found in a series, as the segments between them.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Changepoints {
+    /// The segments, in order, which together cover the series.
+    pub segments: Vec<Segment>,
+
+    /// The estimated standard deviation of the noise about the level.
+    pub noise: f64,
+
+    /// The penalty of each change, in units of the variance of the noise.
+    pub penalty: f64,
+}
+
+/// Formats the number of changes and the parameters which found them as
+/// `dent` prints them, one per line. A precision, as in `{:.3}`, gives the
+/// number of significant digits of each.
+impl std::fmt::Display for Changepoints {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+
+        writeln!(f, "{:>w$} = {}", "Changes", self.segments.len() - 1, w = w)?;
+        writeln!(f, "{:>w$} = {}", "Noise SD", fmt::stat(self.noise, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "Penalty", fmt::stat(self.penalty, p), w = w)
+    }
+}
+
+impl Changepoints {
+    /// The index of the first value after each change.
+    pub fn indices(&self) -> Vec<usize> {
+        self.segments.iter().skip(1).map(|s| s.start).collect()
+    }
+}
+
+/// Find where the mean level of a series changes, by the pruned exact linear
+/// time (PELT) method of Killick, Fearnhead, and Eckley (2012) [1]. It finds
+/// the segmentation minimizing the squared deviations of each value from the
+/// mean of its segment, in units of the variance of the noise, plus `penalty`
+/// for each change, which defaults to `2·max(ln n, 1)` for `n` values. That is
+/// the `2 ln n` of the BIC, but at least 2, since `ln n` is 0 for one value,
+/// and a penalty of 0 would make changes free. Each segment has at least
+/// `MIN_SEGMENT` values.
+///
+/// The noise is estimated from the differences of successive values, by their
+/// median absolute deviation, so that the changes themselves do not inflate
+/// it. The values are assumed independent and roughly normal about their
+/// level: skewed noise, such as that of many timings, gives spurious changes
+/// unless it is transformed, as by a log, or the penalty raised.
+///
+/// [1]: https://doi.org/10.1080/01621459.2012.737745
+pub fn changepoints(data: &[f64], penalty: Option<f64>) -> Result<Changepoints, Error> {
+    let s = Summarizer::new(data)?;
+    let n = data.len();
+
+    let penalty = penalty.unwrap_or(2.0 * (n as f64).ln().max(1.0));
+    if !(penalty >= 0.0 && penalty.is_finite()) {
+        return Err(Error::Undefined);
+    }
+
+    let noise = if n < 2 * MIN_SEGMENT { 0.0 } else { noise(data) };
+
+    // Without noise, or too few values to split, there is one segment.
+    if noise == 0.0 {
+        return Ok(Changepoints {
+            segments: vec![Segment { start: 0, end: n, mean: s.mean() }],
+            noise,
+            penalty,
+        });
+    }
+
+    // Prefix sums of the values and their squares give the cost of any
+    // segment in constant time.
+    let mut sums = vec![(0.0, 0.0); n + 1];
+    for (i, &v) in data.iter().enumerate() {
+        sums[i + 1] = (sums[i].0 + v, sums[i].1 + v * v);
+    }
+    let variance = noise * noise;
+    let cost = |a: usize, b: usize| {
+        let (sum, squares) = (sums[b].0 - sums[a].0, sums[b].1 - sums[a].1);
+        (squares - sum * sum / (b - a) as f64).max(0.0) / variance
+    };
+
+    // The least total cost of the first `t` values, and the start of the last
+    // segment of that best segmentation.
+    let mut best = vec![0.0; n + 1];
+    let mut last = vec![0; n + 1];
+    best[0] = -penalty;
+    let mut candidates: Vec<usize> = vec![0];
+
+    for t in MIN_SEGMENT..=n {
+        let (tau, total) = candidates
+            .iter()
+            .filter(|&&tau| t - tau >= MIN_SEGMENT)
+            .map(|&tau| (tau, best[tau] + cost(tau, t) + penalty))
+            .fold((0, f64::INFINITY), |min, c| if c.1 < min.1 { c } else { min });
+
+        best[t] = total;
+        last[t] = tau;
+
+        // A start which cannot beat the best now never will.
+        candidates.retain(|&tau| t - tau < MIN_SEGMENT || best[tau] + cost(tau, t) <= total);
+        candidates.push(t);
+    }
+
+    let mut bounds = vec![n];
+    let mut t = n;
+    while t > 0 {
+        t = last[t];
+        bounds.push(t);
+    }
+    bounds.reverse();
+
+    let segments = bounds
+        .windows(2)
+        .map(|w| Segment {
+            start: w[0],
+            end: w[1],
+            mean: (sums[w[1]].0 - sums[w[0]].0) / (w[1] - w[0]) as f64,
+        })
+        .collect();
+
+    Ok(Changepoints { segments, noise, penalty })
+}
+
+/// Estimate the standard deviation of independent noise about a level which
+/// changes only rarely: the scaled median absolute deviation of the
+/// differences of successive values, divided by √2, since each difference
+/// has twice the variance of the noise. If most differences are equal, it is
+/// their standard deviation instead.
+fn noise(x: &[f64]) -> f64 {
+    let diffs: Vec<f64> = x.windows(2).map(|w| w[1] - w[0]).collect();
+
+    let s = match Summarizer::new(&diffs) {
+        Ok(s) => s,
+        Err(_) => return 0.0,
+    };
+
+    let mad = s.median_absolute_deviation();
+    let sd = if mad > 0.0 { 1.4826 * mad } else { s.standard_deviation() };
+
+    sd / 2f64.sqrt()
+}
@@ -0,0 +1,909 @@
+use clap::{App, AppSettings, Arg, Shell, SubCommand};
+
+use config;
+use transform;
+
+
+/// The command-line interface. Invoking `dent` without a subcommand is the
+/// same as it has always been: one input is summarized, two are compared with
+/// a t-test, and three or more are summarized.
+pub fn app() -> App<'static, 'static> {
+    App::new("dent")
+        .version(crate_version!())
+        .author("Joe Ranweiler <joe@lemma.co>")
+        .about("A tiny tool for t-tests &c.")
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .after_help("EXIT STATUS:\n    \
+                     0    Success\n    \
+                     1    A significant difference, with --fail-on-reject or --max-regression, \
+                          or\n         \
+                          no non-inferiority shown, with --non-inferiority\n    \
+                     2    An error, such as invalid arguments or input that cannot be read")
+        .arg(Arg::with_name("config")
+             .long("config")
+             .value_name("FILE")
+             .takes_value(true)
+             .global(true)
+             .help("Read defaults of options from FILE [default: ~/.config/dent/config.toml]"))
+        .arg(alpha())
+        .arg(confidence())
+        .arg(stdin())
+        .arg(files().required_unless_one(&["stdin", "follow"]))
+        .args(&follow_args())
+        .args(&input_args())
+        .args(&split_args())
+        .arg(label())
+        .arg(pool())
+        .args(&output_args())
+        .args(&plot_args())
+        .arg(paired().conflicts_with("follow"))
+        .arg(differences().conflicts_with("bland_altman"))
+        .arg(fail_on_reject())
+        .arg(max_regression())
+        .arg(non_inferiority())
+        .arg(alternative())
+        .arg(geomean())
+        .arg(fit().conflicts_with("effect_size"))
+        .arg(auto().conflicts_with_all(&["follow", "fit", "effect_size", "bland_altman"]))
+        .arg(robust().conflicts_with_all(&["follow", "fit", "effect_size", "bland_altman"]))
+        .arg(Arg::with_name("effect_size")
+             .long("effect-size")
+             .value_name("MEASURE")
+             .takes_value(true)
+             .possible_values(&["cohen", "cliff"])
+             .conflicts_with_all(&["tsv", "style"])
+             .help("Print the effect size of each pairwise difference of the samples, by \
+                    Cohen's d or Cliff's delta, as a matrix"))
+        .arg(Arg::with_name("bland_altman")
+             .long("bland-altman")
+             .conflicts_with("follow")
+             .help("Analyze agreement of two files of paired measurements, matched by line, \
+                    or of one --paired input"))
+        .subcommand(SubCommand::with_name("summary")
+             .about("Summarize each input, reading stdin if no files are given")
+             .arg(stdin())
+             .arg(files())
+             .args(&follow_args())
+             .args(&input_args())
+             .args(&split_args())
+             .arg(label())
+             .arg(pool())
+             .arg(fit())
+             .args(&bucket_args())
+             .args(&output_args())
+             .args(&plot_args()))
+        .subcommand(SubCommand::with_name("ttest")
+             .about("Compare the means of two inputs with Welch's t-test")
+             .arg(files()
+                  .required(true)
+                  .max_values(2))
+             .args(&input_args())
+             .args(&split_args())
+             .arg(label())
+             .arg(paired())
+             .arg(differences())
+             .arg(auto())
+             .arg(robust())
+             .arg(fail_on_reject())
+             .arg(max_regression())
+             .arg(non_inferiority())
+             .arg(alternative())
+             .args(&output_args())
+             .args(&plot_args()))
+        .subcommand(SubCommand::with_name("anova")
+             .about("Compare the means of two or more inputs with a one-way ANOVA")
+             .arg(files().required(true))
+             .args(&input_args())
+             .args(&split_args())
+             .arg(label())
+             .arg(fail_on_reject())
+             .arg(format(&["text", "json", "long", "csv", "tsv"]))
+             .arg(fields())
+             .arg(precision())
+             .args(&plot_args()))
+        .subcommand(SubCommand::with_name("lr")
+             .about("Fit a line to pairs of values `x y`, reading stdin if no file is given")
+             .arg(Arg::with_name("file")
+                  .value_name("FILE")
+                  .takes_value(true)
+                  .help("Path to a file of whitespace-separated pairs of sample data"))
+             .arg(lax())
+             .arg(Arg::with_name("timestamps")
+                  .long("timestamps")
+                  .help("Read each x as a timestamp, RFC 3339 or seconds since the Unix epoch, \
+                         and fit y against the time since the earliest, so that the slope is \
+                         the change per --per"))
+             .arg(Arg::with_name("per")
+                  .long("per")
+                  .value_name("UNIT")
+                  .takes_value(true)
+                  .possible_values(&["second", "minute", "hour", "day", "week"])
+                  .requires("timestamps")
+                  .help("Unit of time of the slope with --timestamps [default: day]"))
+             .arg(time_unit().requires("timestamps"))
+             .arg(format(&["text", "json", "long", "csv", "tsv"]))
+             .arg(precision())
+             .arg(plot())
+             .arg(ascii())
+             .arg(svg().help("Write a scatter plot of the pairs to FILE as SVG"))
+             .arg(width()))
+        .subcommand(SubCommand::with_name("hist")
+             .about("Print a histogram of an input, reading stdin if no file is given")
+             .arg(Arg::with_name("file")
+                  .value_name("FILE")
+                  .takes_value(true)
+                  .help("Path to a file of sample data"))
+             .arg(Arg::with_name("bins")
+                  .long("bins")
+                  .value_name("BINS")
+                  .takes_value(true)
+                  .help("Number of bins [default: chosen by Sturges' rule]"))
+             .arg(Arg::with_name("equal_frequency")
+                  .long("equal-frequency")
+                  .help("Bin at evenly spaced percentiles, so that the bins hold about equal \
+                         counts, and draw the density of each, which shows skewed data better \
+                         than bins of equal width"))
+             .args(&input_args())
+             .arg(precision())
+             .arg(ascii())
+             .arg(svg().help("Write the histogram to FILE as SVG"))
+             .arg(width()))
+        .subcommand(SubCommand::with_name("zscore")
+             .about("Print each value of an input standardized by the mean and standard \
+                     deviation of the input, in order, reading stdin if no file is given")
+             .arg(Arg::with_name("file")
+                  .value_name("FILE")
+                  .takes_value(true)
+                  .help("Path to a file of sample data"))
+             .arg(Arg::with_name("robust")
+                  .long("robust")
+                  .help("Standardize by the median and the median absolute deviation, scaled \
+                         to estimate the standard deviation of normal data, which outliers \
+                         hardly affect"))
+             .args(&input_args())
+             .arg(precision()))
+        .subcommand(SubCommand::with_name("clean")
+             .about("Print the values of an input as they are analyzed, after parsing and any \
+                     transform, in order, reading stdin if no file is given")
+             .arg(Arg::with_name("file")
+                  .value_name("FILE")
+                  .takes_value(true)
+                  .help("Path to a file of sample data"))
+             .arg(Arg::with_name("drop_outliers")
+                  .long("drop-outliers")
+                  .help("Drop values beyond 1.5 IQR of the quartiles, the outliers of a \
+                         boxplot"))
+             .args(&input_args())
+             .arg(precision()))
+        .subcommand(SubCommand::with_name("freq")
+             .about("Count each distinct value of an input, with its proportion and the \
+                     cumulative proportion, reading stdin if no file is given")
+             .arg(Arg::with_name("file")
+                  .value_name("FILE")
+                  .takes_value(true)
+                  .help("Path to a file of sample data"))
+             .args(&input_args())
+             .arg(format(&["text", "markdown", "json"]))
+             .arg(precision())
+             .arg(ascii())
+             .arg(width()))
+        .subcommand(SubCommand::with_name("kappa")
+             .about("Measure the agreement of two raters by Cohen's kappa, from the category each \
+                     gave each subject, one subject per line, or from their confusion matrix, \
+                     reading stdin if no file is given")
+             .arg(Arg::with_name("file")
+                  .value_name("FILE")
+                  .takes_value(true)
+                  .help("Path to a file of pairs of labels, or of a confusion matrix"))
+             .arg(Arg::with_name("cols")
+                  .long("cols")
+                  .value_name("A,B")
+                  .takes_value(true)
+                  .use_delimiter(true)
+                  .number_of_values(2)
+                  .conflicts_with("matrix")
+                  .help("Header names or 1-based indices of the columns of the two raters' \
+                         labels [default: 1,2]"))
+             .arg(Arg::with_name("matrix")
+                  .long("matrix")
+                  .help("Read a confusion matrix, one row of counts per line, with the first \
+                         rater's categories as rows and the second's as columns"))
+             .arg(Arg::with_name("weights")
+                  .long("weights")
+                  .value_name("WEIGHTS")
+                  .takes_value(true)
+                  .possible_values(&["none", "linear", "quadratic"])
+                  .default_value("none")
+                  .help("Give partial credit to disagreements between ordered categories, \
+                         falling off linearly or quadratically with their distance apart"))
+             .arg(lax())
+             .arg(csv())
+             .arg(delimiter())
+             .arg(format(&["text", "json"]))
+             .arg(precision()))
+        .subcommand(SubCommand::with_name("roc")
+             .about("Evaluate a detector by its ROC curve and the area under it, from the score \
+                     and the true label, 1 for positive or 0 for negative, of each subject, \
+                     reading stdin if no file is given")
+             .arg(Arg::with_name("file")
+                  .value_name("FILE")
+                  .takes_value(true)
+                  .help("Path to a file of pairs of scores and labels"))
+             .arg(Arg::with_name("cols")
+                  .long("cols")
+                  .value_name("SCORE,LABEL")
+                  .takes_value(true)
+                  .use_delimiter(true)
+                  .number_of_values(2)
+                  .help("Header names or 1-based indices of the columns of scores and labels \
+                         [default: 1,2]"))
+             .arg(lax())
+             .arg(csv())
+             .arg(delimiter())
+             .arg(format(&["text", "json"]))
+             .arg(precision())
+             .arg(plot())
+             .arg(ascii())
+             .arg(width()))
+        .subcommand(SubCommand::with_name("survival")
+             .about("Estimate the survival function of one or two inputs of times to an event, \
+                     each with a flag, 1 if the event was observed or 0 if the time was \
+                     censored, by the Kaplan-Meier estimator, and compare two by the log-rank \
+                     test, reading stdin if no file is given")
+             .arg(files().max_values(2))
+             .arg(Arg::with_name("cols")
+                  .long("cols")
+                  .value_name("TIME,EVENT")
+                  .takes_value(true)
+                  .use_delimiter(true)
+                  .number_of_values(2)
+                  .help("Header names or 1-based indices of the columns of times and event flags \
+                         [default: 1,2]"))
+             .arg(lax())
+             .arg(csv())
+             .arg(delimiter())
+             .arg(time_unit())
+             .arg(label())
+             .arg(format(&["text", "json"]))
+             .arg(precision()))
+        .subcommand(SubCommand::with_name("slo")
+             .about("Print the tail percentiles of an input, such as latencies, with their \
+                     confidence intervals, and check them against service level objectives, \
+                     exiting with status 1 if any is missed, reading stdin if no file is given")
+             .arg(Arg::with_name("file")
+                  .value_name("FILE")
+                  .takes_value(true)
+                  .help("Path to a file of sample data"))
+             .arg(Arg::with_name("percentiles")
+                  .long("percentiles")
+                  .value_name("P,...")
+                  .takes_value(true)
+                  .default_value("50,90,99,99.9")
+                  .help("Percentiles to print, from 0 to 100"))
+             .arg(Arg::with_name("target")
+                  .long("target")
+                  .value_name("TARGET")
+                  .takes_value(true)
+                  .multiple(true)
+                  .number_of_values(1)
+                  .use_delimiter(true)
+                  .help("An objective on a percentile, such as `p99<250` or `p99.9<=1.5s`, \
+                         which is met if the estimate of the percentile is; it is met or missed \
+                         with confidence if its whole confidence interval is. Give more than \
+                         once, or separate by commas, for several."))
+             .args(&input_args())
+             .arg(format(&["text", "json"]))
+             .arg(precision()))
+        .subcommand(SubCommand::with_name("changepoint")
+             .about("Find where the level of a series, such as the nightly results of a \
+                     benchmark, changed, and print the segments between the changes, reading \
+                     stdin if no file is given")
+             .arg(Arg::with_name("file")
+                  .value_name("FILE")
+                  .takes_value(true)
+                  .help("Path to a file of sample data, in order"))
+             .arg(Arg::with_name("penalty")
+                  .long("penalty")
+                  .value_name("PENALTY")
+                  .takes_value(true)
+                  .help("Penalty of each change, in units of the variance of the noise; higher \
+                         finds fewer changes [default: 2 ln n, at least 2, for n values]"))
+             .arg(Arg::with_name("annotate")
+                  .long("annotate")
+                  .conflicts_with("format")
+                  .help("Print each value, in order, with a line marking each change"))
+             .args(&input_args())
+             .arg(format(&["text", "json"]))
+             .arg(precision()))
+        .subcommand(SubCommand::with_name("gen")
+             .about("Print random samples of a distribution, one per line")
+             .arg(Arg::with_name("distribution")
+                  .value_name("DISTRIBUTION")
+                  .possible_values(&["normal", "uniform", "exponential", "lognormal"])
+                  .required(true)
+                  .help("Distribution to sample"))
+             .arg(Arg::with_name("count")
+                  .short("n")
+                  .long("count")
+                  .value_name("N")
+                  .takes_value(true)
+                  .default_value("100")
+                  .help("Number of samples"))
+             .arg(Arg::with_name("mean")
+                  .long("mean")
+                  .value_name("MEAN")
+                  .takes_value(true)
+                  .default_value("0")
+                  .help("Mean of a normal distribution, or of the logarithm of a lognormal one"))
+             .arg(Arg::with_name("sd")
+                  .long("sd")
+                  .value_name("SD")
+                  .takes_value(true)
+                  .default_value("1")
+                  .help("Standard deviation of a normal distribution, or of the logarithm of a \
+                         lognormal one"))
+             .arg(Arg::with_name("min")
+                  .long("min")
+                  .value_name("MIN")
+                  .takes_value(true)
+                  .default_value("0")
+                  .help("Lower bound of a uniform distribution"))
+             .arg(Arg::with_name("max")
+                  .long("max")
+                  .value_name("MAX")
+                  .takes_value(true)
+                  .default_value("1")
+                  .help("Upper bound of a uniform distribution"))
+             .arg(Arg::with_name("rate")
+                  .long("rate")
+                  .value_name("RATE")
+                  .takes_value(true)
+                  .default_value("1")
+                  .help("Rate of an exponential distribution, the reciprocal of its mean"))
+             .arg(Arg::with_name("seed")
+                  .long("seed")
+                  .value_name("SEED")
+                  .takes_value(true)
+                  .help("Seed of the random number generator, to reproduce samples \
+                         [default: from the clock]"))
+             .arg(precision()))
+        .subcommand(SubCommand::with_name("import")
+             .about("Summarize and compare the results of a benchmarking tool")
+             .arg(Arg::with_name("tool")
+                  .value_name("TOOL")
+                  .possible_values(&["criterion"])
+                  .required(true)
+                  .help("Tool which wrote the results"))
+             .arg(Arg::with_name("path")
+                  .value_name("PATH")
+                  .required(true)
+                  .help("Path to the results, such as the `target/criterion` directory"))
+             .arg(geomean())
+             .args(&output_args())
+             .args(&plot_args()))
+        .subcommand(SubCommand::with_name("run")
+             .about("Time shell commands, then summarize and compare their run times")
+             .arg(Arg::with_name("runs")
+                  .short("n")
+                  .long("runs")
+                  .value_name("N")
+                  .takes_value(true)
+                  .default_value("10")
+                  .help("Number of timed runs of each command"))
+             .arg(Arg::with_name("warmup")
+                  .long("warmup")
+                  .value_name("N")
+                  .takes_value(true)
+                  .default_value("0")
+                  .help("Number of untimed runs of each command before the timed runs"))
+             .arg(Arg::with_name("commands")
+                  .value_name("COMMANDS")
+                  .multiple(true)
+                  .required(true)
+                  .help("Shell commands to time, each one argument, such as `'sleep 0.1'`"))
+             .arg(time_unit())
+             .arg(label())
+             .arg(fail_on_reject())
+             .arg(max_regression())
+             .arg(non_inferiority())
+             .args(&output_args())
+             .args(&plot_args()))
+        .subcommand(SubCommand::with_name("baseline")
+             .about("Save a summary of an input as a baseline, or compare an input with one")
+             .setting(AppSettings::SubcommandRequiredElseHelp)
+             .subcommand(SubCommand::with_name("save")
+                  .about("Save a summary, not the data, of an input as a baseline, reading stdin \
+                          if no file is given")
+                  .arg(baseline())
+                  .arg(Arg::with_name("file")
+                       .value_name("FILE")
+                       .help("Path to a file of sample data"))
+                  .args(&input_args())
+                  .arg(label()))
+             .subcommand(SubCommand::with_name("compare")
+                  .about("Compare an input with a baseline using Welch's t-test, reading stdin \
+                          if no file is given")
+                  .arg(baseline())
+                  .arg(Arg::with_name("file")
+                       .value_name("FILE")
+                       .help("Path to a file of sample data"))
+                  .args(&input_args())
+                  .arg(label())
+                  .arg(fail_on_reject())
+                  .arg(max_regression())
+                  .arg(non_inferiority())
+                  .arg(alternative())
+                  .arg(format(&["text", "json"]))
+                  .arg(fields())
+                  .arg(precision())
+                  .arg(Arg::with_name("color")
+                       .long("color")
+                       .value_name("WHEN")
+                       .takes_value(true)
+                       .possible_values(&["auto", "always", "never"])
+                       .default_value(config::get().color.as_deref().unwrap_or("auto"))
+                       .help("Colorize test results"))))
+        .subcommand(SubCommand::with_name("report")
+             .about("Write a self-contained report of summaries, tests, and plots")
+             .arg(Arg::with_name("html")
+                  .long("html")
+                  .value_name("FILE")
+                  .takes_value(true)
+                  .required(true)
+                  .help("Write the report to FILE as HTML"))
+             .arg(files().required(true))
+             .arg(lax())
+             .arg(outliers())
+             .arg(log()))
+        .subcommand(SubCommand::with_name("tui")
+             .about("Explore inputs interactively in a terminal UI")
+             .arg(files().required(true))
+             .args(&input_args())
+             .args(&split_args())
+             .arg(label())
+             .arg(ascii()))
+        .subcommand(SubCommand::with_name("completions")
+             .about("Print a script for a shell to complete dent's commands and options")
+             .arg(Arg::with_name("shell")
+                  .value_name("SHELL")
+                  .possible_values(&Shell::variants())
+                  .required(true)
+                  .help("Shell to complete for")))
+}
+
+fn stdin() -> Arg<'static, 'static> {
+    Arg::with_name("stdin")
+        .short("s")
+        .long("stdin")
+        .help("Read and summarize data from stdin")
+}
+
+fn follow_args() -> [Arg<'static, 'static>; 2] {
+    [
+        // Options of only some commands with --follow, such as --paired,
+        // declare their own conflicts with it: completion scripts require that
+        // each conflict of an option names another of its command.
+        Arg::with_name("follow")
+            .long("follow")
+            .conflicts_with_all(&["files", "csv", "delimiter", "col", "field", "cols", "group_by",
+                                  "from"])
+            .help("Read values from stdin as they arrive, and redraw their summary periodically \
+                   until it ends"),
+        Arg::with_name("interval")
+            .long("interval")
+            .value_name("SECS")
+            .takes_value(true)
+            .requires("follow")
+            .help("Seconds between redraws with --follow [default: 1]"),
+    ]
+}
+
+fn bucket_args() -> [Arg<'static, 'static>; 2] {
+    [
+        Arg::with_name("bucket")
+            .long("bucket")
+            .value_name("DURATION")
+            .takes_value(true)
+            .conflicts_with_all(&["follow", "cols", "group_by", "from", "fit"])
+            .help("Read lines of a timestamp and a value, and summarize the values of each \
+                   interval of DURATION, such as 30s, 5m, 1h, or 1d, aligned to the Unix epoch. \
+                   Timestamps are RFC 3339 or seconds since the epoch."),
+        Arg::with_name("time_col")
+            .long("time-col")
+            .value_name("NAME|INDEX")
+            .takes_value(true)
+            .requires("bucket")
+            .help("Header name or 1-based index of the column of timestamps with --bucket, \
+                   whose values are read from --col or --field [default: 1, with values from \
+                   2]"),
+    ]
+}
+
+fn files() -> Arg<'static, 'static> {
+    Arg::with_name("files")
+        .multiple(true)
+        .value_name("FILES")
+        .takes_value(true)
+        .help("Path to one or more files of sample data")
+}
+
+fn input_args() -> [Arg<'static, 'static>; 10] {
+    [
+        lax(),
+        csv(),
+        delimiter(),
+        Arg::with_name("col")
+            .long("col")
+            .value_name("NAME|INDEX")
+            .takes_value(true)
+            .conflicts_with("field")
+            .help("Header name or 1-based index of the column to read [default: 1]"),
+        Arg::with_name("field")
+            .long("field")
+            .value_name("N")
+            .takes_value(true)
+            .help("1-based index of the whitespace-delimited field to read, unless another \
+                   delimiter is given"),
+        time_unit(),
+        Arg::with_name("transform")
+            .long("transform")
+            .value_name("TRANSFORM")
+            .takes_value(true)
+            .possible_values(transform::NAMES)
+            .help("Transform each value before analysis: log (base 10), log2, sqrt, reciprocal, \
+                   zscore, which standardizes each sample by its own mean and standard \
+                   deviation, robust-zscore, which does so by its median and scaled median \
+                   absolute deviation, or rank, which ranks the values of all samples together"),
+        Arg::with_name("log_summary")
+            .long("log-summary")
+            .conflicts_with("transform")
+            .help("Summarize and compare the log10 of the sample data, as with --transform log"),
+        Arg::with_name("as_rate")
+            .long("as-rate")
+            .value_name("WORK")
+            .takes_value(true)
+            .conflicts_with_all(&["transform", "log_summary"])
+            .help("Convert durations to rates, WORK operations per sample over its duration, in \
+                   ops/s, before analysis, add their harmonic mean, the overall rate, to \
+                   summaries, and compare two samples by a t-test of their harmonic means"),
+        Arg::with_name("progress")
+            .long("progress")
+            .help("Report progress reading inputs on stderr, as is done when it is a terminal \
+                   and reading takes a while"),
+    ]
+}
+
+fn csv() -> Arg<'static, 'static> {
+    Arg::with_name("csv")
+        .long("csv")
+        .help("Read a column of CSV data, rather than one value per line")
+}
+
+fn delimiter() -> Arg<'static, 'static> {
+    Arg::with_name("delimiter")
+        .long("delimiter")
+        .value_name("CHAR")
+        .takes_value(true)
+        .help("Read a column of data delimited by CHAR, `tab`, or `whitespace`")
+}
+
+fn time_unit() -> Arg<'static, 'static> {
+    Arg::with_name("time_unit")
+        .long("time-unit")
+        .value_name("UNIT")
+        .takes_value(true)
+        .help("Unit to which durations such as `12ms`, `1m23.4s`, or `00:02:31.5` are \
+               converted: ns, us, ms, s, min, or h [default: s]")
+}
+
+fn label() -> Arg<'static, 'static> {
+    Arg::with_name("label")
+        .long("label")
+        .value_name("LABEL")
+        .takes_value(true)
+        .multiple(true)
+        .number_of_values(1)
+        .help("Name an input in output, instead of by its path. Give once per input, in order.")
+}
+
+fn pool() -> Arg<'static, 'static> {
+    Arg::with_name("pool")
+        .long("pool")
+        .conflicts_with_all(&["follow", "from"])
+        .help("Merge the inputs into one sample, as shards of the same experiment, or with \
+               --cols or --group-by, merge the samples of each column or group")
+}
+
+fn baseline() -> Arg<'static, 'static> {
+    Arg::with_name("baseline")
+        .value_name("BASELINE")
+        .required(true)
+        .help("Path to the baseline, a JSON file")
+}
+
+fn geomean() -> Arg<'static, 'static> {
+    Arg::with_name("geomean")
+        .long("geomean")
+        .help("Compare two sets of benchmark results by the speedup of each benchmark in both, \
+               the ratio of its old mean to its new, and the geometric mean of the speedups")
+}
+
+fn fit() -> Arg<'static, 'static> {
+    Arg::with_name("fit")
+        .long("fit")
+        .conflicts_with_all(&["follow", "tsv", "style"])
+        .help("Fit normal, lognormal, exponential, and gamma distributions to each input by \
+               maximum likelihood, and name the best fit by AIC")
+}
+
+fn auto() -> Arg<'static, 'static> {
+    Arg::with_name("auto")
+        .long("auto")
+        .conflicts_with_all(&["paired", "alternative", "tsv", "style"])
+        .help("Check the normality, variances, and sizes of two inputs, then compare them by the \
+               test those suit, Welch's t-test, the Mann-Whitney U test, or a permutation test, \
+               and explain why")
+}
+
+fn robust() -> Arg<'static, 'static> {
+    Arg::with_name("robust")
+        .long("robust")
+        .conflicts_with_all(&["paired", "auto", "alternative", "tsv", "style"])
+        .help("Compare the locations of two inputs robustly, for heavy-tailed data: by the \
+               difference of their medians and the Hodges-Lehmann shift, with its confidence \
+               interval, and by the Mann-Whitney U test")
+}
+
+fn fail_on_reject() -> Arg<'static, 'static> {
+    Arg::with_name("fail_on_reject")
+        .long("fail-on-reject")
+        .help("Exit with status 1 if a test finds a significant difference (p < ALPHA, 0.05 \
+               unless set by --alpha or the config file), 0 if not, and 2 on error")
+}
+
+fn max_regression() -> Arg<'static, 'static> {
+    Arg::with_name("max_regression")
+        .long("max-regression")
+        .value_name("PCT")
+        .takes_value(true)
+        .help("Exit with status 1 if the mean of the second input is significantly more than PCT, \
+               such as 5%, greater than that of the first, the baseline, and 2 on error")
+}
+
+fn non_inferiority() -> Arg<'static, 'static> {
+    Arg::with_name("non_inferiority")
+        .long("non-inferiority")
+        .value_name("MARGIN")
+        .takes_value(true)
+        .help("Test whether the mean of the second input is less than MARGIN, such as 5% or 2ms, \
+               greater than that of the first, the baseline, by its one-sided confidence \
+               interval, and exit with status 1 if not shown to be, and 2 on error")
+}
+
+fn alternative() -> Arg<'static, 'static> {
+    Arg::with_name("alternative")
+        .long("alternative")
+        .value_name("HYPOTHESIS")
+        .takes_value(true)
+        .possible_values(&["two-sided", "less", "greater"])
+        .help("Test whether the mean of the second input differs from that of the first, or is \
+               less or greater, with a one-sided t-test [default: two-sided]")
+}
+
+fn paired() -> Arg<'static, 'static> {
+    Arg::with_name("paired")
+        .long("paired")
+        .conflicts_with("group_by")
+        .help("Read pairs of measurements of the same subjects from two columns of one input, \
+               the first two unless --cols is given, and compare them with a paired t-test")
+}
+
+fn differences() -> Arg<'static, 'static> {
+    Arg::with_name("differences")
+        .long("differences")
+        .requires("paired")
+        .conflicts_with_all(&["tsv", "style"])
+        .help("Analyze the differences of --paired measurements, the second less the first, by \
+               their summary, the confidence interval of their mean, their signs, and their \
+               histogram, instead of testing them")
+}
+
+/// Split each input into several samples.
+fn split_args() -> [Arg<'static, 'static>; 4] {
+    [
+        Arg::with_name("cols")
+            .long("cols")
+            .value_name("COLS")
+            .takes_value(true)
+            .use_delimiter(true)
+            .conflicts_with_all(&["col", "field", "group_by"])
+            .help("Comma-separated header names or 1-based indices of columns to compare as \
+                   separate samples"),
+        Arg::with_name("group_by")
+            .long("group-by")
+            .value_name("COL")
+            .takes_value(true)
+            .requires("value")
+            .conflicts_with_all(&["col", "field"])
+            .help("Header name or 1-based index of a column of labels, each of which is a \
+                   sample of the --value column"),
+        Arg::with_name("value")
+            .long("value")
+            .value_name("COL")
+            .takes_value(true)
+            .requires("group_by")
+            .help("Header name or 1-based index of the column of values to group"),
+        Arg::with_name("from")
+            .long("from")
+            .value_name("TOOL")
+            .takes_value(true)
+            .possible_values(&["go", "hyperfine", "jmh"])
+            .conflicts_with_all(&["csv", "delimiter", "col", "field", "cols", "group_by"])
+            .help("Read the results of a benchmarking tool, with each benchmark a sample. \
+                   Given two inputs, compare each benchmark in both."),
+    ]
+}
+
+fn output_args() -> [Arg<'static, 'static>; 5] {
+    [
+        format(&["text", "markdown", "json", "long", "csv", "tsv"]),
+        fields(),
+        precision(),
+        Arg::with_name("tsv")
+            .long("tsv")
+            .help("Print summary data to stdout in TSV format, without test results"),
+        Arg::with_name("style")
+            .long("style")
+            .value_name("STYLE")
+            .takes_value(true)
+            .possible_values(&["dent", "ministat"])
+            .conflicts_with("tsv")
+            .help("Layout of text output: dent's own (the default), or that of BSD ministat"),
+    ]
+}
+
+fn plot_args() -> [Arg<'static, 'static>; 7] {
+    [
+        outliers(),
+        plot(),
+        log(),
+        ascii(),
+        Arg::with_name("color")
+            .long("color")
+            .value_name("WHEN")
+            .takes_value(true)
+            .possible_values(&["auto", "always", "never"])
+            .default_value(config::get().color.as_deref().unwrap_or("auto"))
+            .help("Colorize plots and test results"),
+        svg(),
+        width(),
+    ]
+}
+
+fn svg() -> Arg<'static, 'static> {
+    Arg::with_name("svg")
+        .long("svg")
+        .value_name("FILE")
+        .takes_value(true)
+        .help("Write plots to FILE as SVG: boxplots, or the plot of --bland-altman or the \
+               histogram of --differences")
+}
+
+fn format(formats: &'static [&'static str]) -> Arg<'static, 'static> {
+    // A configured format that a command lacks falls back to text.
+    let default = config::get().format.as_deref().filter(|f| formats.contains(f));
+
+    Arg::with_name("format")
+        .long("format")
+        .value_name("FORMAT")
+        .takes_value(true)
+        .possible_values(formats)
+        .default_value(default.unwrap_or("text"))
+        .help("Output format for summaries and test results")
+}
+
+fn fields() -> Arg<'static, 'static> {
+    Arg::with_name("fields")
+        .long("fields")
+        .value_name("FIELDS")
+        .takes_value(true)
+        .help("Comma-separated summary fields, in order: n, mean, median, geomean, hmean, \
+               stddev, variance, stderr, mad, iqm, midhinge, min, max, range, q1, q3, iqr, \
+               min_adj, max_adj, mean_ci or median_ci, the bounds of a confidence interval at \
+               --confidence, or a percentile such as p99")
+}
+
+fn precision() -> Arg<'static, 'static> {
+    let arg = Arg::with_name("precision")
+        .long("precision")
+        .value_name("DIGITS")
+        .takes_value(true)
+        .help("Significant digits of numbers in text and Markdown output, and of plot labels");
+
+    match configured(config::get().precision) {
+        Some(p) => arg.default_value(p),
+        None => arg,
+    }
+}
+
+fn lax() -> Arg<'static, 'static> {
+    Arg::with_name("lax")
+        .long("lax")
+        .help("Ignore non-numeric input lines")
+}
+
+fn outliers() -> Arg<'static, 'static> {
+    Arg::with_name("plot_outliers")
+        .long("outliers")
+        .help("Include outliers and use min/max for outer fences of boxplot")
+}
+
+fn plot() -> Arg<'static, 'static> {
+    Arg::with_name("plot")
+        .short("p")
+        .long("plot")
+        .help("Print standard boxplots")
+}
+
+fn log() -> Arg<'static, 'static> {
+    Arg::with_name("log")
+        .long("log")
+        .help("Plot sample data on a log10 scale")
+}
+
+fn ascii() -> Arg<'static, 'static> {
+    Arg::with_name("ascii")
+        .long("ascii")
+        .help("Use only ASCII characters in plots")
+}
+
+fn width() -> Arg<'static, 'static> {
+    let arg = Arg::with_name("width")
+        .short("w")
+        .long("width")
+        .value_name("WIDTH")
+        .takes_value(true)
+        .help("Width of plots");
+
+    match configured(config::get().width) {
+        Some(w) => arg.default_value(w),
+        None => arg,
+    }
+}
+
+/// Significance level of tests, for highlighting p-values and deciding whether
+/// a test fails with `--fail-on-reject`, unless set with `--alpha`.
+pub const DEFAULT_ALPHA: f64 = 0.05;
+
+fn alpha() -> Arg<'static, 'static> {
+    let alpha = config::get().alpha.unwrap_or(DEFAULT_ALPHA);
+
+    Arg::with_name("alpha")
+        .long("alpha")
+        .value_name("ALPHA")
+        .takes_value(true)
+        .global(true)
+        .default_value(leak(alpha.to_string()))
+        .help("Significance level of tests, for highlighting p-values and --fail-on-reject")
+}
+
+fn confidence() -> Arg<'static, 'static> {
+    Arg::with_name("confidence")
+        .long("confidence")
+        .value_name("LEVEL")
+        .takes_value(true)
+        .global(true)
+        .help("Confidence level of all intervals, such as those of changes of means, which it \
+               adds to summary tables as mean_ci, and of percentiles with slo [default: 0.95]")
+}
+
+/// A configured default value of an option, if any.
+fn configured<T: ToString>(value: Option<T>) -> Option<&'static str> {
+    value.map(|v| leak(v.to_string()))
+}
+
+/// The command line is parsed once, so leaking default values to live as long
+/// as the `App` is harmless.
+fn leak(s: String) -> &'static str {
+    Box::leak(s.into_boxed_str())
+}
@@ -0,0 +1,21 @@
+use std::env;
+use std::io::{self, IsTerminal};
+
+use dent::plot::color::{self, NOT_SIGNIFICANT, SIGNIFICANT};
+
+
+/// Decide whether to emit color, given the value of `--color`. In `auto` mode,
+/// we color only when stdout is a terminal and `NO_COLOR` is unset.
+pub fn enabled(when: &str) -> bool {
+    match when {
+        "always" => true,
+        "never" => false,
+        _ => io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+    }
+}
+
+/// Highlight `s`, stating the result of a test, in green if it is
+/// `significant`, and red otherwise.
+pub fn significance(s: &str, significant: bool) -> String {
+    color::paint(s, if significant { SIGNIFICANT } else { NOT_SIGNIFICANT })
+}
@@ -0,0 +1,202 @@
+use std::env;
+use std::error;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+
+/// Defaults for command-line options, read from a configuration file. Options
+/// given on the command line take precedence.
+///
+/// The file is a flat TOML table of `key = value` lines, with `#` comments:
+///
+/// ```toml
+/// alpha = 0.01
+/// ascii = true
+/// color = "never"
+/// confidence = 0.9
+/// format = "markdown"
+/// precision = 4
+/// width = 100
+/// ```
+#[derive(Debug, Default)]
+pub struct Config {
+    pub alpha: Option<f64>,
+    pub ascii: bool,
+    pub color: Option<String>,
+    pub confidence: Option<f64>,
+    pub format: Option<String>,
+    pub precision: Option<usize>,
+    pub width: Option<usize>,
+}
+
+/// Output formats a configuration may choose, of those of any command.
+const FORMATS: &[&str] = &["text", "markdown", "json", "long", "csv", "tsv"];
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// The configuration in effect: that given to `set`, or else the default.
+pub fn get() -> &'static Config {
+    CONFIG.get_or_init(Config::default)
+}
+
+/// Put `config` into effect. It can only be set once, before it is used.
+pub fn set(config: Config) {
+    let _ = CONFIG.set(config);
+}
+
+/// The path given by `--config` in `args`, if any. The configuration must be
+/// loaded before the command line is parsed, since it sets its defaults.
+pub fn path_arg(args: &[String]) -> Option<&str> {
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        if arg == "--config" {
+            return args.next().map(|p| p.as_str());
+        }
+        if let Some(path) = arg.strip_prefix("--config=") {
+            return Some(path);
+        }
+    }
+
+    None
+}
+
+/// Load the configuration at `path`, or if none is given, at
+/// `$XDG_CONFIG_HOME/dent/config.toml`, or `~/.config/dent/config.toml`, if it
+/// exists there.
+pub fn load(path: Option<&str>) -> Result<Config, Box<dyn error::Error>> {
+    let (path, required) = match path {
+        Some(p) => (PathBuf::from(p), true),
+        None => match default_path() {
+            Some(p) => (p, false),
+            None => return Ok(Config::default()),
+        },
+    };
+
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(ref e) if !required && e.kind() == io::ErrorKind::NotFound => {
+            return Ok(Config::default());
+        },
+        Err(e) => return Err(format!("Could not read config file {:?}: {}", path, e).into()),
+    };
+
+    parse(&text).map_err(|e| format!("In config file {:?}: {}", path, e).into())
+}
+
+fn default_path() -> Option<PathBuf> {
+    let dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+
+    Some(dir.join("dent").join("config.toml"))
+}
+
+/// A value of a setting.
+enum Value {
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+/// Parse the text of a configuration file.
+pub fn parse(text: &str) -> Result<Config, String> {
+    let mut config = Config::default();
+
+    for (i, line) in text.lines().enumerate() {
+        let n = i + 1;
+        let line = strip_comment(line).trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let (key, value) = match line.split_once('=') {
+            Some((k, v)) => (k.trim(), v.trim()),
+            None => return Err(format!("Expected `key = value` on line {}", n)),
+        };
+        let value = parse_value(value).map_err(|e| format!("{} on line {}", e, n))?;
+        let invalid = || format!("Invalid value of {} on line {}", key, n);
+
+        match (key, value) {
+            ("alpha", Value::Number(a)) if a > 0.0 && a < 1.0 => config.alpha = Some(a),
+            ("ascii", Value::Bool(b)) => config.ascii = b,
+            ("color", Value::String(c)) if ["auto", "always", "never"].contains(&c.as_str()) => {
+                config.color = Some(c);
+            },
+            ("confidence", Value::Number(c)) if c > 0.0 && c < 1.0 => config.confidence = Some(c),
+            ("format", Value::String(f)) if FORMATS.contains(&f.as_str()) => {
+                config.format = Some(f);
+            },
+            ("precision", Value::Number(p)) => {
+                config.precision = Some(count(p).ok_or_else(invalid)?);
+            },
+            ("width", Value::Number(w)) => config.width = Some(count(w).ok_or_else(invalid)?),
+            ("alpha", _) | ("ascii", _) | ("color", _) | ("confidence", _) | ("format", _) |
+            ("precision", _) | ("width", _) => return Err(invalid()),
+            _ => return Err(format!("Unknown setting {:?} on line {}", key, n)),
+        }
+    }
+
+    Ok(config)
+}
+
+/// Remove a `#` comment from a line, unless it is within a string.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {},
+        }
+    }
+
+    line
+}
+
+fn parse_value(s: &str) -> Result<Value, String> {
+    match s {
+        "true" => return Ok(Value::Bool(true)),
+        "false" => return Ok(Value::Bool(false)),
+        _ => {},
+    }
+
+    if let Some(s) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        let mut out = String::new();
+        let mut chars = s.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some('"') => out.push('"'),
+                    Some('\\') => out.push('\\'),
+                    _ => return Err("Unsupported escape in string".to_string()),
+                },
+                '"' => return Err("Unescaped quote in string".to_string()),
+                c => out.push(c),
+            }
+        }
+
+        return Ok(Value::String(out));
+    }
+
+    s.replace('_', "")
+        .parse::<f64>()
+        .ok()
+        .filter(|x| x.is_finite())
+        .map(Value::Number)
+        .ok_or_else(|| format!("Expected a string, number, or boolean, not {:?}", s))
+}
+
+/// A positive whole number, as a count.
+fn count(x: f64) -> Option<usize> {
+    if x >= 1.0 && x.fract() == 0.0 { Some(x as usize) } else { None }
+}
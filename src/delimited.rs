@@ -0,0 +1,121 @@
+use dent::anova::Anova;
+use dent::fmt;
+use dent::lr::LinearRegression;
+use dent::summary::Summarizer;
+use dent::t_test::TTest;
+
+use field::Field;
+
+
+/// Render `fields` of the samples of `sources` as a table of
+/// delimiter-separated values, with a header row. Numbers are written in full
+/// precision. If the `unit` of the data is known, it follows each source.
+pub fn summary_table(sources: &[String], samples: &[Summarizer], fields: &[Field],
+                     unit: Option<&str>, sep: char)
+                     -> String {
+    table(sources, samples, fields, unit, (&[], &[]), sep)
+}
+
+/// Render the summary table of two samples, as `summary_table` does, with the
+/// results of a t-test of the change of the mean from the first, the baseline,
+/// in the row of the second. `MeanDifference` is the second mean less the
+/// first, and `T` is the statistic of that difference, so they agree in sign.
+pub fn t_test_table(sources: &[String], samples: &[Summarizer], fields: &[Field],
+                    unit: Option<&str>, t_test: &TTest, sep: char)
+                    -> String {
+    let header = ["MeanDifference", "T", "DF", "P"];
+    let difference = samples[1].mean() - samples[0].mean();
+    // `welch_t_test` takes the first mean less the second.
+    let values = [difference, -t_test.t, t_test.df, t_test.p];
+    let cells = [vec![String::new(); header.len()], full(&values)];
+
+    table(sources, samples, fields, unit, (&header, &cells), sep)
+}
+
+/// Render the summary table of samples, as `summary_table` does, with the
+/// results of a one-way ANOVA of them all in the row of each.
+pub fn anova_table(sources: &[String], samples: &[Summarizer], fields: &[Field],
+                   unit: Option<&str>, anova: &Anova, sep: char)
+                   -> String {
+    let header = ["F", "DFBetween", "DFWithin", "P"];
+    let values = full(&[anova.f, anova.df_between, anova.df_within, anova.p]);
+    let cells = vec![values; samples.len()];
+
+    table(sources, samples, fields, unit, (&header, &cells), sep)
+}
+
+/// Render the summary table, with the `header` and `cells` of `extra` columns
+/// following those of each sample.
+fn table(sources: &[String], samples: &[Summarizer], fields: &[Field], unit: Option<&str>,
+         extra: (&[&str], &[Vec<String>]), sep: char)
+         -> String {
+    let (extra_header, extra_cells) = extra;
+
+    let mut header = vec!["Source".to_string()];
+    header.extend(unit.map(|_| "Unit".to_string()));
+    header.extend(fields.iter().map(|f| f.header()));
+    header.extend(extra_header.iter().map(|h| h.to_string()));
+
+    let mut s = row(&header, sep);
+
+    for (i, (src, sample)) in sources.iter().zip(samples).enumerate() {
+        let mut cells = vec![field(src, sep)];
+        cells.extend(unit.map(String::from));
+        cells.extend(fields.iter().map(|f| fmt::full(f.value(sample))));
+        cells.extend(extra_cells.get(i).into_iter().flatten().cloned());
+
+        s += &row(&cells, sep);
+    }
+
+    s
+}
+
+/// Render a matrix of the effect size of the sample of each column relative to
+/// that of each row as a delimited table.
+pub fn effect_size_table(sources: &[String], matrix: &[Vec<f64>], sep: char) -> String {
+    let mut header = vec!["Source".to_string()];
+    header.extend(sources.iter().map(|src| field(src, sep)));
+
+    let mut s = row(&header, sep);
+
+    for (src, r) in sources.iter().zip(matrix) {
+        let mut cells = vec![field(src, sep)];
+        cells.extend(r.iter().map(|&x| fmt::full(x)));
+
+        s += &row(&cells, sep);
+    }
+
+    s
+}
+
+/// Render a fitted linear regression as a delimited table.
+pub fn linear_regression_table(lr: &LinearRegression, sep: char) -> String {
+    let header = ["Slope", "Intercept", "R", "StandardError"];
+    let values = [lr.slope(), lr.intercept(), lr.r(), lr.standard_error()];
+
+    row(&header, sep) + &number_row(&values, sep)
+}
+
+fn number_row(values: &[f64], sep: char) -> String {
+    row(&full(values), sep)
+}
+
+fn full(values: &[f64]) -> Vec<String> {
+    values.iter().map(|&x| fmt::full(x)).collect()
+}
+
+fn row<S: AsRef<str>>(cells: &[S], sep: char) -> String {
+    let cells: Vec<&str> = cells.iter().map(|c| c.as_ref()).collect();
+
+    format!("{}\n", cells.join(&sep.to_string()))
+}
+
+/// Quote a text field as in RFC 4180 if it contains a quote, line break, or
+/// the separator. Only needed for CSV: file names rarely contain tabs.
+fn field(s: &str, sep: char) -> String {
+    if sep == ',' && s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
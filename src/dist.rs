@@ -0,0 +1,664 @@
+use std::f64::consts::{PI, SQRT_2};
+
+use error::Error;
+use num;
+#[cfg(not(feature = "std"))] use prelude::*;
+
+
+/// A continuous probability distribution.
+pub trait Distribution {
+    /// The probability density function at `x`.
+    fn pdf(&self, x: f64) -> f64;
+
+    /// The cumulative distribution function: the probability of a value at
+    /// most `x`.
+    fn cdf(&self, x: f64) -> Result<f64, Error>;
+
+    /// The survival function: the probability of a value greater than `x`.
+    /// Distributions override this where `1 - cdf(x)` would lose precision in
+    /// the upper tail.
+    fn sf(&self, x: f64) -> Result<f64, Error> {
+        Ok(1.0 - self.cdf(x)?)
+    }
+
+    /// The quantile function, the inverse of the CDF: the value `x` for which
+    /// the CDF is `p`, for `p` in (0, 1).
+    fn quantile(&self, p: f64) -> Result<f64, Error>;
+}
+
+/// Student's t-distribution with `df` degrees of freedom [1].
+///
+/// [1]: http://dlmf.nist.gov/8.17#E8
+#[derive(Clone, Copy, Debug)]
+pub struct StudentsT {
+    df: f64,
+}
+
+impl StudentsT {
+    /// The t-distribution with `df` degrees of freedom, which need not be a
+    /// whole number, but must be positive.
+    pub fn new(df: f64) -> Result<Self, Error> {
+        if !(df > 0.0 && df.is_finite()) {
+            return Err(Error::Undefined);
+        }
+
+        Ok(StudentsT { df })
+    }
+
+    pub fn df(&self) -> f64 {
+        self.df
+    }
+
+    /// The definite integral of the density function over an interval
+    /// [-t, t]. Also called the A(t|ν) function.
+    ///
+    /// See equation 6.4.9 in [1].
+    ///
+    /// [1]: "Numerical Recipes in C", 2nd Ed., p. 228
+    pub fn a(&self, t: f64) -> Result<f64, Error> {
+        let df = self.df;
+        let x = df / (df + t.powi(2));
+        let ib = num::inc_beta(x, 0.5 * df, 0.5)?;
+
+        Ok(1.0 - ib)
+    }
+
+    /// The critical value for a two-sided confidence interval: the `t` for
+    /// which `A(t|ν)` is `confidence`. Found by bisection.
+    pub fn critical(&self, confidence: f64) -> Result<f64, Error> {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            return Err(Error::Undefined);
+        }
+
+        let mut hi = 1.0;
+        while self.a(hi)? < confidence {
+            hi *= 2.0;
+
+            if !hi.is_finite() {
+                return Err(Error::Diverged);
+            }
+        }
+
+        let mut lo = 0.0;
+        for _ in 0..64 {
+            let mid = 0.5 * (lo + hi);
+
+            if self.a(mid)? < confidence {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+
+        Ok(0.5 * (lo + hi))
+    }
+}
+
+impl Distribution for StudentsT {
+    fn pdf(&self, x: f64) -> f64 {
+        let df = self.df;
+        let ln_norm = num::ln_gamma(0.5 * (df + 1.0)) - num::ln_gamma(0.5 * df)
+            - 0.5 * (df * PI).ln();
+
+        (ln_norm - 0.5 * (df + 1.0) * (x.powi(2) / df).ln_1p()).exp()
+    }
+
+    fn cdf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+
+        // By symmetry, from the probability of the interval [-|x|, |x|].
+        let a = self.a(x.abs())?;
+
+        Ok(if x < 0.0 { 0.5 * (1.0 - a) } else { 0.5 * (1.0 + a) })
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64, Error> {
+        if !(p > 0.0 && p < 1.0) {
+            return Err(Error::Undefined);
+        }
+        if p == 0.5 {
+            return Ok(0.0);
+        }
+
+        let t = self.critical((2.0 * p - 1.0).abs())?;
+
+        Ok(if p < 0.5 { -t } else { t })
+    }
+}
+
+/// The normal distribution with mean `mean` and standard deviation `sd` [1].
+///
+/// [1]: http://dlmf.nist.gov/7.2#E2
+#[derive(Clone, Copy, Debug)]
+pub struct Normal {
+    mean: f64,
+    sd: f64,
+}
+
+impl Normal {
+    /// The normal distribution with the given mean and a positive standard
+    /// deviation.
+    pub fn new(mean: f64, sd: f64) -> Result<Self, Error> {
+        if !(mean.is_finite() && sd > 0.0 && sd.is_finite()) {
+            return Err(Error::Undefined);
+        }
+
+        Ok(Normal { mean, sd })
+    }
+
+    /// The standard normal distribution, with mean 0 and standard deviation 1.
+    pub fn standard() -> Self {
+        Normal { mean: 0.0, sd: 1.0 }
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    pub fn sd(&self) -> f64 {
+        self.sd
+    }
+}
+
+impl Distribution for Normal {
+    fn pdf(&self, x: f64) -> f64 {
+        let z = (x - self.mean) / self.sd;
+
+        (-0.5 * z.powi(2)).exp() / (self.sd * (2.0 * PI).sqrt())
+    }
+
+    fn cdf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+
+        let z = (x - self.mean) / self.sd;
+
+        Ok(0.5 * num::erfc(-z / SQRT_2))
+    }
+
+    fn sf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+
+        let z = (x - self.mean) / self.sd;
+
+        Ok(0.5 * num::erfc(z / SQRT_2))
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64, Error> {
+        if !(p > 0.0 && p < 1.0) {
+            return Err(Error::Undefined);
+        }
+
+        Ok(self.mean + self.sd * normal_quantile(p))
+    }
+}
+
+/// The standard normal quantile function, by Wichura's algorithm AS 241 [1],
+/// which is accurate to about 1 part in 10^16.
+///
+/// [1]: Wichura, M. J. (1988). "Algorithm AS 241: The Percentage Points of the
+///      Normal Distribution". Applied Statistics 37 (3): 477–484.
+fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 8] = [
+        3.3871328727963665, 133.14166789178438,
+        1971.5909503065513, 13731.69376550946,
+        45921.95393154987, 67265.7709270087,
+        33430.57558358813, 2509.0809287301227,
+    ];
+    const B: [f64; 8] = [
+        1.0, 42.31333070160091,
+        687.1870074920579, 5394.196021424751,
+        21213.794301586597, 39307.89580009271,
+        28729.085735721943, 5226.495278852854,
+    ];
+    const C: [f64; 8] = [
+        1.4234371107496835, 4.630337846156546,
+        5.769497221460691, 3.6478483247632045,
+        1.2704582524523684, 0.2417807251774506,
+        0.022723844989269184, 0.0007745450142783414,
+    ];
+    const D: [f64; 8] = [
+        1.0, 2.053191626637759,
+        1.6763848301838038, 0.6897673349851,
+        0.14810397642748008, 0.015198666563616457,
+        0.0005475938084995345, 1.0507500716444169e-09,
+    ];
+    const E: [f64; 8] = [
+        6.657904643501103, 5.463784911164114,
+        1.7848265399172913, 0.29656057182850487,
+        0.026532189526576124, 0.0012426609473880784,
+        2.7115555687434876e-05, 2.0103343992922881e-07,
+    ];
+    const F: [f64; 8] = [
+        1.0, 0.599832206555888,
+        0.1369298809227358, 0.014875361290850615,
+        0.0007868691311456133, 1.8463183175100548e-05,
+        1.421511758316446e-07, 2.0442631033899397e-15,
+    ];
+
+    // Evaluate the rational function with numerator and denominator
+    // coefficients `n` and `d` at `r`, by Horner's method.
+    let ratio = |n: &[f64; 8], d: &[f64; 8], r: f64| {
+        let num = n.iter().rev().fold(0.0, |acc, c| acc * r + c);
+        let den = d.iter().rev().fold(0.0, |acc, c| acc * r + c);
+        num / den
+    };
+
+    let q = p - 0.5;
+    if q.abs() <= 0.425 {
+        return q * ratio(&A, &B, 0.180625 - q * q);
+    }
+
+    let r = if q < 0.0 { p } else { 1.0 - p };
+    let r = (-r.ln()).sqrt();
+
+    let z = if r <= 5.0 {
+        ratio(&C, &D, r - 1.6)
+    } else {
+        ratio(&E, &F, r - 5.0)
+    };
+
+    if q < 0.0 { -z } else { z }
+}
+
+/// The chi-squared distribution with `df` degrees of freedom [1].
+///
+/// [1]: http://dlmf.nist.gov/8.11#iv
+#[derive(Clone, Copy, Debug)]
+pub struct ChiSquared {
+    df: f64,
+}
+
+impl ChiSquared {
+    /// The chi-squared distribution with `df` degrees of freedom, which need
+    /// not be a whole number, but must be positive.
+    pub fn new(df: f64) -> Result<Self, Error> {
+        if !(df > 0.0 && df.is_finite()) {
+            return Err(Error::Undefined);
+        }
+
+        Ok(ChiSquared { df })
+    }
+
+    pub fn df(&self) -> f64 {
+        self.df
+    }
+}
+
+impl Distribution for ChiSquared {
+    fn pdf(&self, x: f64) -> f64 {
+        let k = 0.5 * self.df;
+
+        if x < 0.0 {
+            return 0.0;
+        }
+        if x == 0.0 {
+            return if k < 1.0 { f64::INFINITY } else if k == 1.0 { 0.5 } else { 0.0 };
+        }
+
+        ((k - 1.0) * x.ln() - 0.5 * x - k * 2f64.ln() - num::ln_gamma(k)).exp()
+    }
+
+    fn cdf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+
+        num::inc_gamma(0.5 * self.df, 0.5 * x.max(0.0))
+    }
+
+    fn sf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+
+        num::inc_gamma_complement(0.5 * self.df, 0.5 * x.max(0.0))
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64, Error> {
+        positive_quantile(self, p)
+    }
+}
+
+/// The F-distribution with `d1` and `d2` degrees of freedom [1].
+///
+/// [1]: http://dlmf.nist.gov/8.17#E4
+#[derive(Clone, Copy, Debug)]
+pub struct FisherF {
+    d1: f64,
+    d2: f64,
+}
+
+impl FisherF {
+    /// The F-distribution with `d1` numerator and `d2` denominator degrees of
+    /// freedom, both positive.
+    pub fn new(d1: f64, d2: f64) -> Result<Self, Error> {
+        if !(d1 > 0.0 && d1.is_finite() && d2 > 0.0 && d2.is_finite()) {
+            return Err(Error::Undefined);
+        }
+
+        Ok(FisherF { d1, d2 })
+    }
+
+    pub fn d1(&self) -> f64 {
+        self.d1
+    }
+
+    pub fn d2(&self) -> f64 {
+        self.d2
+    }
+}
+
+impl Distribution for FisherF {
+    fn pdf(&self, x: f64) -> f64 {
+        let (d1, d2) = (self.d1, self.d2);
+
+        if x < 0.0 {
+            return 0.0;
+        }
+        if x == 0.0 {
+            return if d1 < 2.0 { f64::INFINITY } else if d1 == 2.0 { 1.0 } else { 0.0 };
+        }
+
+        let ln_num = d1 * (d1 * x).ln() + d2 * d2.ln() - (d1 + d2) * (d1 * x + d2).ln();
+
+        (0.5 * ln_num - x.ln() - num::ln_beta(0.5 * d1, 0.5 * d2)).exp()
+    }
+
+    fn cdf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+        if x <= 0.0 {
+            return Ok(0.0);
+        }
+
+        let (d1, d2) = (self.d1, self.d2);
+        num::inc_beta(d1 * x / (d1 * x + d2), 0.5 * d1, 0.5 * d2)
+    }
+
+    fn sf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+        if x <= 0.0 {
+            return Ok(1.0);
+        }
+
+        // The complement via the symmetry relation `1 - I_x(a, b) = I_{1-x}(b, a)`.
+        let (d1, d2) = (self.d1, self.d2);
+        num::inc_beta(d2 / (d2 + d1 * x), 0.5 * d2, 0.5 * d1)
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64, Error> {
+        positive_quantile(self, p)
+    }
+}
+
+/// The continuous uniform distribution on the interval [`min`, `max`].
+#[derive(Clone, Copy, Debug)]
+pub struct Uniform {
+    min: f64,
+    max: f64,
+}
+
+impl Uniform {
+    /// The uniform distribution on [`min`, `max`], where `min < max`.
+    pub fn new(min: f64, max: f64) -> Result<Self, Error> {
+        if !(min.is_finite() && max.is_finite() && min < max) {
+            return Err(Error::Undefined);
+        }
+
+        Ok(Uniform { min, max })
+    }
+
+    pub fn min(&self) -> f64 {
+        self.min
+    }
+
+    pub fn max(&self) -> f64 {
+        self.max
+    }
+}
+
+impl Distribution for Uniform {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < self.min || x > self.max {
+            return 0.0;
+        }
+
+        (self.max - self.min).recip()
+    }
+
+    fn cdf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+
+        Ok(((x - self.min) / (self.max - self.min)).clamp(0.0, 1.0))
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64, Error> {
+        if !(p > 0.0 && p < 1.0) {
+            return Err(Error::Undefined);
+        }
+
+        Ok(self.min + p * (self.max - self.min))
+    }
+}
+
+/// The exponential distribution with rate `rate`, whose mean is `1 / rate`.
+#[derive(Clone, Copy, Debug)]
+pub struct Exponential {
+    rate: f64,
+}
+
+impl Exponential {
+    /// The exponential distribution with a positive rate.
+    pub fn new(rate: f64) -> Result<Self, Error> {
+        if !(rate > 0.0 && rate.is_finite()) {
+            return Err(Error::Undefined);
+        }
+
+        Ok(Exponential { rate })
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+}
+
+impl Distribution for Exponential {
+    fn pdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            return 0.0;
+        }
+
+        self.rate * (-self.rate * x).exp()
+    }
+
+    fn cdf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+
+        Ok(-(-self.rate * x.max(0.0)).exp_m1())
+    }
+
+    fn sf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+
+        Ok((-self.rate * x.max(0.0)).exp())
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64, Error> {
+        if !(p > 0.0 && p < 1.0) {
+            return Err(Error::Undefined);
+        }
+
+        Ok(-(-p).ln_1p() / self.rate)
+    }
+}
+
+/// The log-normal distribution, of a value whose natural logarithm is
+/// normally distributed with mean `mu` and standard deviation `sigma`.
+#[derive(Clone, Copy, Debug)]
+pub struct LogNormal {
+    normal: Normal,
+}
+
+impl LogNormal {
+    /// The log-normal distribution whose logarithm has mean `mu` and positive
+    /// standard deviation `sigma`.
+    pub fn new(mu: f64, sigma: f64) -> Result<Self, Error> {
+        Ok(LogNormal { normal: Normal::new(mu, sigma)? })
+    }
+
+    pub fn mu(&self) -> f64 {
+        self.normal.mean()
+    }
+
+    pub fn sigma(&self) -> f64 {
+        self.normal.sd()
+    }
+}
+
+impl Distribution for LogNormal {
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+
+        self.normal.pdf(x.ln()) / x
+    }
+
+    fn cdf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+        if x <= 0.0 {
+            return Ok(0.0);
+        }
+
+        self.normal.cdf(x.ln())
+    }
+
+    fn sf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+        if x <= 0.0 {
+            return Ok(1.0);
+        }
+
+        self.normal.sf(x.ln())
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64, Error> {
+        Ok(self.normal.quantile(p)?.exp())
+    }
+}
+
+/// The gamma distribution with shape `shape` and rate `rate`, whose mean is
+/// `shape / rate` [1].
+///
+/// [1]: http://dlmf.nist.gov/8.2#E4
+#[derive(Clone, Copy, Debug)]
+pub struct Gamma {
+    shape: f64,
+    rate: f64,
+}
+
+impl Gamma {
+    /// The gamma distribution with a positive shape and rate.
+    pub fn new(shape: f64, rate: f64) -> Result<Self, Error> {
+        if !(shape > 0.0 && shape.is_finite() && rate > 0.0 && rate.is_finite()) {
+            return Err(Error::Undefined);
+        }
+
+        Ok(Gamma { shape, rate })
+    }
+
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+}
+
+impl Distribution for Gamma {
+    fn pdf(&self, x: f64) -> f64 {
+        let (k, rate) = (self.shape, self.rate);
+
+        if x < 0.0 {
+            return 0.0;
+        }
+        if x == 0.0 {
+            return if k < 1.0 { f64::INFINITY } else if k == 1.0 { rate } else { 0.0 };
+        }
+
+        (k * rate.ln() + (k - 1.0) * x.ln() - rate * x - num::ln_gamma(k)).exp()
+    }
+
+    fn cdf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+
+        num::inc_gamma(self.shape, self.rate * x.max(0.0))
+    }
+
+    fn sf(&self, x: f64) -> Result<f64, Error> {
+        if x.is_nan() {
+            return Err(Error::Undefined);
+        }
+
+        num::inc_gamma_complement(self.shape, self.rate * x.max(0.0))
+    }
+
+    fn quantile(&self, p: f64) -> Result<f64, Error> {
+        positive_quantile(self, p)
+    }
+}
+
+/// The quantile of a distribution supported on the positive reals, found by
+/// bracketing and bisection of its CDF.
+fn positive_quantile<D: Distribution>(dist: &D, p: f64) -> Result<f64, Error> {
+    if !(p > 0.0 && p < 1.0) {
+        return Err(Error::Undefined);
+    }
+
+    let mut hi = 1.0;
+    while dist.cdf(hi)? < p {
+        hi *= 2.0;
+
+        if !hi.is_finite() {
+            return Err(Error::Diverged);
+        }
+    }
+
+    let mut lo = 0.0;
+    for _ in 0..1100 {
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            break;
+        }
+
+        if dist.cdf(mid)? < p {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Ok(0.5 * (lo + hi))
+}
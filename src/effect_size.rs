@@ -0,0 +1,29 @@
+use summary::{Summarizer, Summary};
+use t_test::pooled_sd;
+
+
+/// Cohen's d of `s2` relative to `s1`: the difference of their means,
+/// `m₂ - m₁`, in units of their pooled standard deviation.
+pub fn cohens_d(s1: &Summary, s2: &Summary) -> f64 {
+    (s2.mean() - s1.mean()) / pooled_sd(s1, s2)
+}
+
+/// Cliff's delta of `s2` relative to `s1`: the probability that a value of
+/// `s2` exceeds one of `s1`, less the probability that it is exceeded. It
+/// ranges from -1, if each value of `s2` is less than any of `s1`, to 1, and
+/// like a rank test, it depends only on the order of the values.
+pub fn cliffs_delta(s1: &Summarizer, s2: &Summarizer) -> f64 {
+    let a = s1.as_slice();
+
+    // Count, for each value of `s2`, the values of `s1` below and above it,
+    // which are sorted.
+    let mut dominance = 0.0;
+    for &x in s2.as_slice() {
+        let below = a.partition_point(|&y| y < x);
+        let above = a.len() - a.partition_point(|&y| y <= x);
+
+        dominance += below as f64 - above as f64;
+    }
+
+    dominance / (s1.size() * s2.size())
+}
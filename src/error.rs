@@ -1,27 +1,45 @@
 use std;
 
+#[cfg(not(feature = "std"))] use prelude::*;
+
 
 #[derive(Debug)]
 pub enum Error {
+    /// Sample data are outside the domain of a statistic, such as ratios
+    /// which are not positive.
     BadSample,
     Diverged,
     EmptySample,
+    /// Paired data have different numbers of values, given in order.
+    MismatchedLengths(usize, usize),
+    /// Sample data include a NaN or infinite value.
+    NonFinite,
+    /// Input could not be parsed on `line`, counted from 1.
+    Parse { line: usize, message: String },
+    /// A model cannot be fit, as when a predictor has only one value.
+    SingularFit,
     Undefined,
 }
 
 impl std::fmt::Display for Error {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
-        write!(f, "{}", std::error::Error::description(self))
-    }
-}
-
-impl std::error::Error for Error {
-    fn description(&self) -> &str {
-        match *self {
-            Error::BadSample => "All sample data must be finite",
+        let description = match *self {
+            Error::BadSample => "Sample data are outside the domain of the statistic",
             Error::Diverged => "Numeric evaluation diverged",
             Error::EmptySample => "Sample data set cannot be empty",
+            Error::MismatchedLengths(a, b) => {
+                return write!(f, "Paired samples must have equal sizes, not {} and {}", a, b);
+            },
+            Error::NonFinite => "All sample data must be finite",
+            Error::Parse { line, ref message } => {
+                return write!(f, "On line {}: {}", line, message);
+            },
+            Error::SingularFit => "Cannot fit a model to data without variation in the predictor",
             Error::Undefined => "Function undefined for argument",
-        }
+        };
+
+        write!(f, "{}", description)
     }
 }
+
+impl std::error::Error for Error {}
@@ -0,0 +1,161 @@
+//! C bindings of summaries and Welch's t-test, with the `ffi` feature.
+//!
+//! Build them as a library with `--crate-type cdylib` or `staticlib`, and
+//! include `include/dent.h`, which `cbindgen` generates from this module:
+//!
+//! ```sh
+//! cargo rustc --lib --release --features ffi --crate-type cdylib
+//! cbindgen --config cbindgen.toml --output include/dent.h
+//! ```
+//!
+//! Each function returns a `DentStatus`, writing its result through its `out`
+//! pointer only on success.
+use std;
+
+use error::Error;
+use summary::Summary;
+use t_test::welch_t_test;
+
+
+/// The outcome of a call, `DENT_STATUS_OK` on success, or else the error.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DentStatus {
+    Ok = 0,
+    /// A pointer argument was null.
+    NullPointer,
+    EmptySample,
+    NonFinite,
+    Undefined,
+    /// Any other error of the statistic.
+    Failed,
+}
+
+impl From<Error> for DentStatus {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::EmptySample => DentStatus::EmptySample,
+            Error::NonFinite => DentStatus::NonFinite,
+            Error::Undefined => DentStatus::Undefined,
+            _ => DentStatus::Failed,
+        }
+    }
+}
+
+/// The summary statistics of a sample, as `summary::Summary`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DentSummary {
+    pub size: usize,
+    pub min: f64,
+    pub min_adjacent: f64,
+    pub lower_quartile: f64,
+    pub median: f64,
+    pub upper_quartile: f64,
+    pub max_adjacent: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub unbiased_variance: f64,
+    pub standard_deviation: f64,
+    pub standard_error: f64,
+}
+
+impl<'a> From<&'a Summary> for DentSummary {
+    fn from(s: &'a Summary) -> Self {
+        DentSummary {
+            size: s.size() as usize,
+            min: s.min(),
+            min_adjacent: s.min_adjacent(),
+            lower_quartile: s.lower_quartile(),
+            median: s.median(),
+            upper_quartile: s.upper_quartile(),
+            max_adjacent: s.max_adjacent(),
+            max: s.max(),
+            mean: s.mean(),
+            unbiased_variance: s.unbiased_variance(),
+            standard_deviation: s.standard_deviation(),
+            standard_error: s.standard_error(),
+        }
+    }
+}
+
+impl DentSummary {
+    fn to_summary(self) -> Result<Summary, Error> {
+        Summary::from_parts(self.size, self.mean, self.unbiased_variance, (self.min, self.max),
+                            (self.min_adjacent, self.max_adjacent),
+                            (self.lower_quartile, self.median, self.upper_quartile))
+    }
+}
+
+/// The results of a t-test, as `t_test::TTest`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DentTTest {
+    pub p: f64,
+    pub t: f64,
+    pub df: f64,
+}
+
+/// Summarize the `len` values at `data`, writing the summary to `out`.
+///
+/// # Safety
+///
+/// `data` must point to `len` readable values, unless `len` is 0, and `out`
+/// to a writable `DentSummary`.
+#[no_mangle]
+pub unsafe extern "C" fn dent_summary(data: *const f64, len: usize, out: *mut DentSummary)
+                                      -> DentStatus {
+    if out.is_null() || (data.is_null() && len > 0) {
+        return DentStatus::NullPointer;
+    }
+
+    let data = if len == 0 { &[] } else { std::slice::from_raw_parts(data, len) };
+
+    match Summary::new(data) {
+        Ok(s) => {
+            *out = DentSummary::from(&s);
+            DentStatus::Ok
+        },
+        Err(e) => e.into(),
+    }
+}
+
+/// Conduct Welch's t-test of two summaries, writing the results to `out`.
+///
+/// # Safety
+///
+/// `s1` and `s2` must point to readable summaries, and `out` to a writable
+/// `DentTTest`.
+#[no_mangle]
+pub unsafe extern "C" fn dent_welch_t_test(s1: *const DentSummary, s2: *const DentSummary,
+                                           out: *mut DentTTest) -> DentStatus {
+    if s1.is_null() || s2.is_null() || out.is_null() {
+        return DentStatus::NullPointer;
+    }
+
+    let t = (*s1).to_summary()
+        .and_then(|s1| (*s2).to_summary().and_then(|s2| welch_t_test(&s1, &s2)));
+
+    match t {
+        Ok(t) => {
+            *out = DentTTest { p: t.p, t: t.t, df: t.df };
+            DentStatus::Ok
+        },
+        Err(e) => e.into(),
+    }
+}
+
+/// A description of `status`, as a static, null-terminated string.
+#[no_mangle]
+pub extern "C" fn dent_status_message(status: DentStatus) -> *const std::os::raw::c_char {
+    let message: &'static [u8] = match status {
+        DentStatus::Ok => b"Success\0",
+        DentStatus::NullPointer => b"A pointer argument was null\0",
+        DentStatus::EmptySample => b"Sample data set cannot be empty\0",
+        DentStatus::NonFinite => b"All sample data must be finite\0",
+        DentStatus::Undefined => b"Function undefined for argument\0",
+        DentStatus::Failed => b"The statistic could not be computed\0",
+    };
+
+    message.as_ptr() as *const std::os::raw::c_char
+}
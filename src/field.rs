@@ -0,0 +1,276 @@
+use dent::change::CONFIDENCE;
+use dent::summary::Summarizer;
+
+
+/// A summary statistic that can be selected for output with `--fields`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Field {
+    Size,
+    Mean,
+    Median,
+    GeometricMean,
+    HarmonicMean,
+    StandardDeviation,
+    Variance,
+    StandardError,
+    Mad,
+    InterquartileMean,
+    Midhinge,
+    Min,
+    Max,
+    Range,
+    LowerQuartile,
+    UpperQuartile,
+    Iqr,
+    MinAdjacent,
+    MaxAdjacent,
+    /// A percentile, as a fraction in [0, 1].
+    Percentile(f64),
+    /// The bounds of the confidence interval of the mean, or of the median,
+    /// at a level such as 0.95.
+    MeanLower(f64),
+    MeanUpper(f64),
+    MedianLower(f64),
+    MedianUpper(f64),
+}
+
+use self::Field::*;
+
+/// Named fields, with their accepted spellings.
+const NAMES: &[(&[&str], Field)] = &[
+    (&["n", "size"], Size),
+    (&["mean"], Mean),
+    (&["median"], Median),
+    (&["geomean"], GeometricMean),
+    (&["hmean"], HarmonicMean),
+    (&["stddev", "sd"], StandardDeviation),
+    (&["variance", "var"], Variance),
+    (&["stderr", "se"], StandardError),
+    (&["mad"], Mad),
+    (&["iqm"], InterquartileMean),
+    (&["midhinge"], Midhinge),
+    (&["min"], Min),
+    (&["max"], Max),
+    (&["range"], Range),
+    (&["q1"], LowerQuartile),
+    (&["q3"], UpperQuartile),
+    (&["iqr"], Iqr),
+    (&["min_adj"], MinAdjacent),
+    (&["max_adj"], MaxAdjacent),
+];
+
+/// Fields of the bounds of confidence intervals, named together, with those
+/// at the default level, which `at_confidence` changes.
+const INTERVALS: &[(&str, [Field; 2])] = &[
+    ("mean_ci", [MeanLower(CONFIDENCE), MeanUpper(CONFIDENCE)]),
+    ("median_ci", [MedianLower(CONFIDENCE), MedianUpper(CONFIDENCE)]),
+];
+
+impl Field {
+    /// Parse a comma-separated list of field names, such as `n,mean,p99`.
+    /// An interval, such as `mean_ci`, gives a field of each of its bounds.
+    pub fn parse_list(spec: &str) -> Result<Vec<Field>, String> {
+        let mut fields = vec![];
+
+        for name in spec.split(',').map(str::trim) {
+            match INTERVALS.iter().find(|&&(n, _)| n == name.to_lowercase()) {
+                Some(&(_, bounds)) => fields.extend_from_slice(&bounds),
+                None => fields.push(Field::parse(name)?),
+            }
+        }
+
+        Ok(fields)
+    }
+
+    /// The field with any confidence interval at `level` instead.
+    pub fn at_confidence(self, level: f64) -> Field {
+        match self {
+            MeanLower(_) => MeanLower(level),
+            MeanUpper(_) => MeanUpper(level),
+            MedianLower(_) => MedianLower(level),
+            MedianUpper(_) => MedianUpper(level),
+            f => f,
+        }
+    }
+
+    fn parse(name: &str) -> Result<Field, String> {
+        let lower = name.to_lowercase();
+
+        if let Some(&(_, f)) = NAMES.iter().find(|&&(names, _)| names.contains(&lower.as_str())) {
+            return Ok(f);
+        }
+
+        lower
+            .strip_prefix('p')
+            .and_then(|p| p.parse::<f64>().ok())
+            .filter(|p| (0.0..=100.0).contains(p))
+            .map(|p| Percentile(p / 100.0))
+            .ok_or_else(|| format!(
+                "Unknown field {:?}; expected one of {}, or a percentile such as p99",
+                name,
+                NAMES
+                    .iter()
+                    .map(|&(names, _)| names[0])
+                    .chain(INTERVALS.iter().map(|&(name, _)| name))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ))
+    }
+
+    /// The fields of the plain-text and Markdown summary tables.
+    pub fn table_defaults(outliers: bool) -> Vec<Field> {
+        let (min, max) = if outliers { (Min, Max) } else { (MinAdjacent, MaxAdjacent) };
+
+        vec![Size, min, LowerQuartile, Median, UpperQuartile, max, Mean, StandardDeviation]
+    }
+
+    /// The fields of JSON and delimited output, which include everything in a
+    /// `Summary`.
+    pub fn all_defaults() -> Vec<Field> {
+        vec![
+            Size,
+            Mean,
+            Median,
+            StandardDeviation,
+            Variance,
+            StandardError,
+            Min,
+            Max,
+            Range,
+            LowerQuartile,
+            UpperQuartile,
+            Iqr,
+            MinAdjacent,
+            MaxAdjacent,
+        ]
+    }
+
+    /// Column title for human-readable tables.
+    pub fn title(&self) -> String {
+        match *self {
+            Size => "Size",
+            Mean => "Mean",
+            Median => "Median",
+            GeometricMean => "Geomean",
+            HarmonicMean => "Hmean",
+            StandardDeviation => "Std Dev",
+            Variance => "Variance",
+            StandardError => "Std Err",
+            Mad => "MAD",
+            InterquartileMean => "IQM",
+            Midhinge => "Midhinge",
+            Min => "Min",
+            Max => "Max",
+            Range => "Range",
+            LowerQuartile => "Q1",
+            UpperQuartile => "Q3",
+            Iqr => "IQR",
+            MinAdjacent => "Min Adj",
+            MaxAdjacent => "Max Adj",
+            Percentile(p) => return percentile_name(p),
+            MeanLower(_) => "Mean Lo",
+            MeanUpper(_) => "Mean Hi",
+            MedianLower(_) => "Median Lo",
+            MedianUpper(_) => "Median Hi",
+        }.to_string()
+    }
+
+    /// Column header for delimited output.
+    pub fn header(&self) -> String {
+        match *self {
+            Size => "Size",
+            Mean => "Mean",
+            Median => "Median",
+            GeometricMean => "GeometricMean",
+            HarmonicMean => "HarmonicMean",
+            StandardDeviation => "StandardDeviation",
+            Variance => "Variance",
+            StandardError => "StandardError",
+            Mad => "MAD",
+            InterquartileMean => "InterquartileMean",
+            Midhinge => "Midhinge",
+            Min => "Min",
+            Max => "Max",
+            Range => "Range",
+            LowerQuartile => "LowerQuartile",
+            UpperQuartile => "UpperQuartile",
+            Iqr => "IQR",
+            MinAdjacent => "MinAdjacent",
+            MaxAdjacent => "MaxAdjacent",
+            Percentile(p) => return percentile_name(p),
+            MeanLower(_) => "MeanLower",
+            MeanUpper(_) => "MeanUpper",
+            MedianLower(_) => "MedianLower",
+            MedianUpper(_) => "MedianUpper",
+        }.to_string()
+    }
+
+    /// Object key for JSON output.
+    pub fn key(&self) -> String {
+        match *self {
+            Size => "size",
+            Mean => "mean",
+            Median => "median",
+            GeometricMean => "geometric_mean",
+            HarmonicMean => "harmonic_mean",
+            StandardDeviation => "standard_deviation",
+            Variance => "variance",
+            StandardError => "standard_error",
+            Mad => "median_absolute_deviation",
+            InterquartileMean => "interquartile_mean",
+            Midhinge => "midhinge",
+            Min => "min",
+            Max => "max",
+            Range => "range",
+            LowerQuartile => "lower_quartile",
+            UpperQuartile => "upper_quartile",
+            Iqr => "iqr",
+            MinAdjacent => "min_adjacent",
+            MaxAdjacent => "max_adjacent",
+            Percentile(p) => return percentile_name(p),
+            MeanLower(_) => "mean_lower",
+            MeanUpper(_) => "mean_upper",
+            MedianLower(_) => "median_lower",
+            MedianUpper(_) => "median_upper",
+        }.to_string()
+    }
+
+    /// The value of the field for a sample. Undefined values, such as the
+    /// geometric or harmonic mean of data which are not all positive, are NaN.
+    pub fn value(&self, s: &Summarizer) -> f64 {
+        match *self {
+            Size => s.size(),
+            Mean => s.mean(),
+            Median => s.median(),
+            GeometricMean => s.geometric_mean().unwrap_or(f64::NAN),
+            HarmonicMean => s.harmonic_mean().unwrap_or(f64::NAN),
+            StandardDeviation => s.standard_deviation(),
+            Variance => s.unbiased_variance(),
+            StandardError => s.standard_error(),
+            Mad => s.median_absolute_deviation(),
+            InterquartileMean => s.interquartile_mean(),
+            Midhinge => s.midhinge(),
+            Min => s.min(),
+            Max => s.max(),
+            Range => s.range(),
+            LowerQuartile => s.lower_quartile(),
+            UpperQuartile => s.upper_quartile(),
+            Iqr => s.iqr(),
+            MinAdjacent => s.min_adjacent(),
+            MaxAdjacent => s.max_adjacent(),
+            Percentile(p) => s.percentile(p).unwrap_or(f64::NAN),
+            MeanLower(level) => s.mean_ci(level).map_or(f64::NAN, |ci| ci.0),
+            MeanUpper(level) => s.mean_ci(level).map_or(f64::NAN, |ci| ci.1),
+            MedianLower(level) => s.median_ci(level).map_or(f64::NAN, |ci| ci.0),
+            MedianUpper(level) => s.median_ci(level).map_or(f64::NAN, |ci| ci.1),
+        }
+    }
+}
+
+/// Name a percentile as it is written on the command line, e.g. `p99.9`.
+fn percentile_name(p: f64) -> String {
+    // Round away the error of dividing by 100 when parsing.
+    let p = (p * 100.0 * 1e9).round() / 1e9;
+
+    format!("p{}", p)
+}
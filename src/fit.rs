@@ -0,0 +1,210 @@
+use dist::{Distribution, Exponential, Gamma, LogNormal, Normal};
+use error::Error;
+use num;
+
+
+/// A family of distributions which can be fit to a sample.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Family {
+    Normal,
+    LogNormal,
+    Exponential,
+    Gamma,
+}
+
+impl Family {
+    /// Every family, in the order candidates are tried.
+    pub const ALL: [Family; 4] = [
+        Family::Normal,
+        Family::LogNormal,
+        Family::Exponential,
+        Family::Gamma,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Family::Normal => "normal",
+            Family::LogNormal => "lognormal",
+            Family::Exponential => "exponential",
+            Family::Gamma => "gamma",
+        }
+    }
+
+    /// The names of the parameters of the family, in the order of
+    /// `Fit::parameters`.
+    pub fn parameter_names(&self) -> &'static [&'static str] {
+        match *self {
+            Family::Normal => &["mean", "sd"],
+            Family::LogNormal => &["mu", "sigma"],
+            Family::Exponential => &["rate"],
+            Family::Gamma => &["shape", "rate"],
+        }
+    }
+}
+
+/// A maximum-likelihood fit of a family of distributions to a sample.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Fit {
+    pub family: Family,
+
+    /// The estimated parameters, named by `Family::parameter_names()`.
+    pub parameters: Vec<f64>,
+
+    /// The log-likelihood of the sample under the fitted distribution.
+    pub log_likelihood: f64,
+
+    /// Akaike's information criterion, `2k - 2 ln L` for `k` parameters. Of
+    /// fits to the same sample, that with the least AIC is best.
+    pub aic: f64,
+
+    /// The Kolmogorov-Smirnov statistic: the greatest distance between the
+    /// empirical CDF of the sample and the CDF of the fitted distribution.
+    pub ks: f64,
+}
+
+impl Fit {
+    /// The fitted distribution.
+    pub fn distribution(&self) -> Box<dyn Distribution> {
+        distribution(self.family, &self.parameters)
+            .expect("parameters of a fit define a distribution")
+    }
+}
+
+/// Fit a distribution of `family` to `data` by maximum likelihood.
+///
+/// The log-normal, exponential, and gamma families require positive data, and
+/// otherwise fail with `Error::BadSample`. A sample without variation cannot be
+/// fit by any family but the exponential.
+pub fn fit(data: &[f64], family: Family) -> Result<Fit, Error> {
+    if data.is_empty() {
+        return Err(Error::EmptySample);
+    }
+    if data.iter().any(|x| !x.is_finite()) {
+        return Err(Error::NonFinite);
+    }
+    if family != Family::Normal && data.iter().any(|&x| x <= 0.0) {
+        return Err(Error::BadSample);
+    }
+
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+
+    let parameters = match family {
+        Family::Normal => vec![mean, mle_sd(data, mean)],
+        Family::LogNormal => {
+            let logs: Vec<f64> = data.iter().map(|x| x.ln()).collect();
+            let mu = logs.iter().sum::<f64>() / n;
+
+            vec![mu, mle_sd(&logs, mu)]
+        },
+        Family::Exponential => vec![mean.recip()],
+        Family::Gamma => {
+            let mean_ln = data.iter().map(|x| x.ln()).sum::<f64>() / n;
+            let shape = gamma_shape(mean.ln() - mean_ln)?;
+
+            vec![shape, shape / mean]
+        },
+    };
+
+    let dist = distribution(family, &parameters)?;
+    let log_likelihood: f64 = data.iter().map(|&x| dist.pdf(x).ln()).sum();
+    let aic = 2.0 * parameters.len() as f64 - 2.0 * log_likelihood;
+    let ks = ks_statistic(data, &*dist)?;
+
+    Ok(Fit { family, parameters, log_likelihood, aic, ks })
+}
+
+/// Fit every family that admits `data`, ordered from best to worst by AIC.
+pub fn fit_all(data: &[f64]) -> Result<Vec<Fit>, Error> {
+    let mut fits = vec![];
+
+    for &family in &Family::ALL {
+        match fit(data, family) {
+            Ok(f) => fits.push(f),
+            Err(Error::BadSample) | Err(Error::Undefined) => {},
+            Err(e) => return Err(e),
+        }
+    }
+
+    if fits.is_empty() {
+        return Err(Error::Undefined);
+    }
+
+    fits.sort_by(|a, b| a.aic.total_cmp(&b.aic));
+
+    Ok(fits)
+}
+
+fn distribution(family: Family, parameters: &[f64]) -> Result<Box<dyn Distribution>, Error> {
+    let dist: Box<dyn Distribution> = match family {
+        Family::Normal => Box::new(Normal::new(parameters[0], parameters[1])?),
+        Family::LogNormal => Box::new(LogNormal::new(parameters[0], parameters[1])?),
+        Family::Exponential => Box::new(Exponential::new(parameters[0])?),
+        Family::Gamma => Box::new(Gamma::new(parameters[0], parameters[1])?),
+    };
+
+    Ok(dist)
+}
+
+/// The maximum-likelihood estimate of the standard deviation, which divides by
+/// `n` rather than `n - 1`.
+fn mle_sd(data: &[f64], mean: f64) -> f64 {
+    let n = data.len() as f64;
+
+    (data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt()
+}
+
+const GAMMA_SHAPE_MAX_ITER: usize = 100;
+const GAMMA_SHAPE_TOLERANCE: f64 = 1e-12;
+
+/// Solve `ln k - ψ(k) = s` for the maximum-likelihood shape `k` of a gamma
+/// distribution, where `s` is the log of the mean less the mean of the logs
+/// of the sample. Newton's method converges quickly from the closed-form
+/// approximation of Minka [1].
+///
+/// [1]: Minka, T. P. (2002). "Estimating a Gamma distribution".
+fn gamma_shape(s: f64) -> Result<f64, Error> {
+    // By Jensen's inequality, `s` is positive unless the data are all equal.
+    if !(s > 0.0 && s.is_finite()) {
+        return Err(Error::Undefined);
+    }
+
+    let mut k = (3.0 - s + ((s - 3.0).powi(2) + 24.0 * s).sqrt()) / (12.0 * s);
+
+    for _ in 0..GAMMA_SHAPE_MAX_ITER {
+        let f = k.ln() - num::digamma(k) - s;
+        let df = k.recip() - num::trigamma(k);
+        let next = k - f / df;
+
+        // Keep the iterate positive, where the equation is defined.
+        let next = if next > 0.0 { next } else { 0.5 * k };
+
+        if (next - k).abs() <= GAMMA_SHAPE_TOLERANCE * k {
+            return Ok(next);
+        }
+
+        k = next;
+    }
+
+    Err(Error::Diverged)
+}
+
+/// The Kolmogorov-Smirnov statistic of `data` against `dist`.
+fn ks_statistic<D: Distribution + ?Sized>(data: &[f64], dist: &D) -> Result<f64, Error> {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let n = sorted.len() as f64;
+    let mut d: f64 = 0.0;
+
+    for (i, &x) in sorted.iter().enumerate() {
+        let f = dist.cdf(x)?;
+        let i = i as f64;
+
+        d = d.max(f - i / n).max((i + 1.0) / n - f);
+    }
+
+    Ok(d)
+}
@@ -1,3 +1,10 @@
+#[cfg(not(feature = "std"))] use prelude::*;
+
+
+/// Width of the labels of statistics in plain-text output, such as the `p` of
+/// `p = 0.05`, so that their values line up.
+pub const LABEL_WIDTH: usize = 12;
+
 fn exp_parts(x: f64) -> (String, String) {
     let s = format!("{:e}", x);
     let parts: Vec<_> = s.split("e").collect();
@@ -62,3 +69,75 @@ pub fn f(x: f64, max_len: usize) -> String {
 
     format!("{:.0e}", x)
 }
+
+/// Format a float `x` to `digits` significant digits, keeping trailing zeros
+/// since they are significant. Like `printf("%#.*g")`, use scientific notation
+/// only for very small values, and those too large to write without it.
+pub fn sig(x: f64, digits: usize) -> String {
+    if !x.is_finite() {
+        return format!("{}", x);
+    }
+
+    let digits = digits.max(1);
+
+    // Round first, so that the exponent reflects any carry, e.g. 9.9996 to
+    // "1.000e1" for 4 digits.
+    let s = format!("{x:.p$e}", p = digits - 1, x = x);
+    let e: i32 = s.split('e').nth(1).and_then(|e| e.parse().ok()).unwrap_or(0);
+
+    if e < -4 || e >= digits as i32 {
+        s
+    } else {
+        format!("{x:.p$}", p = (digits as i32 - 1 - e) as usize, x = x)
+    }
+}
+
+/// Format `x` to `precision` significant digits if given, or else as `f()`
+/// does, within `max_len` characters.
+pub fn cell(x: f64, max_len: usize, precision: Option<usize>) -> String {
+    match precision {
+        Some(p) => sig(x, p),
+        None => f(x, max_len),
+    }
+}
+
+/// Format `x` in full precision, for output meant to be read by programs. The
+/// `Display` format of very small and large floats is impractically long, so
+/// use exponential notation for those.
+pub fn full(x: f64) -> String {
+    let a = x.abs();
+
+    if a != 0.0 && a.is_finite() && !(1e-5..1e16).contains(&a) {
+        format!("{:e}", x)
+    } else {
+        format!("{}", x)
+    }
+}
+
+/// Format a fraction `x` as a percentage, such as `7.31%`, to `precision`
+/// significant digits if given, or else to 3.
+pub fn percent(x: f64, precision: Option<usize>) -> String {
+    format!("{}%", sig(100.0 * x, precision.unwrap_or(3)))
+}
+
+/// Format a confidence level, such as 0.95, as a percentage, such as `95%`,
+/// rounding away the error of scaling it, as of 0.9 to 90.00000000000001.
+pub fn level(confidence: f64) -> String {
+    format!("{}%", (confidence * 100.0 * 1e9).round() / 1e9)
+}
+
+/// Format a fraction `x` as a percentage as `percent()` does, with a sign
+/// even if it is positive, such as `+7.31%`.
+pub fn signed_percent(x: f64, precision: Option<usize>) -> String {
+    let sign = if x > 0.0 { "+" } else { "" };
+
+    format!("{}{}", sign, percent(x, precision))
+}
+
+/// Format `x` to `precision` significant digits if given, or else in full.
+pub fn stat(x: f64, precision: Option<usize>) -> String {
+    match precision {
+        Some(p) => sig(x, p),
+        None => format!("{}", x),
+    }
+}
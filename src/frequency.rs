@@ -0,0 +1,44 @@
+use error::Error;
+use summary::Summarizer;
+
+
+/// The count of one distinct value of a sample.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Frequency {
+    pub value: f64,
+    pub count: usize,
+
+    /// The fraction of the sample equal to `value`.
+    pub proportion: f64,
+
+    /// The fraction of the sample at most `value`.
+    pub cumulative: f64,
+}
+
+/// Count each distinct value of `data`, treating the data as discrete, as
+/// for response codes or small integers. The counts are in increasing order
+/// of value.
+pub fn frequencies(data: &[f64]) -> Result<Vec<Frequency>, Error> {
+    let s = Summarizer::new(data)?;
+    let sorted = s.as_slice();
+    let n = sorted.len() as f64;
+
+    let mut frequencies: Vec<Frequency> = vec![];
+    let mut at_most = 0;
+
+    for &x in sorted {
+        at_most += 1;
+
+        match frequencies.last_mut() {
+            Some(f) if f.value == x => f.count += 1,
+            _ => frequencies.push(Frequency { value: x, count: 1, proportion: 0.0, cumulative: 0.0 }),
+        }
+
+        let f = frequencies.last_mut().unwrap_or_else(|| unreachable!());
+        f.proportion = f.count as f64 / n;
+        f.cumulative = at_most as f64 / n;
+    }
+
+    Ok(frequencies)
+}
@@ -0,0 +1,122 @@
+use error::Error;
+use summary::Summarizer;
+
+
+/// Counts of sample data in contiguous bins, of equal width unless made by
+/// `with_equal_frequency_bins()`.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Histogram {
+    counts: Vec<usize>,
+    edges: Vec<f64>,
+}
+
+impl Histogram {
+    /// Count the sample data in `bins` equal-width bins spanning the range of
+    /// the data. Each bin includes its lower edge, and the last bin also
+    /// includes its upper edge, the sample maximum.
+    pub fn new(data: &[f64], bins: usize) -> Result<Self, Error> {
+        if bins == 0 {
+            return Err(Error::Undefined);
+        }
+
+        let s = Summarizer::new(data)?;
+
+        // Give a sample of identical values a unit-width range around them.
+        let (lo, hi) = if s.range() > 0.0 {
+            (s.min(), s.max())
+        } else {
+            (s.min() - 0.5, s.max() + 0.5)
+        };
+        let width = (hi - lo) / bins as f64;
+
+        let edges: Vec<f64> = (0..(bins + 1))
+            .map(|i| if i == bins { hi } else { lo + i as f64 * width })
+            .collect();
+
+        let mut counts = vec![0; bins];
+
+        for &x in s.as_slice() {
+            let i = ((x - lo) / width).floor() as usize;
+            counts[i.min(bins - 1)] += 1;
+        }
+
+        Ok(Histogram { counts, edges })
+    }
+
+    /// Like `new()`, choosing the number of bins using Sturges' rule.
+    pub fn with_sturges_bins(data: &[f64]) -> Result<Self, Error> {
+        Histogram::new(data, sturges_bins(data.len()))
+    }
+
+    /// Count the sample data in up to `bins` bins of about equal counts,
+    /// with edges at evenly spaced percentiles of the data, so that the bins
+    /// are narrow where the data are dense. This shows heavily skewed data
+    /// much better than equal-width bins, most of which would be nearly
+    /// empty. Bins include their edges as those of `new()` do. Where tied
+    /// values would give a bin no width, it is merged into the next, leaving
+    /// fewer bins.
+    pub fn with_equal_frequency_bins(data: &[f64], bins: usize) -> Result<Self, Error> {
+        if bins == 0 {
+            return Err(Error::Undefined);
+        }
+
+        let s = Summarizer::new(data)?;
+
+        if s.range() == 0.0 {
+            return Histogram::new(data, 1);
+        }
+
+        let mut edges: Vec<f64> = (0..(bins + 1))
+            .map(|i| s.percentile(i as f64 / bins as f64))
+            .collect::<Result<_, _>>()?;
+        edges.dedup();
+
+        let bins = edges.len() - 1;
+        let mut counts = vec![0; bins];
+
+        for &x in s.as_slice() {
+            let i = edges[1..bins].partition_point(|&e| e <= x);
+            counts[i] += 1;
+        }
+
+        Ok(Histogram { counts, edges })
+    }
+
+    /// The density of the data in each bin: the fraction of the sample in
+    /// it, per unit of width, so that the area of each bin is its share of
+    /// the sample. Unlike the counts, it can be compared between bins of
+    /// different widths.
+    pub fn densities(&self) -> Vec<f64> {
+        let n: usize = self.counts.iter().sum();
+
+        self.counts
+            .iter()
+            .zip(self.edges.windows(2))
+            .map(|(&c, e)| c as f64 / n as f64 / (e[1] - e[0]))
+            .collect()
+    }
+
+    /// Number of bins.
+    pub fn bins(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Number of data points in each bin.
+    pub fn counts(&self) -> &[usize] {
+        &self.counts
+    }
+
+    /// Edges of the bins, in increasing order. The `i`th bin spans from the
+    /// `i`th to the `i + 1`th edge, so there is one more edge than bin.
+    pub fn edges(&self) -> &[f64] {
+        &self.edges
+    }
+}
+
+/// The number of bins for a sample of size `n` by Sturges' rule [1].
+///
+/// [1]: https://doi.org/10.1080/01621459.1926.10502161
+pub fn sturges_bins(n: usize) -> usize {
+    (n.max(1) as f64).log2().ceil() as usize + 1
+}
@@ -0,0 +1,254 @@
+use std::error;
+use std::fs;
+use std::io::{BufRead, Read};
+use std::path::Path;
+
+use input::{self, NamedSamples};
+use json::{self, Value};
+use unit::{TimeUnit, Unit};
+
+
+/// Named samples, each with the unit of its values, if any, in the base unit
+/// of its dimension.
+type Results = Vec<(String, Vec<f64>, Option<Unit>)>;
+
+
+/// Read the results of a benchmarking `tool` as one sample per benchmark,
+/// converting durations as directed by `opts`.
+pub fn read<R>(tool: &str, mut reader: R, opts: &input::Options)
+               -> Result<NamedSamples, Box<dyn error::Error>>
+    where R: BufRead {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+
+    let data = match tool {
+        "go" => durations(go(&text)?),
+        "hyperfine" => durations(hyperfine(&text)?),
+        "jmh" => jmh(&text)?,
+        _ => return Err(format!("Unknown benchmark results format {:?}", tool).into()),
+    };
+
+    data
+        .into_iter()
+        .map(|(name, d, unit)| {
+            let d = d
+                .into_iter()
+                .map(|x| opts.normalize(x, unit))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Result of {:?} {}", name, e))?;
+            Ok((name, d))
+        })
+        .collect()
+}
+
+fn durations(data: NamedSamples) -> Results {
+    data.into_iter().map(|(name, d)| (name, d, Some(Unit::Seconds))).collect()
+}
+
+/// Read the output of `go test -bench`: the time per operation of each run
+/// of each benchmark, in seconds. Lines other than results are ignored, as is
+/// the `Benchmark` prefix of names.
+fn go(text: &str) -> Result<NamedSamples, Box<dyn error::Error>> {
+    let mut benches: NamedSamples = vec![];
+
+    for line in text.lines() {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+
+        let name = match fields.first().and_then(|f| f.strip_prefix("Benchmark")) {
+            Some(name) if fields.len() >= 4 => name,
+            _ => continue,
+        };
+        let ns = match fields.iter().position(|&f| f == "ns/op") {
+            Some(i) if i >= 3 => fields[i - 1],
+            _ => continue,
+        };
+        let ns: f64 = ns
+            .parse()
+            .map_err(|_| format!("Invalid time per operation of {:?}: {:?}", name, ns))?;
+
+        match benches.iter_mut().find(|(n, _)| n == name) {
+            Some((_, times)) => times.push(ns * 1e-9),
+            None => benches.push((name.to_string(), vec![ns * 1e-9])),
+        }
+    }
+
+    if benches.is_empty() {
+        return Err("No Go benchmark results found".into());
+    }
+
+    Ok(benches)
+}
+
+/// Read JMH's JSON results: the score of each measurement iteration of each
+/// fork of each benchmark. Benchmarks with parameters are named with them, as
+/// `bench(size=10)`. Times per operation are converted to seconds, and
+/// throughputs to operations per second.
+fn jmh(text: &str) -> Result<Results, Box<dyn error::Error>> {
+    let results = json::parse(text)?;
+    let results = results.as_array().ok_or("Expected an array of JMH results")?;
+
+    results
+        .iter()
+        .map(|r| {
+            let mut name = r
+                .get("benchmark")
+                .and_then(Value::as_str)
+                .ok_or("Expected the \"benchmark\" of a JMH result")?
+                .to_string();
+
+            if let Some(Value::Object(params)) = r.get("params") {
+                let params = params
+                    .iter()
+                    .map(|(k, v)| {
+                        v.as_str()
+                            .map(|v| format!("{}={}", k, v))
+                            .ok_or_else(|| format!("Expected the value of {:?} of {:?}", k, name))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                name = format!("{}({})", name, params.join(","));
+            }
+
+            let metric = r.get("primaryMetric");
+            let forks = metric
+                .and_then(|m| m.get("rawData"))
+                .and_then(Value::as_array)
+                .ok_or_else(|| format!("Expected the raw data of {:?}", name))?;
+            let mut data = vec![];
+            for fork in forks {
+                data.extend(fork
+                    .as_f64_array()
+                    .ok_or_else(|| format!("Expected arrays of scores of {:?}", name))?);
+            }
+
+            let score_unit = metric
+                .and_then(|m| m.get("scoreUnit"))
+                .and_then(Value::as_str)
+                .unwrap_or("");
+            let (data, unit) = match score_unit.split_once('/') {
+                Some((time, "op")) => {
+                    let time = TimeUnit::parse(time)?;
+                    (data.into_iter().map(|x| time.seconds(x)).collect(), Some(Unit::Seconds))
+                },
+                Some(("ops", time)) => {
+                    let time = TimeUnit::parse(time)?;
+                    (data.into_iter().map(|x| x / time.seconds(1.0)).collect(), None)
+                },
+                _ => return Err(format!("Unknown unit of {:?}: {:?}", name, score_unit).into()),
+            };
+
+            Ok((name, data, unit))
+        })
+        .collect()
+}
+
+/// Read hyperfine's `--export-json` results: the times of the runs of each
+/// command, in seconds.
+fn hyperfine(text: &str) -> Result<NamedSamples, Box<dyn error::Error>> {
+    let results = json::parse(text)?;
+    let results = results
+        .get("results")
+        .and_then(Value::as_array)
+        .ok_or("Expected an array of hyperfine \"results\"")?;
+
+    results
+        .iter()
+        .map(|r| {
+            let command = r
+                .get("command")
+                .and_then(Value::as_str)
+                .ok_or("Expected the \"command\" of a hyperfine result")?;
+            let times = r
+                .get("times")
+                .and_then(Value::as_f64_array)
+                .ok_or_else(|| format!("Expected an array of \"times\" of {:?}", command))?;
+
+            Ok((command.to_string(), times))
+        })
+        .collect()
+}
+
+
+/// A benchmark measured by Criterion.rs: samples of the time per iteration,
+/// in nanoseconds, from its latest run and from its saved baseline, if any.
+pub struct Benchmark {
+    pub name: String,
+    pub base: Option<Vec<f64>>,
+    pub new: Vec<f64>,
+}
+
+/// Read the benchmarks in a Criterion.rs output directory, usually
+/// `target/criterion`, in order of their paths.
+pub fn criterion(dir: &str) -> Result<Vec<Benchmark>, Box<dyn error::Error>> {
+    let mut benches = vec![];
+    criterion_dir(Path::new(dir), Path::new(dir), &mut benches)?;
+
+    if benches.is_empty() {
+        return Err(format!("No Criterion.rs benchmarks found in {:?}", dir).into());
+    }
+
+    Ok(benches)
+}
+
+/// Find benchmarks in `dir`, a subdirectory of `root`, recursively. A
+/// benchmark is a directory with a `new` run.
+fn criterion_dir(root: &Path, dir: &Path, benches: &mut Vec<Benchmark>)
+                 -> Result<(), Box<dyn error::Error>> {
+    let new = dir.join("new");
+
+    if new.join("sample.json").is_file() {
+        let info = new.join("benchmark.json");
+        let name = if info.is_file() {
+            read_json(&info)?.get("full_id").and_then(Value::as_str).map(String::from)
+        } else {
+            None
+        };
+        let name = name.unwrap_or_else(|| {
+            dir.strip_prefix(root).unwrap_or(dir).to_string_lossy().into_owned()
+        });
+
+        let base = dir.join("base").join("sample.json");
+        let base = if base.is_file() { Some(criterion_sample(&base)?) } else { None };
+
+        benches.push(Benchmark { name, base, new: criterion_sample(&new.join("sample.json"))? });
+
+        return Ok(());
+    }
+
+    let mut entries = fs::read_dir(dir)?.collect::<Result<Vec<_>, _>>()?;
+    entries.sort_by_key(|e| e.path());
+
+    for e in entries {
+        // Reports are HTML, not data.
+        if e.file_type()?.is_dir() && e.file_name() != "report" {
+            criterion_dir(root, &e.path(), benches)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Read a `sample.json`, which records the total time of each of a number of
+/// iterations.
+fn criterion_sample(path: &Path) -> Result<Vec<f64>, Box<dyn error::Error>> {
+    let sample = read_json(path)?;
+    let field = |k| sample
+        .get(k)
+        .and_then(Value::as_f64_array)
+        .ok_or_else(|| format!("Expected an array of numbers {:?} in {:?}", k, path));
+
+    let iters = field("iters")?;
+    let times = field("times")?;
+
+    if iters.len() != times.len() {
+        return Err(format!("Mismatched iterations and times in {:?}", path).into());
+    }
+
+    Ok(times.iter().zip(&iters).map(|(t, n)| t / n).collect())
+}
+
+fn read_json(path: &Path) -> Result<Value, Box<dyn error::Error>> {
+    let mut text = String::new();
+    input::open_file(&path.to_string_lossy())?.read_to_string(&mut text)?;
+
+    Ok(json::parse(&text).map_err(|e| format!("{} in {:?}", e, path))?)
+}
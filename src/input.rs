@@ -0,0 +1,714 @@
+use std::error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::mem;
+use std::slice;
+use std::str;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use dent::error::Error;
+
+use progress::Progress;
+use timestamp;
+use unit::{self, TimeUnit, Unit};
+
+
+/// A column of delimited input, selected by header name or 1-based index.
+#[derive(Clone, Debug)]
+pub enum Column {
+    Name(String),
+    Index(usize),
+}
+
+impl Column {
+    /// Parse a column given on the command line: a positive integer is an
+    /// index, and anything else is a header name.
+    pub fn parse(s: &str) -> Result<Column, Box<dyn error::Error>> {
+        match s.parse::<usize>() {
+            Ok(0) => Err("Column indices start at 1".into()),
+            Ok(i) => Ok(Column::Index(i)),
+            Err(_) => Ok(Column::Name(s.to_string())),
+        }
+    }
+}
+
+/// How the fields of a line of columnar input are separated.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Delimiter {
+    /// Commas, with fields optionally quoted as in RFC 4180.
+    Csv,
+    /// Runs of whitespace, ignoring leading and trailing whitespace.
+    #[default]
+    Whitespace,
+    /// A single character. Adjacent delimiters separate empty fields.
+    Char(char),
+}
+
+impl Delimiter {
+    /// Parse a delimiter given on the command line: a single character, or
+    /// one of the names `tab` and `whitespace`.
+    pub fn parse(s: &str) -> Result<Delimiter, Box<dyn error::Error>> {
+        let mut chars = s.chars();
+
+        match (s, chars.next(), chars.next()) {
+            ("tab", _, _) | ("\\t", _, _) => Ok(Delimiter::Char('\t')),
+            ("whitespace", _, _) => Ok(Delimiter::Whitespace),
+            (_, Some(c), None) if c.is_whitespace() => Ok(Delimiter::Whitespace),
+            (_, Some(c), None) => Ok(Delimiter::Char(c)),
+            _ => Err(format!("Invalid delimiter {:?}: expected a single character", s).into()),
+        }
+    }
+
+    fn split(&self, line: &str) -> Vec<String> {
+        match *self {
+            Delimiter::Csv => split_csv(line),
+            Delimiter::Whitespace => line.split_whitespace().map(String::from).collect(),
+            Delimiter::Char(c) => line.split(c).map(String::from).collect(),
+        }
+    }
+}
+
+/// Samples of data, each with a name, such as a group label.
+pub type NamedSamples = Vec<(String, Vec<f64>)>;
+
+/// How to read sample data. Inputs may be read with the same options on
+/// several threads.
+#[derive(Debug, Default)]
+pub struct Options {
+    /// Skip lines that cannot be parsed, rather than failing.
+    pub lax_parsing: bool,
+
+    /// Read a column of delimited data, rather than one value per line.
+    pub column: Option<Column>,
+
+    /// How the columns are delimited.
+    pub delimiter: Delimiter,
+
+    /// The unit to which durations are converted.
+    pub time_unit: TimeUnit,
+
+    /// The units of the values read so far, known from their suffixes:
+    /// `UNKNOWN` before any are read, or else as encoded by `units_code`.
+    units: AtomicU8,
+}
+
+const UNKNOWN: u8 = 0;
+
+/// Encode the unit of a value, or its lack of one, for `Options::units`.
+fn units_code(unit: Option<Unit>) -> u8 {
+    match unit {
+        None => 1,
+        Some(Unit::Seconds) => 2,
+        Some(Unit::Bytes) => 3,
+    }
+}
+
+fn units_decode(code: u8) -> Option<Unit> {
+    match code {
+        2 => Some(Unit::Seconds),
+        3 => Some(Unit::Bytes),
+        _ => None,
+    }
+}
+
+impl Options {
+    pub fn new(lax_parsing: bool, column: Option<Column>, delimiter: Delimiter) -> Options {
+        Options { lax_parsing, column, delimiter, ..Options::default() }
+    }
+
+    /// Symbol of the unit of the values read so far, if they had unit
+    /// suffixes.
+    pub fn unit(&self) -> Option<&'static str> {
+        units_decode(self.units.load(Ordering::Relaxed)).map(|u| match u {
+            Unit::Seconds => self.time_unit.symbol(),
+            u => u.symbol(),
+        })
+    }
+
+    /// Parse a value, which may have a unit suffix or be a duration. All
+    /// values with units must measure the same dimension, and if any has a
+    /// unit, all must.
+    fn parse(&self, s: &str) -> Result<f64, String> {
+        let (x, unit) = unit::parse(s).map_err(|_| format!("Value {:?} is not a number", s))?;
+
+        self.normalize(x, unit).map_err(|e| format!("Value {:?} {}", s, e))
+    }
+
+    /// Parse a value as `parse` does, from input line `line`.
+    fn parse_at(&self, s: &str, line: usize) -> Result<f64, Error> {
+        self.parse(s).map_err(|message| Error::Parse { line, message })
+    }
+
+    /// Check that the unit of a value, given in the base unit of its
+    /// dimension, agrees with those of the values read so far, and convert it
+    /// to the time unit if it is a duration.
+    pub fn normalize(&self, x: f64, unit: Option<Unit>) -> Result<f64, String> {
+        let code = units_code(unit);
+        let mut seen = self.units.load(Ordering::Relaxed);
+
+        if seen == UNKNOWN {
+            // Another thread may read the first value at once.
+            seen = match self.units.compare_exchange(UNKNOWN, code, Ordering::Relaxed,
+                                                     Ordering::Relaxed) {
+                Ok(_) => code,
+                Err(seen) => seen,
+            };
+        }
+
+        if seen != code {
+            return Err(match (units_decode(seen), unit) {
+                (Some(u), None) => format!("has no unit, but earlier values are in {}", u.symbol()),
+                (Some(u), _) => format!("is not in {}, as are earlier values", u.symbol()),
+                (None, _) => "has a unit, but earlier values did not".to_string(),
+            });
+        }
+
+        Ok(if unit == Some(Unit::Seconds) { self.time_unit.convert(x) } else { x })
+    }
+}
+
+/// Read sample data as directed by `opts`.
+pub fn read<R>(reader: R, opts: &Options) -> Result<Vec<f64>, Box<dyn error::Error>>
+    where R: BufRead {
+    match opts.column {
+        Some(ref col) => read_column(reader, col, opts),
+        None => read_data(reader, opts),
+    }
+}
+
+/// Read one value per line, skipping blank lines. With `lax_parsing`, lines
+/// that cannot be parsed are skipped instead of being an error.
+pub fn read_data<R>(mut reader: R, opts: &Options) -> Result<Vec<f64>, Box<dyn error::Error>>
+    where R: BufRead {
+    let mut data: Vec<f64> = vec![];
+    let mut line = vec![];
+    let mut n = 0;
+
+    // Reuse one buffer, rather than allocating a `String` per line.
+    while reader.read_until(b'\n', &mut line)? > 0 {
+        n += 1;
+        push_value(&line, n, opts, &mut data)?;
+        line.clear();
+    }
+
+    Ok(data)
+}
+
+/// Parse line `n` of one value onto `data`, unless it is blank.
+pub fn push_value(line: &[u8], n: usize, opts: &Options, data: &mut Vec<f64>)
+              -> Result<(), Error> {
+    let line = line.trim_ascii();
+
+    if line.is_empty() {
+        return Ok(());
+    }
+
+    let parsed = match str::from_utf8(line) {
+        Ok(s) => opts.parse_at(s, n),
+        Err(e) => Err(Error::Parse {
+            line: n,
+            message: format!("Value {:?} is not UTF-8: {}", String::from_utf8_lossy(line), e),
+        }),
+    };
+
+    match parsed {
+        Ok(d) => data.push(d),
+        err => if !opts.lax_parsing { err?; }
+    }
+
+    Ok(())
+}
+
+/// Read one value per line, as by `read_data`, from a file mapped into memory,
+/// parsing each line in place. Returns `None` for inputs that cannot be read
+/// this way: anything but a nonempty, uncompressed regular file.
+#[cfg(feature = "mmap")]
+fn read_mapped(path: &str, opts: &Options) -> Result<Option<Vec<f64>>, Box<dyn error::Error>> {
+    // Report progress in chunks of about this many bytes, split at lines.
+    const CHUNK: usize = 1 << 20;
+
+    let f = open(path)?;
+    let meta = f.metadata()?;
+
+    if !meta.is_file() || meta.len() == 0 {
+        return Ok(None);
+    }
+
+    // Safety: the map is only read, and only while the file is open. As with
+    // any read, another process changing the file meanwhile changes the data.
+    let map = unsafe { memmap2::Mmap::map(&f)? };
+    let mut bytes: &[u8] = &map;
+
+    if bytes.starts_with(GZIP_MAGIC) || bytes.starts_with(ZSTD_MAGIC) {
+        return Ok(None);
+    }
+
+    let mut progress = Progress::new(io::empty(), path, Some(meta.len()));
+    let mut data: Vec<f64> = vec![];
+    let mut n = 0;
+
+    while !bytes.is_empty() {
+        let end = match bytes[CHUNK.min(bytes.len())..].iter().position(|&b| b == b'\n') {
+            Some(i) => CHUNK + i + 1,
+            None => bytes.len(),
+        };
+        let (chunk, rest) = bytes.split_at(end);
+
+        // A chunk ends with a line break, and so with an empty "line" that is
+        // not counted, unless it is the end of the file.
+        for line in chunk.strip_suffix(b"\n").unwrap_or(chunk).split(|&b| b == b'\n') {
+            n += 1;
+            push_value(line, n, opts, &mut data)?;
+        }
+
+        progress.advance(chunk.len());
+        bytes = rest;
+    }
+    progress.advance(0);
+
+    Ok(Some(data))
+}
+
+/// Read an input split into samples by lines of the form `# name: NAME`, each
+/// read as by `read`. Values before the first such line are a sample named
+/// `default`.
+pub fn read_named<R>(reader: R, default: &str, opts: &Options)
+                     -> Result<NamedSamples, Box<dyn error::Error>>
+    where R: BufRead {
+    // The name, text, and number of the line before the text of each section.
+    let mut sections = vec![(default.to_string(), String::new(), 0)];
+
+    for (i, l) in reader.lines().enumerate() {
+        let l = l?;
+
+        match marker(&l) {
+            Some(name) => sections.push((name.to_string(), String::new(), i + 1)),
+            None => {
+                let text = &mut sections.last_mut().unwrap_or_else(|| unreachable!()).1;
+                text.push_str(&l);
+                text.push('\n');
+            },
+        }
+    }
+
+    // Only a blank preamble to the first marker.
+    if sections.len() > 1 && sections[0].1.trim().is_empty() {
+        sections.remove(0);
+    }
+
+    sections
+        .into_iter()
+        .map(|(name, text, offset)| {
+            let data = read(text.as_bytes(), opts).map_err(|e| match e.downcast::<Error>() {
+                Ok(e) => match *e {
+                    Error::Parse { line, message } => Error::Parse { line: offset + line, message },
+                    e => e,
+                }.into(),
+                Err(e) => e,
+            })?;
+
+            Ok((name, data))
+        })
+        .collect()
+}
+
+/// The name of a `# name: NAME` line.
+fn marker(line: &str) -> Option<&str> {
+    line.trim()
+        .strip_prefix('#')?
+        .trim_start()
+        .strip_prefix("name:")
+        .map(str::trim)
+}
+
+/// Read one whitespace-separated pair of values `x y` per line, skipping blank
+/// lines. With `lax_parsing`, lines that cannot be parsed are skipped.
+pub fn read_pairs<R>(reader: R, lax_parsing: bool) -> Result<Vec<(f64, f64)>, Box<dyn error::Error>>
+    where R: BufRead {
+    let mut data: Vec<(f64, f64)> = vec![];
+
+    for (i, l) in reader.lines().enumerate() {
+        let l = l?;
+        let fields: Vec<&str> = l.split_whitespace().collect();
+        let parse = |f: &str| f.parse::<f64>().map_err(|_| Error::Parse {
+            line: i + 1,
+            message: format!("Value {:?} is not a number", f),
+        });
+
+        if fields.is_empty() {
+            continue;
+        }
+
+        let pair = if fields.len() == 2 {
+            parse(fields[0]).and_then(|x| Ok((x, parse(fields[1])?)))
+        } else if lax_parsing {
+            continue;
+        } else {
+            return Err(Error::Parse {
+                line: i + 1,
+                message: format!("Expected two values, found {:?}", l),
+            }.into());
+        };
+
+        match pair {
+            Ok(p) => data.push(p),
+            err => if !lax_parsing { err?; }
+        }
+    }
+
+    Ok(data)
+}
+
+/// Read one column of delimited data, ignoring the others. If the column is
+/// selected by name, the first line must be a header row. If it is selected by
+/// index, a first line whose selected field is not a number is taken to be a
+/// header, and skipped. Quoted CSV fields may contain commas and escaped
+/// quotes, but not line breaks.
+pub fn read_column<R>(reader: R, col: &Column, opts: &Options)
+                      -> Result<Vec<f64>, Box<dyn error::Error>>
+    where R: BufRead {
+    let mut data = read_columns(reader, slice::from_ref(col), opts)?;
+
+    Ok(data.remove(0))
+}
+
+/// Read several columns of delimited data as separate samples, as with
+/// `read_column`. With `lax_parsing`, a line missing one column still
+/// contributes to the others.
+pub fn read_columns<R>(reader: R, cols: &[Column], opts: &Options)
+                       -> Result<Vec<Vec<f64>>, Box<dyn error::Error>>
+    where R: BufRead {
+    let mut data: Vec<Vec<f64>> = vec![vec![]; cols.len()];
+    let numeric = vec![true; cols.len()];
+    let rows = select_fields(reader, cols, &numeric, opts)?;
+
+    for (n, row) in rows {
+        for (field, d) in row.iter().zip(&mut data) {
+            match field.as_ref().map(|f| opts.parse_at(f, n)) {
+                Some(Ok(x)) => d.push(x),
+                Some(err) if !opts.lax_parsing => { err?; },
+                _ => {},
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+/// Read two columns of delimited data as pairs of values `(a, b)`, as with
+/// `read_column`. With `lax_parsing`, a line on which either value is missing
+/// or cannot be parsed is skipped whole, so that the pairs stay aligned.
+pub fn read_paired_columns<R>(reader: R, a: &Column, b: &Column, opts: &Options)
+                              -> Result<Vec<(f64, f64)>, Box<dyn error::Error>>
+    where R: BufRead {
+    let mut data: Vec<(f64, f64)> = vec![];
+    let cols = [a.clone(), b.clone()];
+    let rows = select_fields(reader, &cols, &[true, true], opts)?;
+
+    for (n, row) in rows {
+        let pair = match (&row[0], &row[1]) {
+            (Some(a), Some(b)) => opts.parse_at(a, n).and_then(|a| Ok((a, opts.parse_at(b, n)?))),
+            _ => continue,
+        };
+
+        match pair {
+            Ok(p) => data.push(p),
+            err => if !opts.lax_parsing { err?; }
+        }
+    }
+
+    Ok(data)
+}
+
+/// Read two columns of delimited data as pairs `(t, x)` of a timestamp, in
+/// seconds since the Unix epoch as parsed by `timestamp::parse`, and a value,
+/// as with `read_paired_columns`.
+pub fn read_timestamped<R>(reader: R, time: &Column, value: &Column, opts: &Options)
+                           -> Result<Vec<(f64, f64)>, Box<dyn error::Error>>
+    where R: BufRead {
+    let mut data: Vec<(f64, f64)> = vec![];
+    let cols = [time.clone(), value.clone()];
+    let rows = select_fields(reader, &cols, &[false, true], opts)?;
+
+    for (n, row) in rows {
+        let pair = match (&row[0], &row[1]) {
+            (Some(t), Some(x)) => timestamp::parse(t)
+                .map_err(|message| Error::Parse { line: n, message })
+                .and_then(|t| Ok((t, opts.parse_at(x, n)?))),
+            _ => continue,
+        };
+
+        match pair {
+            Ok(p) => data.push(p),
+            err => if !opts.lax_parsing { err?; }
+        }
+    }
+
+    Ok(data)
+}
+
+/// Read two columns of delimited data as pairs of labels `(a, b)`, such as
+/// the categories two raters gave each subject, as with `read_column`. Since
+/// labels need not be numbers, a header row is skipped only if the columns
+/// are selected by name. With `lax_parsing`, a line missing either label is
+/// skipped.
+pub fn read_label_pairs<R>(reader: R, a: &Column, b: &Column, opts: &Options)
+                           -> Result<Vec<(String, String)>, Box<dyn error::Error>>
+    where R: BufRead {
+    let cols = [a.clone(), b.clone()];
+    let rows = select_fields(reader, &cols, &[false, false], opts)?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(_, mut row)| match (row[0].take(), row[1].take()) {
+            (Some(a), Some(b)) => Some((a, b)),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Read a matrix of counts, one delimited row per line, skipping blank lines.
+/// Every row must have as many values as the first. With `lax_parsing`, lines
+/// that cannot be parsed are skipped.
+pub fn read_matrix<R>(reader: R, opts: &Options) -> Result<Vec<Vec<f64>>, Box<dyn error::Error>>
+    where R: BufRead {
+    let mut rows: Vec<Vec<f64>> = vec![];
+
+    for (i, l) in reader.lines().enumerate() {
+        let l = l?;
+
+        if l.trim().is_empty() {
+            continue;
+        }
+
+        let row = opts.delimiter
+            .split(&l)
+            .iter()
+            .map(|f| f.trim().parse::<f64>().map_err(|_| Error::Parse {
+                line: i + 1,
+                message: format!("Value {:?} is not a number", f.trim()),
+            }))
+            .collect::<Result<Vec<f64>, _>>()
+            .and_then(|row| match rows.first() {
+                Some(first) if first.len() != row.len() => Err(Error::Parse {
+                    line: i + 1,
+                    message: format!("Expected {} values, found {:?}", first.len(), l),
+                }),
+                _ => Ok(row),
+            });
+
+        match row {
+            Ok(row) => rows.push(row),
+            err => if !opts.lax_parsing { err?; }
+        }
+    }
+
+    Ok(rows)
+}
+
+/// Read long-format delimited data, in which each line has a group label and
+/// a value, as one sample per group. Groups are in order of first appearance.
+pub fn read_groups<R>(reader: R, group: &Column, value: &Column, opts: &Options)
+                      -> Result<NamedSamples, Box<dyn error::Error>>
+    where R: BufRead {
+    let mut groups: NamedSamples = vec![];
+    let cols = [group.clone(), value.clone()];
+    let rows = select_fields(reader, &cols, &[false, true], opts)?;
+
+    for (n, row) in rows {
+        let (label, x) = match (&row[0], row[1].as_ref().map(|v| opts.parse_at(v, n))) {
+            (Some(label), Some(Ok(x))) => (label, x),
+            (_, Some(err @ Err(_))) if !opts.lax_parsing => { err?; continue },
+            _ => continue,
+        };
+
+        match groups.iter_mut().find(|g| g.0 == *label) {
+            Some(g) => g.1.push(x),
+            None => groups.push((label.clone(), vec![x])),
+        }
+    }
+
+    Ok(groups)
+}
+
+/// The number of a line of delimited data, and its selected fields.
+type Row = (usize, Vec<Option<String>>);
+
+/// The trimmed fields `cols` of each nonblank line of delimited data, with the
+/// number of the line. A field is `None` if its line is too short and
+/// `lax_parsing` is set. If any column
+/// is selected by name, the first line must be a header row. Otherwise, a
+/// first line with a field which should be `numeric` but is not a number is
+/// taken to be a header. Either way, the header is skipped.
+fn select_fields<R>(reader: R, cols: &[Column], numeric: &[bool], opts: &Options)
+                    -> Result<Vec<Row>, Box<dyn error::Error>>
+    where R: BufRead {
+    let mut rows = vec![];
+    let mut lines = reader.lines().enumerate();
+
+    let by_name = cols.iter().any(|c| matches!(*c, Column::Name(_)));
+    let header = if by_name {
+        match lines.next() {
+            Some((_, l)) => opts.delimiter.split(&l?),
+            None => return Ok(rows),
+        }
+    } else {
+        vec![]
+    };
+
+    let indices = cols
+        .iter()
+        .map(|c| match *c {
+            Column::Name(ref name) => header
+                .iter()
+                .position(|h| h.trim() == name)
+                .ok_or_else(|| format!("No column named {:?} in header", name)),
+            Column::Index(i) => Ok(i - 1),
+        })
+        .collect::<Result<Vec<usize>, String>>()?;
+
+    for (i, l) in lines {
+        let l = l?;
+
+        if l.trim().is_empty() {
+            continue;
+        }
+
+        let fields = opts.delimiter.split(&l);
+        let row = indices
+            .iter()
+            .map(|&c| match fields.get(c) {
+                Some(f) => Ok(Some(f.trim().to_string())),
+                None if opts.lax_parsing => Ok(None),
+                None => Err(Error::Parse {
+                    line: i + 1,
+                    message: format!("No column {} in {:?}", c + 1, l),
+                }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        rows.push((i + 1, row));
+    }
+
+    let header = !by_name && rows.first().is_some_and(|(_, r)| {
+        r.iter().zip(numeric).any(|(f, &n)| match *f {
+            Some(ref f) if n => unit::parse(f).is_err(),
+            _ => false,
+        })
+    });
+    if header {
+        rows.remove(0);
+    }
+
+    Ok(rows)
+}
+
+/// Split a line of CSV into fields, as in RFC 4180.
+fn split_csv(line: &str) -> Vec<String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.trim_end_matches('\r').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            },
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    fields
+}
+
+fn open(path: &str) -> Result<File, Box<dyn error::Error>> {
+    File::open(path).map_err(|e| format!("Could not open file {:?}: {}", path, e).into())
+}
+
+/// Open a file for reading, decompressing it if it is gzip or zstd data.
+pub fn open_file(path: &str) -> Result<Box<dyn BufRead>, Box<dyn error::Error>> {
+    let f = open(path)?;
+    let size = f.metadata().ok().filter(|m| m.is_file()).map(|m| m.len());
+
+    decompress(BufReader::new(Progress::new(f, path, size)))
+}
+
+/// Open stdin for reading, decompressing it if it is gzip or zstd data.
+pub fn open_stdin() -> Result<Box<dyn BufRead>, Box<dyn error::Error>> {
+    decompress(BufReader::new(Progress::new(io::stdin().lock(), "stdin", None)))
+}
+
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+/// Wrap `reader` to decompress it if it begins like gzip or zstd data. Text
+/// never does.
+fn decompress<R>(mut reader: R) -> Result<Box<dyn BufRead>, Box<dyn error::Error>>
+    where R: BufRead + 'static {
+    let head = reader.fill_buf()?;
+
+    if head.starts_with(GZIP_MAGIC) {
+        return gzip(reader);
+    }
+
+    if head.starts_with(ZSTD_MAGIC) {
+        return zstd(reader);
+    }
+
+    Ok(Box::new(reader))
+}
+
+#[cfg(feature = "gzip")]
+fn gzip<R>(reader: R) -> Result<Box<dyn BufRead>, Box<dyn error::Error>>
+    where R: BufRead + 'static {
+    // Concatenated gzip members, as from `cat a.gz b.gz`, are one stream.
+    Ok(Box::new(BufReader::new(flate2::bufread::MultiGzDecoder::new(reader))))
+}
+
+#[cfg(not(feature = "gzip"))]
+fn gzip<R>(_reader: R) -> Result<Box<dyn BufRead>, Box<dyn error::Error>> {
+    Err("Input is gzip-compressed, but gzip support was not enabled at build time".into())
+}
+
+#[cfg(feature = "zstd")]
+fn zstd<R>(reader: R) -> Result<Box<dyn BufRead>, Box<dyn error::Error>>
+    where R: BufRead + 'static {
+    Ok(Box::new(BufReader::new(zstd::Decoder::with_buffer(reader)?)))
+}
+
+#[cfg(not(feature = "zstd"))]
+fn zstd<R>(_reader: R) -> Result<Box<dyn BufRead>, Box<dyn error::Error>> {
+    Err("Input is zstd-compressed, but zstd support was not enabled at build time".into())
+}
+
+pub fn read_file(path: &str, opts: &Options) -> Result<Vec<f64>, Box<dyn error::Error>> {
+    #[cfg(feature = "mmap")]
+    if opts.column.is_none() {
+        if let Some(data) = read_mapped(path, opts).map_err(|e| locate(path, e))? {
+            return Ok(data);
+        }
+    }
+
+    read(open_file(path)?, opts).map_err(|e| locate(path, e))
+}
+
+pub fn read_stdin(opts: &Options) -> Result<Vec<f64>, Box<dyn error::Error>> {
+    read(open_stdin()?, opts).map_err(|e| locate("stdin", e))
+}
+
+/// Name the input `source` in an error reading it. Errors parsing a line are
+/// given as `source:line: message`, as compilers give them.
+pub fn locate(source: &str, e: Box<dyn error::Error>) -> Box<dyn error::Error> {
+    match e.downcast::<Error>() {
+        Ok(e) => match *e {
+            Error::Parse { line, message } => format!("{}:{}: {}", source, line, message).into(),
+            e => e.into(),
+        },
+        Err(e) => e,
+    }
+}
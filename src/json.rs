@@ -0,0 +1,727 @@
+use dent::advice::Advice;
+use dent::anova::Anova;
+use dent::change::{Change, GeometricMeanRatio};
+use dent::changepoint::Changepoints;
+use dent::fit::Fit;
+use dent::frequency::Frequency;
+use dent::histogram::Histogram;
+use dent::kappa::{Kappa, Weights};
+use dent::lr::LinearRegression;
+use dent::mann_whitney::{HodgesLehmann, MannWhitney};
+use dent::permutation::Permutation;
+use dent::roc::Roc;
+use dent::summary::{Summarizer, Summary};
+use dent::survival::{KaplanMeier, LogRank};
+use dent::t_test::{Alternative, TTest};
+
+use field::Field;
+use slo::Target;
+
+
+/// A JSON value, to describe our results or read those of other tools.
+#[derive(Clone)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Render as pretty-printed JSON, with a trailing newline.
+    pub fn render(&self) -> String {
+        let mut s = String::new();
+        self.write(&mut s, 0);
+        s.push('\n');
+
+        s
+    }
+
+    /// The field `key` of an object.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match *self {
+            Value::Object(ref fields) => fields.iter().find(|f| f.0 == key).map(|f| &f.1),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Value::Number(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match *self {
+            Value::String(ref v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match *self {
+            Value::Array(ref items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// The numbers of an array, if it is one of only numbers.
+    pub fn as_f64_array(&self) -> Option<Vec<f64>> {
+        self.as_array()?.iter().map(Value::as_f64).collect()
+    }
+
+    fn write(&self, s: &mut String, depth: usize) {
+        match *self {
+            Value::Null => s.push_str("null"),
+            Value::Bool(b) => s.push_str(if b { "true" } else { "false" }),
+            Value::Number(x) => s.push_str(&number(x)),
+            Value::String(ref v) => s.push_str(&string(v)),
+            Value::Array(ref items) => {
+                if items.is_empty() {
+                    return s.push_str("[]");
+                }
+                s.push('[');
+                for (i, v) in items.iter().enumerate() {
+                    if i > 0 {
+                        s.push(',');
+                    }
+                    newline(s, depth + 1);
+                    v.write(s, depth + 1);
+                }
+                newline(s, depth);
+                s.push(']');
+            },
+            Value::Object(ref fields) => {
+                if fields.is_empty() {
+                    return s.push_str("{}");
+                }
+                s.push('{');
+                for (i, (k, v)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        s.push(',');
+                    }
+                    newline(s, depth + 1);
+                    s.push_str(&string(k));
+                    s.push_str(": ");
+                    v.write(s, depth + 1);
+                }
+                newline(s, depth);
+                s.push('}');
+            },
+        }
+    }
+}
+
+/// Parse a JSON document, such as the results of another tool.
+pub fn parse(text: &str) -> Result<Value, String> {
+    let mut p = Parser { text: text.as_bytes(), pos: 0 };
+
+    let v = p.value()?;
+    p.skip_whitespace();
+
+    if p.pos < p.text.len() {
+        return Err(p.error("trailing characters"));
+    }
+
+    Ok(v)
+}
+
+/// A recursive-descent parser of JSON text.
+struct Parser<'a> {
+    text: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, what: &str) -> String {
+        format!("Invalid JSON at byte {}: {}", self.pos, what)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.get(self.pos).cloned()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(b' ') | Some(b'\t') | Some(b'\n') | Some(b'\r') = self.peek() {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Result<(), String> {
+        self.skip_whitespace();
+
+        if self.peek() != Some(b) {
+            return Err(self.error(&format!("expected `{}`", b as char)));
+        }
+        self.pos += 1;
+
+        Ok(())
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => Ok(Value::String(self.string()?)),
+            Some(b't') => self.literal("true", Value::Bool(true)),
+            Some(b'f') => self.literal("false", Value::Bool(false)),
+            Some(b'n') => self.literal("null", Value::Null),
+            Some(_) => self.number(),
+            None => Err(self.error("unexpected end of input")),
+        }
+    }
+
+    fn literal(&mut self, word: &str, v: Value) -> Result<Value, String> {
+        if !self.text[self.pos..].starts_with(word.as_bytes()) {
+            return Err(self.error("unexpected character"));
+        }
+        self.pos += word.len();
+
+        Ok(v)
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let start = self.pos;
+
+        while let Some(b'0'..=b'9') | Some(b'-') | Some(b'+') | Some(b'.') | Some(b'e')
+            | Some(b'E') = self.peek() {
+            self.pos += 1;
+        }
+
+        // Only ASCII was consumed, so the slice is valid UTF-8.
+        String::from_utf8_lossy(&self.text[start..self.pos])
+            .parse()
+            .map(Value::Number)
+            .map_err(|_| self.error("invalid number"))
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect(b'"')?;
+        let mut bytes = vec![];
+
+        loop {
+            match self.peek() {
+                Some(b'"') => break,
+                Some(b'\\') => {
+                    self.pos += 1;
+                    let c = match self.peek() {
+                        Some(b'"') => '"',
+                        Some(b'\\') => '\\',
+                        Some(b'/') => '/',
+                        Some(b'b') => '\u{8}',
+                        Some(b'f') => '\u{c}',
+                        Some(b'n') => '\n',
+                        Some(b'r') => '\r',
+                        Some(b't') => '\t',
+                        Some(b'u') => self.unicode_escape()?,
+                        _ => return Err(self.error("invalid escape")),
+                    };
+                    self.pos += 1;
+
+                    let mut buf = [0; 4];
+                    bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                },
+                Some(b) => {
+                    bytes.push(b);
+                    self.pos += 1;
+                },
+                None => return Err(self.error("unterminated string")),
+            }
+        }
+        self.pos += 1;
+
+        String::from_utf8(bytes).map_err(|_| self.error("invalid UTF-8"))
+    }
+
+    /// Decode the `\uXXXX` escape at `pos`, which is left at its last digit,
+    /// combining surrogate pairs.
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+
+        if !(0xd800..0xdc00).contains(&high) {
+            return char::from_u32(high).ok_or_else(|| self.error("invalid escape"));
+        }
+
+        if !self.text[self.pos + 1..].starts_with(b"\\u") {
+            return Err(self.error("unpaired surrogate"));
+        }
+        self.pos += 2;
+        let low = self.hex4()?;
+
+        char::from_u32(0x10000 + ((high - 0xd800) << 10) + (low.wrapping_sub(0xdc00) & 0x3ff))
+            .ok_or_else(|| self.error("invalid escape"))
+    }
+
+    /// Read the four hex digits following the `u` at `pos`.
+    fn hex4(&mut self) -> Result<u32, String> {
+        let digits = self.text
+            .get(self.pos + 1..self.pos + 5)
+            .and_then(|d| ::std::str::from_utf8(d).ok())
+            .and_then(|d| u32::from_str_radix(d, 16).ok())
+            .ok_or_else(|| self.error("invalid escape"))?;
+        self.pos += 4;
+
+        Ok(digits)
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        self.expect(b'[')?;
+        let mut items = vec![];
+
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.pos += 1;
+            return Ok(Value::Array(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']') => break,
+                _ => return Err(self.error("expected `,` or `]`")),
+            }
+        }
+        self.pos += 1;
+
+        Ok(Value::Array(items))
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        self.expect(b'{')?;
+        let mut fields = vec![];
+
+        self.skip_whitespace();
+        if self.peek() == Some(b'}') {
+            self.pos += 1;
+            return Ok(Value::Object(fields));
+        }
+
+        loop {
+            self.skip_whitespace();
+            let k = self.string()?;
+            self.expect(b':')?;
+            fields.push((k, self.value()?));
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b'}') => break,
+                _ => return Err(self.error("expected `,` or `}`")),
+            }
+        }
+        self.pos += 1;
+
+        Ok(Value::Object(fields))
+    }
+}
+
+/// An object with `fields` in order.
+pub fn object(fields: Vec<(&str, Value)>) -> Value {
+    Value::Object(fields.into_iter().map(|(k, v)| (k.to_string(), v)).collect())
+}
+
+pub fn summary(source: &str, s: &Summarizer, fields: &[Field]) -> Value {
+    let mut items = vec![("source".to_string(), Value::String(source.to_string()))];
+    items.extend(fields.iter().map(|f| (f.key(), Value::Number(f.value(s)))));
+
+    Value::Object(items)
+}
+
+pub fn summaries(sources: &[String], samples: &[Summarizer], fields: &[Field]) -> Value {
+    let items = sources
+        .iter()
+        .zip(samples)
+        .map(|(src, s)| summary(src, s, fields))
+        .collect();
+
+    Value::Array(items)
+}
+
+/// A t-test of two samples, with its alternative hypothesis if one-sided.
+pub fn t_test(t_test: &TTest, s1: &Summary, s2: &Summary, alternative: Alternative) -> Value {
+    let mut fields = vec![
+        ("mean_difference", Value::Number(s2.mean() - s1.mean())),
+        ("t", Value::Number(t_test.t)),
+        ("df", Value::Number(t_test.df)),
+        ("p", Value::Number(t_test.p)),
+    ];
+    if alternative != Alternative::TwoSided {
+        fields.push(("alternative", Value::String(alternative.name().to_string())));
+    }
+
+    object(fields)
+}
+
+/// A change between samples, with the confidence level of its intervals.
+pub fn change(c: &Change) -> Value {
+    object(vec![
+        ("absolute", Value::Number(c.absolute)),
+        ("absolute_margin", Value::Number(c.absolute_margin)),
+        ("relative", Value::Number(c.relative)),
+        ("relative_margin", Value::Number(c.relative_margin)),
+        ("confidence", Value::Number(c.confidence)),
+    ])
+}
+
+pub fn geometric_mean_ratio(g: &GeometricMeanRatio) -> Value {
+    object(vec![
+        ("ratio", Value::Number(g.ratio)),
+        ("lower", Value::Number(g.lower)),
+        ("upper", Value::Number(g.upper)),
+        ("confidence", Value::Number(g.confidence)),
+    ])
+}
+
+/// A matrix of the effect size by `measure` of each sample of `sources`
+/// relative to each other, as in `long::effect_sizes`.
+pub fn effect_sizes(measure: &str, sources: &[String], matrix: &[Vec<f64>]) -> Value {
+    let sources = sources.iter().map(|s| Value::String(s.clone())).collect();
+    let rows = matrix
+        .iter()
+        .map(|r| Value::Array(r.iter().map(|&x| Value::Number(x)).collect()))
+        .collect();
+
+    object(vec![
+        ("measure", Value::String(measure.to_string())),
+        ("sources", Value::Array(sources)),
+        ("matrix", Value::Array(rows)),
+    ])
+}
+
+/// The fits to the sample of `source`, best first, with the name of the best.
+/// The differences of pairs, with the confidence interval of their mean at
+/// `level`, the counts of positive, negative, and zero differences, and their
+/// histogram.
+pub fn differences(source: &str, s: &Summarizer, fields: &[Field], level: f64,
+                   ci: (f64, f64), signs: [usize; 3], hist: &Histogram) -> Value {
+    let numbers = |xs: &[f64]| Value::Array(xs.iter().map(|&x| Value::Number(x)).collect());
+    let counts: Vec<f64> = hist.counts().iter().map(|&n| n as f64).collect();
+
+    object(vec![
+        ("summary", summary(source, s, fields)),
+        ("mean_ci", object(vec![
+            ("lower", Value::Number(ci.0)),
+            ("upper", Value::Number(ci.1)),
+            ("confidence", Value::Number(level)),
+        ])),
+        ("signs", object(vec![
+            ("positive", Value::Number(signs[0] as f64)),
+            ("negative", Value::Number(signs[1] as f64)),
+            ("zero", Value::Number(signs[2] as f64)),
+        ])),
+        ("histogram", object(vec![
+            ("edges", numbers(hist.edges())),
+            ("counts", numbers(&counts)),
+        ])),
+    ])
+}
+
+pub fn fits(source: &str, fits: &[Fit]) -> Value {
+    let items = fits
+        .iter()
+        .map(|f| {
+            let names = f.family.parameter_names().iter().map(|n| n.to_string());
+            let parameters = names.zip(f.parameters.iter().map(|&p| Value::Number(p))).collect();
+
+            object(vec![
+                ("family", Value::String(f.family.name().to_string())),
+                ("parameters", Value::Object(parameters)),
+                ("log_likelihood", Value::Number(f.log_likelihood)),
+                ("aic", Value::Number(f.aic)),
+                ("ks", Value::Number(f.ks)),
+            ])
+        })
+        .collect();
+
+    object(vec![
+        ("source", Value::String(source.to_string())),
+        ("best", Value::String(fits[0].family.name().to_string())),
+        ("fits", Value::Array(items)),
+    ])
+}
+
+/// The count and proportions of each distinct value.
+pub fn frequencies(frequencies: &[Frequency]) -> Value {
+    let items = frequencies
+        .iter()
+        .map(|f| {
+            object(vec![
+                ("value", Value::Number(f.value)),
+                ("count", Value::Number(f.count as f64)),
+                ("proportion", Value::Number(f.proportion)),
+                ("cumulative", Value::Number(f.cumulative)),
+            ])
+        })
+        .collect();
+
+    Value::Array(items)
+}
+
+/// The checks of `advice` and the test it chose, with the reason, given the
+/// names of the samples.
+pub fn advice(advice: &Advice, names: [&str; 2]) -> Value {
+    let normality = advice
+        .normality
+        .iter()
+        .map(|sw| match *sw {
+            Some(ref sw) => object(vec![("w", Value::Number(sw.w)), ("p", Value::Number(sw.p))]),
+            None => Value::Null,
+        })
+        .collect();
+    let variances = match advice.variances {
+        Some(ref v) => object(vec![("f", Value::Number(v.f)), ("p", Value::Number(v.p))]),
+        None => Value::Null,
+    };
+
+    object(vec![
+        ("test", Value::String(advice.test.name().to_string())),
+        ("reason", Value::String(advice.explain(names))),
+        ("sizes", Value::Array(advice.sizes.iter().map(|&n| Value::Number(n as f64)).collect())),
+        ("normality", Value::Array(normality)),
+        ("variances", variances),
+    ])
+}
+
+pub fn mann_whitney(mw: &MannWhitney) -> Value {
+    object(vec![
+        ("u", Value::Number(mw.u)),
+        ("p", Value::Number(mw.p)),
+        ("exact", Value::Bool(mw.exact)),
+    ])
+}
+
+pub fn hodges_lehmann(hl: &HodgesLehmann) -> Value {
+    object(vec![
+        ("shift", Value::Number(hl.shift)),
+        ("lower", Value::Number(hl.lower)),
+        ("upper", Value::Number(hl.upper)),
+        ("confidence", Value::Number(hl.confidence)),
+        ("exact", Value::Bool(hl.exact)),
+    ])
+}
+
+pub fn permutation(perm: &Permutation) -> Value {
+    object(vec![
+        ("difference", Value::Number(perm.difference)),
+        ("p", Value::Number(perm.p)),
+        ("resamples", Value::Number(perm.resamples as f64)),
+    ])
+}
+
+pub fn anova(anova: &Anova) -> Value {
+    object(vec![
+        ("f", Value::Number(anova.f)),
+        ("df_between", Value::Number(anova.df_between)),
+        ("df_within", Value::Number(anova.df_within)),
+        ("p", Value::Number(anova.p)),
+    ])
+}
+
+pub fn kappa(kappa: &Kappa, weights: Weights, categories: &[String]) -> Value {
+    object(vec![
+        ("kappa", Value::Number(kappa.kappa)),
+        ("standard_error", Value::Number(kappa.standard_error)),
+        ("z", Value::Number(kappa.z)),
+        ("p", Value::Number(kappa.p)),
+        ("observed", Value::Number(kappa.observed)),
+        ("expected", Value::Number(kappa.expected)),
+        ("size", Value::Number(kappa.size)),
+        ("weights", Value::String(weights.name().to_string())),
+        ("categories", Value::Array(categories.iter().map(|c| Value::String(c.clone())).collect())),
+    ])
+}
+
+/// The area under an ROC curve, with its confidence interval, and the curve.
+pub fn roc(roc: &Roc) -> Value {
+    let points = roc.points
+        .iter()
+        .map(|p| {
+            object(vec![
+                ("threshold", Value::Number(p.threshold)),
+                ("false_positive_rate", Value::Number(p.false_positive_rate)),
+                ("true_positive_rate", Value::Number(p.true_positive_rate)),
+            ])
+        })
+        .collect();
+
+    object(vec![
+        ("auc", Value::Number(roc.auc)),
+        ("standard_error", Value::Number(roc.standard_error)),
+        ("confidence", Value::Number(roc.confidence)),
+        ("lower", Value::Number(roc.lower)),
+        ("upper", Value::Number(roc.upper)),
+        ("positives", Value::Number(roc.positives as f64)),
+        ("negatives", Value::Number(roc.negatives as f64)),
+        ("points", Value::Array(points)),
+    ])
+}
+
+/// Percentiles of a sample of size `n`, each a fraction with its estimate and
+/// the bounds of its confidence interval at `level`, and whether each target
+/// is met, and with confidence.
+pub fn slo(n: f64, level: f64, estimates: &[(f64, f64, Option<f64>, Option<f64>)],
+           targets: &[Target], results: &[(bool, bool)]) -> Value {
+    let option = |x: Option<f64>| x.map_or(Value::Null, Value::Number);
+    let percentiles = estimates
+        .iter()
+        .map(|&(p, x, lower, upper)| {
+            object(vec![
+                ("percentile", Value::Number(p * 100.0)),
+                ("value", Value::Number(x)),
+                ("lower", option(lower)),
+                ("upper", option(upper)),
+            ])
+        })
+        .collect();
+    let targets = targets
+        .iter()
+        .zip(results)
+        .map(|(t, &(met, confident))| {
+            object(vec![
+                ("target", Value::String(t.spec.clone())),
+                ("percentile", Value::Number(t.percentile * 100.0)),
+                ("bound", Value::Number(t.bound)),
+                ("met", Value::Bool(met)),
+                ("confident", Value::Bool(confident)),
+            ])
+        })
+        .collect();
+
+    object(vec![
+        ("size", Value::Number(n)),
+        ("confidence", Value::Number(level)),
+        ("percentiles", Value::Array(percentiles)),
+        ("targets", Value::Array(targets)),
+        ("met", Value::Bool(results.iter().all(|&(met, _)| met))),
+    ])
+}
+
+/// The segments of a series between changes of its level, with the indices
+/// of the changes, each that of the first value after it, from 1.
+pub fn changepoints(changes: &Changepoints) -> Value {
+    let indices = changes
+        .indices()
+        .iter()
+        .map(|&i| Value::Number((i + 1) as f64))
+        .collect();
+    let segments = changes.segments
+        .iter()
+        .map(|s| {
+            object(vec![
+                ("start", Value::Number((s.start + 1) as f64)),
+                ("end", Value::Number(s.end as f64)),
+                ("size", Value::Number(s.size() as f64)),
+                ("mean", Value::Number(s.mean)),
+            ])
+        })
+        .collect();
+
+    object(vec![
+        ("changes", Value::Array(indices)),
+        ("segments", Value::Array(segments)),
+        ("noise", Value::Number(changes.noise)),
+        ("penalty", Value::Number(changes.penalty)),
+    ])
+}
+
+/// The Kaplan-Meier estimate and median survival of each named sample.
+pub fn survival(sources: &[String], estimates: &[KaplanMeier]) -> Value {
+    let option = |x: Option<f64>| x.map_or(Value::Null, Value::Number);
+    let items = sources
+        .iter()
+        .zip(estimates)
+        .map(|(source, km)| {
+            let steps = km.steps
+                .iter()
+                .map(|s| {
+                    object(vec![
+                        ("time", Value::Number(s.time)),
+                        ("at_risk", Value::Number(s.at_risk as f64)),
+                        ("events", Value::Number(s.events as f64)),
+                        ("censored", Value::Number(s.censored as f64)),
+                        ("survival", Value::Number(s.survival)),
+                        ("lower", Value::Number(s.lower)),
+                        ("upper", Value::Number(s.upper)),
+                    ])
+                })
+                .collect();
+
+            object(vec![
+                ("source", Value::String(source.clone())),
+                ("size", Value::Number(km.size as f64)),
+                ("events", Value::Number(km.events as f64)),
+                ("median", option(km.median)),
+                ("median_lower", option(km.median_lower)),
+                ("median_upper", option(km.median_upper)),
+                ("confidence", Value::Number(km.confidence)),
+                ("steps", Value::Array(steps)),
+            ])
+        })
+        .collect();
+
+    Value::Array(items)
+}
+
+pub fn log_rank(test: &LogRank) -> Value {
+    let pair = |x: [f64; 2]| Value::Array(vec![Value::Number(x[0]), Value::Number(x[1])]);
+
+    object(vec![
+        ("chi_squared", Value::Number(test.chi_squared)),
+        ("p", Value::Number(test.p)),
+        ("observed", pair(test.observed)),
+        ("expected", pair(test.expected)),
+    ])
+}
+
+pub fn linear_regression(lr: &LinearRegression) -> Value {
+    object(vec![
+        ("slope", Value::Number(lr.slope())),
+        ("intercept", Value::Number(lr.intercept())),
+        ("r", Value::Number(lr.r())),
+        ("standard_error", Value::Number(lr.standard_error())),
+    ])
+}
+
+fn newline(s: &mut String, depth: usize) {
+    s.push('\n');
+    s.push_str(&"  ".repeat(depth));
+}
+
+/// JSON has no representation of non-finite numbers, so they become `null`.
+/// The `Debug` format of a finite float is a valid JSON number which
+/// round-trips, using exponential notation for very large and small values.
+fn number(x: f64) -> String {
+    if x.is_finite() {
+        format!("{:?}", x)
+    } else {
+        "null".to_string()
+    }
+}
+
+fn string(v: &str) -> String {
+    let mut s = String::with_capacity(v.len() + 2);
+    s.push('"');
+
+    for c in v.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            c if (c as u32) < 0x20 => s.push_str(&format!("\\u{:04x}", c as u32)),
+            c => s.push(c),
+        }
+    }
+
+    s.push('"');
+    s
+}
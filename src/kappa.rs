@@ -0,0 +1,217 @@
+use std;
+
+use dist::{Distribution, Normal};
+use error::Error;
+use fmt;
+
+
+/// How disagreements between categories are credited by a weighted kappa,
+/// for categories which are ordered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Weights {
+    /// Only exact agreement counts, as in Cohen's original kappa.
+    Unweighted,
+    /// Partial agreement falls off linearly with the distance between
+    /// categories.
+    Linear,
+    /// Partial agreement falls off with the square of the distance between
+    /// categories, which makes the kappa comparable to an intraclass
+    /// correlation.
+    Quadratic,
+}
+
+impl Weights {
+    /// The name of the weights, as given on the command line.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Weights::Unweighted => "none",
+            Weights::Linear => "linear",
+            Weights::Quadratic => "quadratic",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Weights> {
+        match s {
+            "none" => Some(Weights::Unweighted),
+            "linear" => Some(Weights::Linear),
+            "quadratic" => Some(Weights::Quadratic),
+            _ => None,
+        }
+    }
+
+    /// The agreement credited to categories `i` and `j` of `k`, from 0 to 1.
+    fn weight(&self, i: usize, j: usize, k: usize) -> f64 {
+        if i == j {
+            return 1.0;
+        }
+
+        let d = i.abs_diff(j) as f64 / (k - 1) as f64;
+        match *self {
+            Weights::Unweighted => 0.0,
+            Weights::Linear => 1.0 - d,
+            Weights::Quadratic => 1.0 - d * d,
+        }
+    }
+}
+
+/// The agreement of two raters on the same subjects, by Cohen's kappa.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Kappa {
+    pub kappa: f64,
+
+    /// The asymptotic standard error of `kappa`.
+    pub standard_error: f64,
+
+    /// The z statistic and two-sided p-value of the null hypothesis that the
+    /// raters agree only by chance, so that the kappa is 0.
+    pub z: f64,
+    pub p: f64,
+
+    /// The weighted proportion of subjects on which the raters agree.
+    pub observed: f64,
+
+    /// The proportion expected to agree by chance, were the raters
+    /// independent, given how often each uses each category.
+    pub expected: f64,
+
+    /// The number of subjects.
+    pub size: f64,
+}
+
+/// Formats the results as `dent` prints them, one per line. A precision, as
+/// in `{:.3}`, gives the number of significant digits of each.
+impl std::fmt::Display for Kappa {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+
+        writeln!(f, "{:>w$} = {}", "Kappa", fmt::stat(self.kappa, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "SE", fmt::stat(self.standard_error, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "z", fmt::stat(self.z, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "p", fmt::stat(self.p, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "Observed", fmt::stat(self.observed, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "Expected", fmt::stat(self.expected, p), w = w)
+    }
+}
+
+/// The counts of subjects given each pair of categories by two raters, with
+/// the categories, in order: `counts[i][j]` subjects were put in category `i`
+/// by the first rater and in `j` by the second.
+#[derive(Debug)]
+pub struct ConfusionMatrix {
+    pub categories: Vec<String>,
+    pub counts: Vec<Vec<f64>>,
+}
+
+impl ConfusionMatrix {
+    /// Count the pairs of labels given to each subject by two raters. The
+    /// categories are every label either rater used, in numeric order if all
+    /// are numbers, and otherwise in lexical order, so that weighted kappas
+    /// of ordered ratings such as `1` to `5` see them in order.
+    pub fn new<S: AsRef<str>>(pairs: &[(S, S)]) -> ConfusionMatrix {
+        let mut categories: Vec<String> = pairs
+            .iter()
+            .flat_map(|(a, b)| vec![a.as_ref().to_string(), b.as_ref().to_string()])
+            .collect();
+
+        let numbers: Option<Vec<f64>> = categories.iter().map(|c| c.parse::<f64>().ok()).collect();
+        match numbers {
+            Some(_) => categories.sort_by(|a, b| {
+                let (x, y) = (a.parse::<f64>().unwrap_or(0.0), b.parse::<f64>().unwrap_or(0.0));
+                x.total_cmp(&y).then_with(|| a.cmp(b))
+            }),
+            None => categories.sort(),
+        }
+        categories.dedup();
+
+        let index = |s: &str| {
+            categories.iter().position(|c| c == s).unwrap_or_else(|| unreachable!())
+        };
+        let mut counts = vec![vec![0.0; categories.len()]; categories.len()];
+        for (a, b) in pairs {
+            counts[index(a.as_ref())][index(b.as_ref())] += 1.0;
+        }
+
+        ConfusionMatrix { categories, counts }
+    }
+}
+
+/// Measure the agreement of two raters who each put the same subjects in one
+/// of several categories, by Cohen's kappa [1], given their confusion matrix:
+/// the counts of subjects given each pair of categories, with the first
+/// rater's categories as rows. The kappa is the proportion of agreement
+/// beyond that expected by chance, from 1 for perfect agreement down through
+/// 0 for chance agreement.
+///
+/// With `Linear` or `Quadratic` weights, the categories are taken to be in
+/// order, and near misses get partial credit, giving a weighted kappa. The
+/// standard errors, of the kappa and of its test against 0, are those of
+/// Fleiss, Cohen, and Everitt (1969).
+///
+/// [1]: https://en.wikipedia.org/wiki/Cohen%27s_kappa
+pub fn cohens_kappa(counts: &[Vec<f64>], weights: Weights) -> Result<Kappa, Error> {
+    let k = counts.len();
+
+    if counts.iter().any(|row| row.len() != k) {
+        return Err(Error::BadSample);
+    }
+    if counts.iter().flatten().any(|c| !c.is_finite()) {
+        return Err(Error::NonFinite);
+    }
+    if counts.iter().flatten().any(|&c| c < 0.0) {
+        return Err(Error::BadSample);
+    }
+
+    let n: f64 = counts.iter().flatten().sum();
+    if n == 0.0 {
+        return Err(Error::EmptySample);
+    }
+
+    let p: Vec<Vec<f64>> = counts.iter().map(|row| row.iter().map(|c| c / n).collect()).collect();
+    let rows: Vec<f64> = p.iter().map(|row| row.iter().sum()).collect();
+    let cols: Vec<f64> = (0..k).map(|j| p.iter().map(|row| row[j]).sum()).collect();
+    let w = |i, j| weights.weight(i, j, k);
+    let cells = || (0..k).flat_map(move |i| (0..k).map(move |j| (i, j)));
+
+    let observed: f64 = cells().map(|(i, j)| w(i, j) * p[i][j]).sum();
+    let expected: f64 = cells().map(|(i, j)| w(i, j) * rows[i] * cols[j]).sum();
+
+    // With every subject in one category, chance explains all agreement.
+    if expected >= 1.0 {
+        return Err(Error::Undefined);
+    }
+
+    let kappa = (observed - expected) / (1.0 - expected);
+
+    // The mean weights of each row and column, by the other's marginals.
+    let row_weights: Vec<f64> = (0..k).map(|i| (0..k).map(|j| cols[j] * w(i, j)).sum()).collect();
+    let col_weights: Vec<f64> = (0..k).map(|j| (0..k).map(|i| rows[i] * w(i, j)).sum()).collect();
+    let scale = n * (1.0 - expected).powi(2);
+
+    let var = (cells()
+        .map(|(i, j)| {
+            let d = w(i, j) - (row_weights[i] + col_weights[j]) * (1.0 - kappa);
+            p[i][j] * d * d
+        })
+        .sum::<f64>() - (kappa - expected * (1.0 - kappa)).powi(2)) / scale;
+    let var0 = (cells()
+        .map(|(i, j)| {
+            let d = w(i, j) - (row_weights[i] + col_weights[j]);
+            rows[i] * cols[j] * d * d
+        })
+        .sum::<f64>() - expected * expected) / scale;
+
+    let z = kappa / var0.max(0.0).sqrt();
+    let p = if z.is_nan() { 1.0 } else { (2.0 * Normal::standard().sf(z.abs())?).min(1.0) };
+
+    Ok(Kappa {
+        kappa,
+        standard_error: var.max(0.0).sqrt(),
+        z,
+        p,
+        observed,
+        expected,
+        size: n,
+    })
+}
@@ -1,8 +1,50 @@
-extern crate stamp;
+//! Without the default `std` feature, the crate is `no_std`, needing only
+//! `alloc`, and takes its floating point functions from `libm`. It then
+//! includes only its core statistics: the `dist`, `summary`, and `t_test`
+//! modules, and those they depend on.
+#![cfg_attr(not(feature = "std"), no_std)]
+// The test harness links `std`, whose methods of `f64` then shadow those of
+// `num::Real`.
+#![cfg_attr(all(not(feature = "std"), test), allow(dead_code, unused_imports))]
 
+#[cfg(not(feature = "std"))] #[macro_use] extern crate alloc;
+#[cfg(not(feature = "std"))] extern crate core as std;
+#[cfg(any(not(feature = "std"), target_arch = "wasm32"))] extern crate libm;
+#[cfg(feature = "serde")] #[macro_use] extern crate serde;
+// The code PyO3 generates names `::core`, which needs this in edition 2015.
+#[cfg(feature = "python")] extern crate core;
+#[cfg(feature = "python")] extern crate pyo3;
+#[cfg(feature = "std")] extern crate stamp;
+#[cfg(feature = "std")] extern crate term;
+#[cfg(feature = "wasm")] extern crate wasm_bindgen;
+
+#[cfg(feature = "std")] pub mod advice;
+#[cfg(feature = "std")] pub mod anova;
+#[cfg(feature = "std")] pub mod bland_altman;
+#[cfg(feature = "std")] pub mod change;
+#[cfg(feature = "std")] pub mod changepoint;
+pub mod dist;
+#[cfg(feature = "std")] pub mod effect_size;
 pub mod error;
-pub mod lr;
+#[cfg(feature = "ffi")] pub mod ffi;
+#[cfg(feature = "std")] pub mod fit;
+pub mod fmt;
+#[cfg(feature = "std")] pub mod frequency;
+#[cfg(feature = "std")] pub mod histogram;
+#[cfg(feature = "std")] pub mod kappa;
+#[cfg(feature = "std")] pub mod lr;
+#[cfg(feature = "std")] pub mod mann_whitney;
+#[cfg(feature = "std")] pub mod non_inferiority;
+#[cfg(feature = "std")] pub mod normality;
 mod num;
-pub mod plot;
+#[cfg(feature = "std")] pub mod permutation;
+#[cfg(feature = "std")] pub mod plot;
+#[cfg(not(feature = "std"))] mod prelude;
+#[cfg(feature = "python")] pub mod python;
+#[cfg(feature = "std")] pub mod random;
+#[cfg(feature = "std")] pub mod rank;
+#[cfg(feature = "std")] pub mod roc;
 pub mod summary;
+#[cfg(feature = "std")] pub mod survival;
 pub mod t_test;
+#[cfg(feature = "wasm")] pub mod wasm;
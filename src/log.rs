@@ -1,8 +1,18 @@
+use std::io::{self, IsTerminal, Write};
+
 use term;
 
 
+/// Print an error message to stderr, highlighted if stderr is a terminal that
+/// supports it.
 pub fn error(err: &str) {
-    let mut t = term::stderr().expect("Couldn't open terminal device");
+    let mut t = match term::stderr() {
+        Some(t) if io::stderr().is_terminal() => t,
+        _ => {
+            let _ = writeln!(io::stderr(), "dent: error: {}", err);
+            return;
+        },
+    };
 
     let _ = t.attr(term::Attr::Bold);
     let _ = t.fg(term::color::BLACK);
@@ -0,0 +1,82 @@
+use dent::anova::Anova;
+use dent::change::Change;
+use dent::fmt;
+use dent::lr::LinearRegression;
+use dent::summary::{Summarizer, Summary};
+use dent::t_test::TTest;
+
+use field::Field;
+
+
+/// Render `fields` of a sample as `key: value` lines, one per statistic.
+pub fn summary(source: &str, s: &Summarizer, fields: &[Field], precision: Option<usize>)
+               -> String {
+    let mut out = format!("source: {}\n", source);
+
+    for f in fields {
+        out += &line(&f.key(), f.value(s), precision);
+    }
+
+    out
+}
+
+pub fn t_test(t_test: &TTest, s1: &Summary, s2: &Summary, precision: Option<usize>) -> String {
+    [
+        ("mean_difference", s2.mean() - s1.mean()),
+        ("t", t_test.t),
+        ("df", t_test.df),
+        ("p", t_test.p),
+    ].iter().map(|&(k, v)| line(k, v, precision)).collect()
+}
+
+/// Render the change of a `statistic`, such as `mean`, between samples.
+pub fn change(statistic: &str, c: &Change, precision: Option<usize>) -> String {
+    [
+        ("change", c.absolute),
+        ("change_margin", c.absolute_margin),
+        ("relative_change", c.relative),
+        ("relative_change_margin", c.relative_margin),
+    ].iter().map(|&(k, v)| line(&format!("{}_{}", statistic, k), v, precision)).collect()
+}
+
+/// Render a matrix of effect sizes by `measure`, keyed by the indices of the
+/// samples from 1: `cohens_d_1_2` is the effect size of the second sample
+/// relative to the first.
+pub fn effect_sizes(measure: &str, matrix: &[Vec<f64>], precision: Option<usize>) -> String {
+    let mut out = String::new();
+
+    for (i, row) in matrix.iter().enumerate() {
+        for (j, &x) in row.iter().enumerate() {
+            out += &line(&format!("{}_{}_{}", measure, i + 1, j + 1), x, precision);
+        }
+    }
+
+    out
+}
+
+pub fn anova(anova: &Anova, precision: Option<usize>) -> String {
+    [
+        ("f", anova.f),
+        ("df_between", anova.df_between),
+        ("df_within", anova.df_within),
+        ("p", anova.p),
+    ].iter().map(|&(k, v)| line(k, v, precision)).collect()
+}
+
+pub fn linear_regression(lr: &LinearRegression, precision: Option<usize>) -> String {
+    [
+        ("slope", lr.slope()),
+        ("intercept", lr.intercept()),
+        ("r", lr.r()),
+        ("standard_error", lr.standard_error()),
+    ].iter().map(|&(k, v)| line(k, v, precision)).collect()
+}
+
+fn line(key: &str, value: f64, precision: Option<usize>) -> String {
+    let v = match precision {
+        Some(p) => fmt::sig(value, p),
+        None => fmt::full(value),
+    };
+
+    format!("{}: {}\n", key, v)
+}
@@ -1,9 +1,14 @@
+use std;
+
 use error::Error;
+use fmt;
 use summary::Summarizer;
 
 
 /// The results of a simple linear regression with one predictor variable and
 /// one response variable.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct LinearRegression {
     intercept: f64,
     r: f64,
@@ -26,6 +31,17 @@ impl LinearRegression {
         LinearRegression::simple_lr(data)
     }
 
+    /// Fit the sample data as `new()` does, from pairs of 32-bit floating
+    /// point numbers, which are widened to `f64`.
+    pub fn from_f32(data: &[(f32, f32)]) -> Result<Self, Error> {
+        let data: Vec<(f64, f64)> = data
+            .iter()
+            .map(|&(x, y)| (f64::from(x), f64::from(y)))
+            .collect();
+
+        LinearRegression::new(&data)
+    }
+
     /// Intercept `β` of the fitted linear model `Y = αX + β`.
     pub fn intercept(&self) -> f64 {
         self.intercept
@@ -60,6 +76,10 @@ impl LinearRegression {
         let std_x = summ_x.standard_deviation();
         let std_y = summ_y.standard_deviation();
 
+        if std_x.is_nan() || std_x == 0.0 {
+            return Err(Error::SingularFit);
+        }
+
         let r_num: f64 = (0..x.len())
             .map(|i| (x[i] - mean_x) * (y[i] - mean_y))
             .sum();
@@ -80,3 +100,17 @@ impl LinearRegression {
         })
     }
 }
+
+/// Formats the fitted model as `dent` prints it, one statistic per line. A
+/// precision, as in `{:.3}`, gives the number of significant digits of each.
+impl std::fmt::Display for LinearRegression {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+
+        writeln!(f, "{:>w$} = {}", "Slope", fmt::stat(self.slope, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "Intercept", fmt::stat(self.intercept, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "r", fmt::stat(self.r, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "SE", fmt::stat(self.standard_error, p), w = w)
+    }
+}
@@ -0,0 +1,283 @@
+use std;
+
+use dist::{Distribution, Normal};
+use error::Error;
+use fmt;
+use rank::joint_ranks;
+use summary::Summarizer;
+
+
+/// The results of a two-sided Mann-Whitney U test.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MannWhitney {
+    /// The U statistic of the second sample: the number of pairs of values,
+    /// one from each sample, in which that of the second sample is greater,
+    /// counting ties as one half.
+    pub u: f64,
+    pub p: f64,
+
+    /// Whether `p` is exact, rather than from the normal approximation.
+    pub exact: bool,
+}
+
+/// Formats the results as `dent` prints them, one per line. A precision, as
+/// in `{:.3}`, gives the number of significant digits of each.
+impl std::fmt::Display for MannWhitney {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+
+        writeln!(f, "{:>w$} = {}", "p", fmt::stat(self.p, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "U", fmt::stat(self.u, p), w = w)
+    }
+}
+
+/// Samples with fewer values than this each, and no ties, get an exact
+/// p-value, as R's `wilcox.test` does.
+const EXACT_LIMIT: usize = 50;
+
+/// Test the null hypothesis that a value of either sample is as likely to
+/// exceed one of the other as to be exceeded by it, by the Mann-Whitney U test
+/// [1]. Depending only on the order of values, it needs no assumption of
+/// normality.
+///
+/// The p-value is exact for small samples without ties, and otherwise comes
+/// from the normal approximation with corrections for ties and continuity.
+///
+/// [1]: https://www.itl.nist.gov/div898/software/dataplot/refman1/auxillar/mannwhit.htm
+pub fn mann_whitney(s1: &Summarizer, s2: &Summarizer) -> Result<MannWhitney, Error> {
+    let (a, b) = (s1.as_slice(), s2.as_slice());
+    let (n1, n2) = (a.len(), b.len());
+
+    let ranks = joint_ranks(&[a, b]);
+    let r2: f64 = ranks[1].iter().sum();
+    let u = r2 - (n2 * (n2 + 1)) as f64 / 2.0;
+
+    let pooled = [a, b].concat();
+    let ties = tie_correction(&pooled);
+
+    if n1 < EXACT_LIMIT && n2 < EXACT_LIMIT && ties == 0.0 {
+        let p = exact_p(u, n1, n2);
+        return Ok(MannWhitney { u, p, exact: true });
+    }
+
+    let (n1, n2) = (n1 as f64, n2 as f64);
+    let n = n1 + n2;
+    let mean = n1 * n2 / 2.0;
+    let var = n1 * n2 / 12.0 * ((n + 1.0) - ties / (n * (n - 1.0)));
+
+    // Without variation, every value is tied.
+    if var <= 0.0 {
+        return Err(Error::Undefined);
+    }
+
+    let diff = u - mean;
+    let correction = 0.5 * diff.signum();
+    let z = (diff - correction) / var.sqrt();
+    let p = (2.0 * Normal::standard().sf(z.abs())?).min(1.0);
+
+    Ok(MannWhitney { u, p, exact: false })
+}
+
+/// The Hodges-Lehmann estimate of the shift in location of the second of two
+/// samples from the first, with its confidence interval `[lower, upper]` at
+/// level `confidence`.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HodgesLehmann {
+    /// The median of the differences of each value of the second sample
+    /// less each value of the first.
+    pub shift: f64,
+    pub lower: f64,
+    pub upper: f64,
+    pub confidence: f64,
+
+    /// Whether the interval is exact, rather than from the normal
+    /// approximation.
+    pub exact: bool,
+}
+
+/// Formats the estimate and its interval as `dent` prints them, one per line.
+/// A precision, as in `{:.3}`, gives the number of significant digits of each.
+impl std::fmt::Display for HodgesLehmann {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+        let level = format!("{} CI", fmt::level(self.confidence));
+
+        writeln!(f, "{:>w$} = {}", "HL shift", fmt::stat(self.shift, p), w = w)?;
+        writeln!(f, "{:>w$} = {} to {}", level, fmt::stat(self.lower, p),
+                 fmt::stat(self.upper, p), w = w)
+    }
+}
+
+/// Estimate the shift in location of `s2` from `s1` by the Hodges-Lehmann
+/// estimator [1], the median of all differences of a value of `s2` less one
+/// of `s1`, which is robust to outliers and heavy tails. Its interval is that
+/// of R's `wilcox.test`, bounded by order statistics of the differences whose
+/// ranks are quantiles of the Mann-Whitney U statistic: exact for small
+/// samples without ties, as `mann_whitney` is, and otherwise from the normal
+/// approximation.
+///
+/// The differences are never all formed, so large samples need little memory.
+///
+/// [1]: https://doi.org/10.1214/aoms/1177704172
+pub fn hodges_lehmann(s1: &Summarizer, s2: &Summarizer, confidence: f64)
+                      -> Result<HodgesLehmann, Error> {
+    if !(confidence > 0.0 && confidence < 1.0) {
+        return Err(Error::Undefined);
+    }
+
+    let (a, b) = (s1.as_slice(), s2.as_slice());
+    let (n1, n2) = (a.len(), b.len());
+    let n = n1 * n2;
+    let alpha = 1.0 - confidence;
+
+    let shift = if n % 2 == 1 {
+        kth_difference(a, b, n / 2 + 1)
+    } else {
+        (kth_difference(a, b, n / 2) + kth_difference(a, b, n / 2 + 1)) / 2.0
+    };
+
+    let ties = tie_correction(&[a, b].concat());
+    let exact = n1 < EXACT_LIMIT && n2 < EXACT_LIMIT && ties == 0.0;
+
+    // The rank of the lower bound: the least `U` at least as likely to be
+    // reached or exceeded downward as half of `alpha`.
+    let rank = if exact {
+        let counts = u_counts(n1, n2);
+        let total: f64 = counts.iter().sum();
+        let mut cumulative = 0.0;
+
+        counts
+            .iter()
+            .position(|&c| {
+                cumulative += c;
+                cumulative / total >= alpha / 2.0
+            })
+            .unwrap_or(n)
+    } else {
+        let (m1, m2) = (n1 as f64, n2 as f64);
+        let var = m1 * m2 / 12.0 * ((m1 + m2 + 1.0) - ties / ((m1 + m2) * (m1 + m2 - 1.0)));
+        if var <= 0.0 {
+            return Err(Error::Undefined);
+        }
+
+        let z = Normal::standard().quantile(1.0 - alpha / 2.0)?;
+        (n as f64 / 2.0 - 0.5 - z * var.sqrt()).ceil().max(0.0) as usize
+    };
+    let rank = rank.clamp(1, n.div_ceil(2));
+
+    Ok(HodgesLehmann {
+        shift,
+        lower: kth_difference(a, b, rank),
+        upper: kth_difference(a, b, n + 1 - rank),
+        confidence,
+        exact,
+    })
+}
+
+/// The `k`th least, from 1, of the differences `b[j] - a[i]` of sorted `a`
+/// and `b`, found by bisection on the number of differences at most a value,
+/// which needs no more memory than the samples.
+fn kth_difference(a: &[f64], b: &[f64], k: usize) -> f64 {
+    // The number of differences at most `d`: for each `a[i]`, in order, the
+    // values of `b` at most `a[i] + d` are a prefix which only grows.
+    let count = |d: f64| {
+        let mut j = 0;
+        a.iter()
+            .map(|&x| {
+                while j < b.len() && b[j] - x <= d {
+                    j += 1;
+                }
+                j
+            })
+            .sum::<usize>()
+    };
+
+    // The `k`th difference is in `(lo, hi]`.
+    let mut lo = b[0] - a[a.len() - 1];
+    let mut hi = b[b.len() - 1] - a[0];
+    if count(lo) >= k {
+        return lo;
+    }
+
+    loop {
+        let mid = lo + (hi - lo) / 2.0;
+        if mid <= lo || mid >= hi {
+            return hi;
+        }
+
+        if count(mid) >= k {
+            hi = mid;
+        } else {
+            lo = mid;
+        }
+    }
+}
+
+/// The sum of `t³ - t` over the sizes `t` of each group of tied values.
+fn tie_correction(pooled: &[f64]) -> f64 {
+    let mut sorted = pooled.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let mut sum = 0.0;
+    let mut i = 0;
+    while i < sorted.len() {
+        let mut j = i + 1;
+        while j < sorted.len() && sorted[j] == sorted[i] {
+            j += 1;
+        }
+
+        let t = (j - i) as f64;
+        sum += t.powi(3) - t;
+        i = j;
+    }
+
+    sum
+}
+
+/// The exact two-sided p-value of `u` for samples of sizes `n1` and `n2`
+/// without ties, from the count of orderings which give each `U`.
+fn exact_p(u: f64, n1: usize, n2: usize) -> f64 {
+    let counts = u_counts(n1, n2);
+    let total: f64 = counts.iter().sum();
+
+    // `U` is a whole number without ties.
+    let u = u.round() as usize;
+    let lower: f64 = counts[..=u].iter().sum();
+    let upper: f64 = counts[u..].iter().sum();
+
+    (2.0 * lower.min(upper) / total).min(1.0)
+}
+
+/// The number of orderings of samples of sizes `m` and `n` which give each
+/// value of `U`, from 0 to `mn`, by the recurrence
+/// `f(m, n, u) = f(m - 1, n, u - n) + f(m, n - 1, u)`.
+fn u_counts(m: usize, n: usize) -> Vec<f64> {
+    // The counts of `f(i, j, _)` for each `j` of the current `i`.
+    let mut prev: Vec<Vec<f64>> = (0..=n).map(|_| vec![1.0]).collect();
+
+    for i in 1..=m {
+        let mut next: Vec<Vec<f64>> = Vec::with_capacity(n + 1);
+        next.push(vec![1.0]);
+
+        for j in 1..=n {
+            let mut f = vec![0.0; i * j + 1];
+
+            for (u, &c) in prev[j].iter().enumerate() {
+                f[u + j] += c;
+            }
+            for (u, &c) in next[j - 1].iter().enumerate() {
+                f[u] += c;
+            }
+
+            next.push(f);
+        }
+
+        prev = next;
+    }
+
+    prev.pop().unwrap_or_else(|| unreachable!())
+}
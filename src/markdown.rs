@@ -0,0 +1,79 @@
+use dent::change::Change;
+use dent::fmt;
+use dent::plot::{self, PlotOptions};
+use dent::summary::{Summarizer, Summary};
+use dent::t_test::TTest;
+
+use field::Field;
+use table::{Align, Table};
+
+
+/// Width of formatted numbers in Markdown tables.
+const NUM_WIDTH: usize = 10;
+
+/// Render a GitHub-flavored Markdown table of `fields` of the samples of
+/// `sources`.
+pub fn summary_table(sources: &[String], samples: &[Summarizer], fields: &[Field],
+                     precision: Option<usize>) -> String {
+    let mut t = fields.iter().fold(Table::new().column("Source", Align::Left, 0), |t, f| {
+        t.column(&f.title(), Align::Right, 0)
+    });
+
+    for (src, s) in sources.iter().zip(samples) {
+        let mut cells = vec![code(src)];
+        cells.extend(fields.iter().map(|f| fmt::cell(f.value(s), NUM_WIDTH, precision)));
+        t.row(cells);
+    }
+
+    t.markdown()
+}
+
+/// Render a GitHub-flavored Markdown table of the results of a t-test.
+pub fn t_test_table(t_test: &TTest, s1: &Summary, s2: &Summary, precision: Option<usize>)
+                    -> String {
+    let header = ["m₁", "m₂", "m₂ - m₁", "t", "DF", "p"];
+    let values = [s1.mean(), s2.mean(), s2.mean() - s1.mean(), t_test.t, t_test.df, t_test.p];
+
+    let mut t = header.iter().fold(Table::new(), |t, h| t.column(h, Align::Right, 0));
+    t.row(values.iter().map(|&x| fmt::cell(x, NUM_WIDTH, precision)).collect());
+
+    t.markdown()
+}
+
+/// Render a GitHub-flavored Markdown table of the changes of the mean and
+/// median between samples, with their confidence intervals.
+pub fn change_table(mean: &Change, median: &Change, precision: Option<usize>) -> String {
+    let ci = format!("{} CI", fmt::level(mean.confidence));
+    let mut t = Table::new()
+        .column("Change", Align::Left, 0)
+        .column("Absolute", Align::Right, 0)
+        .column(&ci, Align::Right, 0)
+        .column("Relative", Align::Right, 0)
+        .column(&ci, Align::Right, 0);
+
+    for (name, c) in [("Mean", mean), ("Median", median)] {
+        t.row(vec![
+            name.to_string(),
+            fmt::cell(c.absolute, NUM_WIDTH, precision),
+            format!("± {}", fmt::cell(c.absolute_margin, NUM_WIDTH, precision)),
+            fmt::signed_percent(c.relative, precision),
+            format!("± {}", fmt::percent(c.relative_margin, precision)),
+        ]);
+    }
+
+    t.markdown()
+}
+
+/// Render a comparison plot in a fenced code block. Markdown renderers vary in
+/// their handling of box-drawing characters, so we always plot in ASCII.
+pub fn plot_block(summaries: &[&Summary], opts: &PlotOptions) -> Result<String, &'static str> {
+    let opts = opts.clone().ascii(true).color(false);
+    let p = plot::comparison_plot(summaries, &opts)?;
+
+    Ok(format!("```\n{}\n```\n", p))
+}
+
+/// Format `s` as inline code, escaping pipes so the table stays intact.
+fn code(s: &str) -> String {
+    format!("`{}`", s.replace('|', "\\|"))
+}
@@ -0,0 +1,193 @@
+use dent::summary::{Summarizer, Summary};
+use dent::t_test::{pooled_sd, t_critical};
+
+
+/// Markers of samples in ministat's plot and table, indexed from 1 by sample.
+/// Values of several samples in the same place are marked with the bitwise
+/// OR of their indices, so an `x` and a `+` make a `*`.
+const SYMBOLS: &[u8] = b" x+*%#@O";
+
+/// The most samples ministat can show together.
+const MAX_SAMPLES: usize = 7;
+
+/// Render `samples` as BSD ministat does: a legend of the markers of
+/// `sources`, a dot plot `width` columns wide within its frame, and a table of
+/// summary statistics. With `compare`, each sample after the first is compared
+/// to it by Student's t-test, with a confidence interval at level
+/// `confidence`.
+pub fn render(sources: &[String], samples: &[Summarizer], compare: bool, confidence: f64,
+              width: usize) -> Result<String, String> {
+    if samples.len() > MAX_SAMPLES {
+        return Err(format!("The ministat style shows at most {} samples, not {}", MAX_SAMPLES,
+                           samples.len()));
+    }
+
+    let mut s = String::new();
+
+    for (i, src) in sources.iter().enumerate() {
+        s += &format!("{} {}\n", symbol(i), src);
+    }
+
+    s += &plot(samples, width);
+    s += "    N           Min           Max        Median           Avg        Stddev\n";
+
+    for (i, sample) in samples.iter().enumerate() {
+        s += &format!("{} {:>3} {:>13} {:>13} {:>13} {:>13} {:>13}\n", symbol(i), sample.size(),
+                      g(sample.min(), 8), g(sample.max(), 8), g(sample.median(), 8),
+                      g(sample.mean(), 8), g(sample.standard_deviation(), 8));
+
+        if compare && i > 0 {
+            s += &relative(&Summary::from(sample), &Summary::from(&samples[0]), confidence);
+        }
+    }
+
+    Ok(s)
+}
+
+fn symbol(i: usize) -> char {
+    SYMBOLS[i + 1] as char
+}
+
+/// Plot each value of `samples` as a marker in the column of its value,
+/// stacking values in the same column, above a line marking the mean (`A`),
+/// the median (`M`), and the mean plus or minus a standard deviation (`|`) of
+/// each sample.
+fn plot(samples: &[Summarizer], width: usize) -> String {
+    let min = samples.iter().map(|s| s.min()).fold(f64::INFINITY, f64::min);
+    let max = samples.iter().map(|s| s.max()).fold(f64::NEG_INFINITY, f64::max);
+    let span = max - min;
+
+    if span == 0.0 {
+        return "[no plot, span is zero width]\n".to_string();
+    }
+
+    // Each column is `dx` wide, and the least and greatest values fall in the
+    // middle of the first and last.
+    let width = width.max(2);
+    let dx = span / (width - 1) as f64;
+    let x0 = min - 0.5 * dx;
+    let column = |x: f64| (((x - x0) / dx) as usize).min(width - 1);
+
+    // The markers of values stacked `j` high in each column; the first row is
+    // unused, as in ministat.
+    let mut rows: Vec<Vec<u8>> = vec![vec![0; width]];
+    let mut bar = vec![b' '; width];
+
+    for (i, sample) in samples.iter().enumerate() {
+        let mut last = None;
+        let mut j = 0;
+
+        for &x in sample.as_slice() {
+            let c = column(x);
+            if last == Some(c) {
+                j += 1;
+            } else {
+                j = 1;
+                last = Some(c);
+            }
+
+            if rows.len() <= j {
+                rows.push(vec![0; width]);
+            }
+            rows[j][c] |= (i + 1) as u8;
+        }
+
+        let sd = sample.standard_deviation();
+        if !sd.is_nan() {
+            let lo = column(sample.mean() - sd);
+            let hi = column(sample.mean() + sd);
+
+            for b in bar.iter_mut().take(hi).skip(lo + 1) {
+                if *b == b' ' {
+                    *b = b'_';
+                }
+            }
+            bar[lo] = b'|';
+            bar[hi] = b'|';
+        }
+
+        bar[column(sample.median())] = b'M';
+        bar[column(sample.mean())] = b'A';
+    }
+
+    let frame = format!("+{}+\n", "-".repeat(width));
+    let mut s = frame.clone();
+
+    for row in rows.iter().skip(1).rev() {
+        let markers: String = row.iter().map(|&k| SYMBOLS[k as usize] as char).collect();
+        s += &format!("|{}|\n", markers);
+    }
+    s += &format!("|{}|\n", String::from_utf8_lossy(&bar));
+
+    s + &frame
+}
+
+/// Compare sample `s` to the first sample, `base`, as ministat does: by the
+/// difference of their means and its confidence interval at level
+/// `confidence`, assuming equal variances.
+fn relative(s: &Summary, base: &Summary, confidence: f64) -> String {
+    let (n, n_base) = (s.size(), base.size());
+    let t = match t_critical(confidence, n + n_base - 2.0) {
+        Ok(t) => t,
+        Err(_) => return String::new(),
+    };
+
+    let pooled = pooled_sd(s, base);
+    let d = s.mean() - base.mean();
+    let e = t * pooled * (n.recip() + n_base.recip()).sqrt();
+
+    // The margin of the relative difference, exactly as ministat computes it.
+    let re = (n - 1.0) * variance(s)
+        + (n_base - 1.0) * variance(base) * s.mean().powi(2) / base.mean().powi(2);
+    let re = t * (re * (n + n_base) / (n * n_base * (n + n_base - 2.0))).sqrt();
+
+    let confidence = 100.0 * confidence;
+
+    if d.abs() > e {
+        format!("Difference at {:.1}% confidence\n\
+                 \t{} +/- {}\n\
+                 \t{}% +/- {}%\n\
+                 \t(Student's t, pooled s = {})\n",
+                confidence, g(d, 6), g(e, 6), g(100.0 * d / base.mean(), 6),
+                g(100.0 * re / base.mean(), 6), g(pooled, 6))
+    } else {
+        format!("No difference proven at {:.1}% confidence\n", confidence)
+    }
+}
+
+/// The variance of a sample, or zero for a single value.
+fn variance(s: &Summary) -> f64 {
+    if s.size() < 2.0 { 0.0 } else { s.unbiased_variance() }
+}
+
+/// Format `x` as C's `printf("%.*g")` does: to `precision` significant digits
+/// without trailing zeros, in scientific notation with at least two exponent
+/// digits if its exponent is less than -4 or at least `precision`.
+fn g(x: f64, precision: usize) -> String {
+    if x.is_nan() {
+        return "nan".to_string();
+    }
+    if x.is_infinite() {
+        return if x < 0.0 { "-inf" } else { "inf" }.to_string();
+    }
+
+    let p = precision.max(1);
+    let s = format!("{x:.p$e}", p = p - 1, x = x);
+    let (mantissa, e) = s.split_at(s.find('e').unwrap_or(s.len()));
+    let e: i32 = e[1..].parse().unwrap_or(0);
+
+    if e < -4 || e >= p as i32 {
+        let sign = if e < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", trim_zeros(mantissa), sign, e.abs())
+    } else {
+        trim_zeros(&format!("{x:.p$}", p = (p as i32 - 1 - e) as usize, x = x))
+    }
+}
+
+fn trim_zeros(s: &str) -> String {
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        s.to_string()
+    }
+}
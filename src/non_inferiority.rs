@@ -0,0 +1,117 @@
+use std;
+
+use dist::{Distribution, StudentsT};
+use error::Error;
+use fmt;
+use summary::Summary;
+use t_test::{t_critical, welch_satterthwaite_df};
+
+
+/// How much worse than a baseline a new sample may be and still be no worse,
+/// when greater values are worse, as for run times.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Margin {
+    /// A difference of means, in units of the data.
+    Absolute(f64),
+    /// A fraction of the mean of the baseline, such as 0.05 for 5%.
+    Relative(f64),
+}
+
+impl Margin {
+    /// The margin, absolute or relative, as a number.
+    pub fn value(&self) -> f64 {
+        match *self {
+            Margin::Absolute(m) | Margin::Relative(m) => m,
+        }
+    }
+}
+
+/// A test of whether the mean of a new sample is not worse than that of a
+/// baseline by `margin` or more, with the one-sided confidence interval of the
+/// change of the mean, which is `(-∞, upper]` at level `confidence`. Each is
+/// absolute or relative, as the margin is.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NonInferiority {
+    pub margin: Margin,
+    pub change: f64,
+    pub upper: f64,
+    pub confidence: f64,
+    pub t: f64,
+    pub df: f64,
+    pub p: f64,
+}
+
+impl NonInferiority {
+    /// Whether the new sample is shown to be no worse than the baseline: the
+    /// confidence interval of the change lies below the margin.
+    pub fn non_inferior(&self) -> bool {
+        self.upper < self.margin.value()
+    }
+}
+
+/// Formats the change, its interval, and the test as `dent` prints them, one
+/// per line. A precision, as in `{:.3}`, gives the number of significant
+/// digits of each.
+impl std::fmt::Display for NonInferiority {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+        let x = |x| match self.margin {
+            Margin::Absolute(_) => fmt::stat(x, p),
+            Margin::Relative(_) => fmt::signed_percent(x, p),
+        };
+        let level = format!("{} upper", fmt::level(self.confidence));
+
+        writeln!(f, "{:>w$} = {}", "Margin", x(self.margin.value()), w = w)?;
+        writeln!(f, "{:>w$} = {}", "Change", x(self.change), w = w)?;
+        writeln!(f, "{:>w$} = {}", level, x(self.upper), w = w)?;
+        writeln!(f, "{:>w$} = {}", "p", fmt::stat(self.p, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "t", fmt::stat(self.t, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "DF", fmt::stat(self.df, p), w = w)
+    }
+}
+
+/// Test whether the mean of `new` is not worse than that of `baseline` by
+/// `margin` or more, when greater values are worse, at level `confidence`,
+/// such as 0.95. The null hypothesis is that the change of the mean is at
+/// least the margin, and the new sample is non-inferior if the test rejects
+/// it, as it does just when the one-sided confidence interval of the change
+/// lies below the margin.
+///
+/// The change has the standard error of the difference of means, or, if the
+/// margin is relative, that of their ratio by the delta method, as a
+/// `change::Change` does, with Welch-Satterthwaite degrees of freedom.
+pub fn non_inferiority(baseline: &Summary, new: &Summary, margin: Margin, confidence: f64)
+                       -> Result<NonInferiority, Error> {
+    if !(confidence > 0.5 && confidence < 1.0 && margin.value().is_finite()) {
+        return Err(Error::Undefined);
+    }
+
+    let (m1, se1) = (baseline.mean(), baseline.standard_error());
+    let (m2, se2) = (new.mean(), new.standard_error());
+
+    let (change, se) = match margin {
+        Margin::Absolute(_) => (m2 - m1, (se1.powi(2) + se2.powi(2)).sqrt()),
+        Margin::Relative(_) => {
+            let ratio = m2 / m1;
+            (ratio - 1.0, (se2.powi(2) + ratio.powi(2) * se1.powi(2)).sqrt() / m1.abs())
+        },
+    };
+    if !change.is_finite() {
+        return Err(Error::Undefined);
+    }
+
+    let df = welch_satterthwaite_df(baseline.unbiased_variance(), baseline.size(),
+                                    new.unbiased_variance(), new.size());
+    let t = (change - margin.value()) / se;
+
+    // A one-sided interval at a level has the bound of a two-sided one with
+    // twice the probability outside it.
+    let upper = change + t_critical(2.0 * confidence - 1.0, df)? * se;
+    // The probability of a t statistic at most `t`.
+    let p = StudentsT::new(df)?.cdf(t)?;
+
+    Ok(NonInferiority { margin, change, upper, confidence, t, df, p })
+}
@@ -0,0 +1,144 @@
+use std;
+use std::f64::consts::PI;
+
+use dist::{Distribution, Normal};
+use error::Error;
+use fmt;
+use summary::Summarizer;
+
+
+/// The results of a Shapiro-Wilk test of normality.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ShapiroWilk {
+    /// The W statistic, from 0 to 1, where values near 1 are consistent with
+    /// normality.
+    pub w: f64,
+    pub p: f64,
+}
+
+/// Formats the results as `dent` prints them, one per line. A precision, as
+/// in `{:.3}`, gives the number of significant digits of each.
+impl std::fmt::Display for ShapiroWilk {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+
+        writeln!(f, "{:>w$} = {}", "W", fmt::stat(self.w, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "p", fmt::stat(self.p, p), w = w)
+    }
+}
+
+/// Test the null hypothesis that a sample was drawn from a normal
+/// distribution, by the Shapiro-Wilk test [1] with the approximations of its
+/// coefficients and p-value by Royston [2], for samples of 3 to 5000 values.
+///
+/// [1]: https://www.itl.nist.gov/div898/handbook/prc/section2/prc213.htm
+/// [2]: Royston, P. (1995). "Remark AS R94: A Remark on Algorithm AS 181: The
+///      W-test for Normality". Applied Statistics 44 (4): 547–551.
+pub fn shapiro_wilk(s: &Summarizer) -> Result<ShapiroWilk, Error> {
+    let x = s.as_slice();
+    let n = x.len();
+
+    if !(3..=5000).contains(&n) {
+        return Err(Error::Undefined);
+    }
+
+    let range = s.range();
+    if range == 0.0 {
+        return Err(Error::Undefined);
+    }
+
+    let a = coefficients(n);
+
+    // Scale by the range, as Royston does, to avoid overflow.
+    let mean = s.mean();
+    let ss: f64 = x.iter().map(|v| ((v - mean) / range).powi(2)).sum();
+    let b: f64 = a.iter().zip(x).map(|(a, v)| a * (v - mean) / range).sum();
+
+    // Rounding can leave `W` slightly greater than 1 for a perfectly
+    // normal-looking sample.
+    let w = (b.powi(2) / ss).min(1.0);
+
+    Ok(ShapiroWilk { w, p: p_value(w, n) })
+}
+
+/// The coefficients `a_i` of the order statistics of a sample of size `n`.
+fn coefficients(n: usize) -> Vec<f64> {
+    if n == 3 {
+        let a = 0.5f64.sqrt();
+        return vec![-a, 0.0, a];
+    }
+
+    let z = Normal::standard();
+    let nf = n as f64;
+
+    let m: Vec<f64> = (1..=n)
+        .map(|i| z.quantile((i as f64 - 0.375) / (nf + 0.25)).unwrap_or_else(|_| unreachable!()))
+        .collect();
+    let mm: f64 = m.iter().map(|m| m.powi(2)).sum();
+
+    let u = nf.sqrt().recip();
+    let poly = |c: &[f64]| c.iter().rev().fold(0.0, |acc, c| acc * u + c);
+
+    let an = m[n - 1] / mm.sqrt()
+        + poly(&[0.0, 0.221157, -0.147981, -2.071190, 4.434685, -2.706056]);
+
+    let mut a = vec![0.0; n];
+    if n > 5 {
+        let an1 = m[n - 2] / mm.sqrt()
+            + poly(&[0.0, 0.042981, -0.293762, -1.752461, 5.682633, -3.582633]);
+        let phi = (mm - 2.0 * m[n - 1].powi(2) - 2.0 * m[n - 2].powi(2))
+            / (1.0 - 2.0 * an.powi(2) - 2.0 * an1.powi(2));
+
+        for i in 2..(n - 2) {
+            a[i] = m[i] / phi.sqrt();
+        }
+        a[1] = -an1;
+        a[n - 2] = an1;
+    } else {
+        let phi = (mm - 2.0 * m[n - 1].powi(2)) / (1.0 - 2.0 * an.powi(2));
+
+        for i in 1..(n - 1) {
+            a[i] = m[i] / phi.sqrt();
+        }
+    }
+    a[0] = -an;
+    a[n - 1] = an;
+
+    a
+}
+
+/// The p-value of `w` for a sample of size `n`, from Royston's normalizing
+/// transformations of `W`, or exactly for `n = 3`.
+fn p_value(w: f64, n: usize) -> f64 {
+    if n == 3 {
+        let p = 6.0 / PI * (w.sqrt().asin() - 0.75f64.sqrt().asin());
+        return p.max(0.0);
+    }
+
+    let nf = n as f64;
+    let poly = |c: &[f64], x: f64| c.iter().rev().fold(0.0, |acc, c| acc * x + c);
+
+    let z = if n <= 11 {
+        let gamma = poly(&[-2.273, 0.459], nf);
+        let mu = poly(&[0.5440, -0.39978, 0.025054, -6.714e-4], nf);
+        let sigma = poly(&[1.3822, -0.77857, 0.062767, -0.0020322], nf).exp();
+
+        // Past this bound, the transformation is undefined, and `W` is so
+        // small as to reject normality beyond doubt.
+        if (-w).ln_1p() >= gamma {
+            return 0.0;
+        }
+
+        (-(gamma - (-w).ln_1p()).ln() - mu) / sigma
+    } else {
+        let ln_n = nf.ln();
+        let mu = poly(&[-1.5861, -0.31082, -0.083751, 0.0038915], ln_n);
+        let sigma = poly(&[-0.4803, -0.082676, 0.0030302], ln_n).exp();
+
+        ((-w).ln_1p() - mu) / sigma
+    };
+
+    Normal::standard().sf(z).unwrap_or(0.0)
+}
@@ -1,26 +1,250 @@
 use error::Error;
 
+#[cfg(all(feature = "std", not(target_arch = "wasm32")))]
 mod cmath {
-    extern {
+    extern "C" {
+        pub fn erfc(x: f64) -> f64;
         pub fn lgamma(z: f64) -> f64;
     }
 }
 
+/// Without `std`, or on WebAssembly, which has no C math library, the same
+/// functions from `libm`.
+#[cfg(any(not(feature = "std"), target_arch = "wasm32"))]
+mod cmath {
+    pub unsafe fn erfc(x: f64) -> f64 {
+        ::libm::erfc(x)
+    }
+
+    pub unsafe fn lgamma(z: f64) -> f64 {
+        ::libm::lgamma(z)
+    }
+}
+
+/// The methods of `f64` which need `std`, from `libm`, for the crate without
+/// it.
+#[cfg(not(feature = "std"))]
+pub trait Real {
+    fn exp(self) -> f64;
+    fn exp_m1(self) -> f64;
+    fn floor(self) -> f64;
+    fn fract(self) -> f64;
+    fn ln(self) -> f64;
+    fn ln_1p(self) -> f64;
+    fn powf(self, n: f64) -> f64;
+    fn powi(self, n: i32) -> f64;
+    fn round(self) -> f64;
+    fn sqrt(self) -> f64;
+}
+
+#[cfg(not(feature = "std"))]
+impl Real for f64 {
+    fn exp(self) -> f64 {
+        ::libm::exp(self)
+    }
+
+    fn exp_m1(self) -> f64 {
+        ::libm::expm1(self)
+    }
+
+    fn floor(self) -> f64 {
+        ::libm::floor(self)
+    }
+
+    fn fract(self) -> f64 {
+        self - ::libm::trunc(self)
+    }
+
+    fn ln(self) -> f64 {
+        ::libm::log(self)
+    }
+
+    fn ln_1p(self) -> f64 {
+        ::libm::log1p(self)
+    }
+
+    fn powf(self, n: f64) -> f64 {
+        ::libm::pow(self, n)
+    }
+
+    fn powi(self, n: i32) -> f64 {
+        ::libm::pow(self, f64::from(n))
+    }
+
+    fn round(self) -> f64 {
+        ::libm::round(self)
+    }
+
+    fn sqrt(self) -> f64 {
+        ::libm::sqrt(self)
+    }
+}
+
 /// The natural logarithm of the gamma function [1].
 ///
 /// [1]: https://www.encyclopediaofmath.org/index.php/Gamma-function
-fn ln_gamma(z: f64) -> f64 {
+pub fn ln_gamma(z: f64) -> f64 {
     unsafe { cmath::lgamma(z) }
 }
 
-/// The complete beta function [1].
+/// The digamma function, the derivative of the natural log-gamma function
+/// [1], for positive `x`.
+///
+/// We shift `x` up by the recurrence [2] until the asymptotic expansion [3] is
+/// accurate to double precision.
+///
+/// [1]: http://dlmf.nist.gov/5.2#E2
+/// [2]: http://dlmf.nist.gov/5.5#E2
+/// [3]: http://dlmf.nist.gov/5.11#E2
+#[cfg(feature = "std")]
+pub fn digamma(x: f64) -> f64 {
+    let mut x = x;
+    let mut acc = 0.0;
+
+    while x < 6.0 {
+        acc -= x.recip();
+        x += 1.0;
+    }
+
+    let x2 = x.powi(2).recip();
+    let series = x2 * (1.0 / 12.0 - x2 * (1.0 / 120.0 - x2 * (1.0 / 252.0
+        - x2 * (1.0 / 240.0 - x2 / 132.0))));
+
+    acc + x.ln() - 0.5 / x - series
+}
+
+/// The trigamma function, the derivative of the digamma function, for positive
+/// `x`, computed like `digamma()`.
+#[cfg(feature = "std")]
+pub fn trigamma(x: f64) -> f64 {
+    let mut x = x;
+    let mut acc = 0.0;
+
+    while x < 6.0 {
+        acc += x.powi(2).recip();
+        x += 1.0;
+    }
+
+    let x2 = x.powi(2).recip();
+    let series = x2 * (1.0 / 6.0 - x2 * (1.0 / 30.0 - x2 * (1.0 / 42.0 - x2 / 30.0)));
+
+    acc + (1.0 + 0.5 / x + series) / x
+}
+
+/// The complementary error function [1].
+///
+/// [1]: http://dlmf.nist.gov/7.2#E2
+pub fn erfc(x: f64) -> f64 {
+    unsafe { cmath::erfc(x) }
+}
+
+/// The natural logarithm of the complete beta function [1].
 ///
 /// Computed using the equation [2] via the natural log-gamma function.
 ///
 /// [1]: https://www.encyclopediaofmath.org/index.php/Beta-function
 /// [2]: http://dlmf.nist.gov/8.17#E3
+pub fn ln_beta(a: f64, b: f64) -> f64 {
+    ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b)
+}
+
+/// The complete beta function [1].
+///
+/// [1]: https://www.encyclopediaofmath.org/index.php/Beta-function
 fn beta(a: f64, b: f64) -> f64 {
-    (ln_gamma(a) + ln_gamma(b) - ln_gamma(a + b)).exp()
+    ln_beta(a, b).exp()
+}
+
+/// The regularized lower incomplete gamma function `P(a, x)` [1].
+///
+/// We use the power series [2] when `x < a + 1`, and otherwise the complement
+/// of the continued fraction for `Q(a, x)` [3], each of which converges
+/// rapidly in its region.
+///
+/// [1]: http://dlmf.nist.gov/8.2#E4
+/// [2]: "Numerical Recipes in C", 2nd Ed., p. 218
+/// [3]: "Numerical Recipes in C", 2nd Ed., p. 219
+pub fn inc_gamma(a: f64, x: f64) -> Result<f64, Error> {
+    let (p, _) = inc_gamma_pq(a, x)?;
+
+    Ok(p)
+}
+
+/// The regularized upper incomplete gamma function `Q(a, x) = 1 - P(a, x)`,
+/// computed directly so as to keep precision in the upper tail.
+pub fn inc_gamma_complement(a: f64, x: f64) -> Result<f64, Error> {
+    let (_, q) = inc_gamma_pq(a, x)?;
+
+    Ok(q)
+}
+
+const INC_GAMMA_CF_APPX_ZERO: f64 = 1e-30;
+const INC_GAMMA_CONVERGENCE_LIMIT: f64 = 1e-15;
+const INC_GAMMA_MAX_ITER: usize = 1000;
+
+fn inc_gamma_pq(a: f64, x: f64) -> Result<(f64, f64), Error> {
+    if x.is_nan() || a.is_nan() { return Err(Error::Undefined); }
+    if x < 0.0 { return Err(Error::Undefined); }
+    if a <= 0.0 { return Err(Error::Undefined); }
+
+    if x == 0.0 {
+        return Ok((0.0, 1.0));
+    }
+    if x.is_infinite() {
+        return Ok((1.0, 0.0));
+    }
+
+    // Leading coefficient common to both representations.
+    let coeff = (a * x.ln() - x - ln_gamma(a)).exp();
+
+    if x < a + 1.0 {
+        let mut ap = a;
+        let mut del = a.recip();
+        let mut sum = del;
+
+        for _ in 0..INC_GAMMA_MAX_ITER {
+            ap += 1.0;
+            del *= x / ap;
+            sum += del;
+
+            if del.abs() < sum.abs() * INC_GAMMA_CONVERGENCE_LIMIT {
+                let p = sum * coeff;
+                return Ok((p, 1.0 - p));
+            }
+        }
+    } else {
+        // The modified Lentz's algorithm, as for `inc_beta_cf`.
+        let mut b = x + 1.0 - a;
+        let mut c = INC_GAMMA_CF_APPX_ZERO.recip();
+        let mut d = b.recip();
+        let mut h = d;
+
+        for i in 1..INC_GAMMA_MAX_ITER {
+            let i = i as f64;
+            let an = -i * (i - a);
+            b += 2.0;
+
+            d = an * d + b;
+            if d.abs() < INC_GAMMA_CF_APPX_ZERO {
+                d = INC_GAMMA_CF_APPX_ZERO;
+            }
+            c = b + an / c;
+            if c.abs() < INC_GAMMA_CF_APPX_ZERO {
+                c = INC_GAMMA_CF_APPX_ZERO;
+            }
+            d = d.recip();
+
+            let del = d * c;
+            h *= del;
+
+            if (del - 1.0).abs() < INC_GAMMA_CONVERGENCE_LIMIT {
+                let q = coeff * h;
+                return Ok((1.0 - q, q));
+            }
+        }
+    }
+
+    Err(Error::Diverged)
 }
 
 /// The regularized incomplete beta function [1].
@@ -35,18 +259,27 @@ fn beta(a: f64, b: f64) -> f64 {
 /// [3]: http://dlmf.nist.gov/8.17#E22
 /// [4]: http://dlmf.nist.gov/8.17#E4
 pub fn inc_beta(x: f64, a: f64, b: f64) -> Result<f64, Error> {
+    if x.is_nan() { return Err(Error::Undefined); }
     if x < 0.0 { return Err(Error::Undefined); }
     if 1.0 < x { return Err(Error::Undefined); }
     if a <= 0.0 { return Err(Error::Undefined); }
     if b <= 0.0 { return Err(Error::Undefined); }
 
     let bound = (a + 1.0) / (a + b + 2.0);
-    let ib = if x < bound {
+    // At the bound itself, the symmetry relation would only give back the
+    // same arguments when `a` = `b`.
+    let ib = if x <= bound {
         // The continued fraction will converge rapidly with the given args.
 
-        // Leading coefficient of [3].
+        // Leading coefficient of [3]. For large `a` and `b`, its factors
+        // underflow, and it must be found by its logarithm.
         let coeff = (x.powf(a) * (1.0 - x).powf(b))
             / (a * beta(a, b));
+        let coeff = if coeff.is_normal() {
+            coeff
+        } else {
+            (a * x.ln() + b * (-x).ln_1p() - ln_beta(a, b)).exp() / a
+        };
 
         coeff * inc_beta_cf(x, a, b)?
     } else {
@@ -120,7 +353,7 @@ fn inc_beta_cf_step(x: f64, a: f64, b: f64, i: usize, f0: f64, c0: f64, d0: f64)
 ///
 /// [1]: http://dlmf.nist.gov/8.17#E23
 fn cf_d(i: usize, x: f64, a: f64, b: f64) -> f64 {
-    if i % 2 == 0 {
+    if i.is_multiple_of(2) {
         let m = (i / 2) as f64;
         cf_d_even(m, x, a, b)
     } else {
@@ -0,0 +1,88 @@
+use std;
+
+use error::Error;
+use fmt;
+use random::Rng;
+use summary::Summarizer;
+
+
+/// The results of a two-sided permutation test of a difference in means.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Permutation {
+    /// The difference of the means of the samples, second less first.
+    pub difference: f64,
+    pub p: f64,
+
+    /// The number of random relabelings of the pooled sample.
+    pub resamples: usize,
+}
+
+/// Formats the results as `dent` prints them, one per line. A precision, as
+/// in `{:.3}`, gives the number of significant digits of each.
+impl std::fmt::Display for Permutation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+
+        writeln!(f, "{:>w$} = {}", "p", fmt::stat(self.p, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "Difference", fmt::stat(self.difference, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "Resamples", self.resamples, w = w)
+    }
+}
+
+/// The number of resamples of `permutation_test()`, enough to estimate a
+/// p-value near 0.05 to within about 0.005.
+pub const RESAMPLES: usize = 10000;
+
+/// The seed of the generator of `permutation_test()`, fixed so that results
+/// are reproducible.
+const SEED: u64 = 0x64656e74;
+
+/// Test the null hypothesis that the labels of the values of two samples are
+/// exchangeable, so that any difference in their means is due to chance, by a
+/// Monte Carlo permutation test [1] of `resamples` random relabelings. It
+/// needs no assumption of the distribution of the data.
+///
+/// The p-value counts the observed labeling among the resamples, so it is
+/// never 0.
+///
+/// [1]: https://www.itl.nist.gov/div898/handbook/eda/section3/eda35i.htm
+pub fn permutation_test(s1: &Summarizer, s2: &Summarizer, resamples: usize)
+                        -> Result<Permutation, Error> {
+    if resamples == 0 {
+        return Err(Error::Undefined);
+    }
+
+    let mut pooled = [s1.as_slice(), s2.as_slice()].concat();
+    let n1 = s1.as_slice().len();
+    let n2 = pooled.len() - n1;
+    let total: f64 = pooled.iter().sum();
+
+    // The difference of means of a labeling depends only on the sum of the
+    // first sample.
+    let difference_of = |sum1: f64| (total - sum1) / n2 as f64 - sum1 / n1 as f64;
+    let difference = difference_of(s1.as_slice().iter().sum());
+
+    // Allow for rounding in the sums of labelings with the same difference.
+    let threshold = difference.abs() * (1.0 - 1e-12);
+
+    let mut rng = Rng::new(SEED);
+    let mut extreme = 0;
+    for _ in 0..resamples {
+        // A partial Fisher-Yates shuffle, to draw the first sample.
+        for i in 0..n1 {
+            let j = i + (rng.next_u64() % (pooled.len() - i) as u64) as usize;
+            pooled.swap(i, j);
+        }
+
+        let sum1: f64 = pooled[..n1].iter().sum();
+        if difference_of(sum1).abs() >= threshold {
+            extreme += 1;
+        }
+    }
+
+    let p = (extreme + 1) as f64 / (resamples + 1) as f64;
+
+    Ok(Permutation { difference, p, resamples })
+}
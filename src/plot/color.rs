@@ -0,0 +1,59 @@
+//! Colors of terminal and SVG output, shared by plots and the results of tests
+//! so that they agree.
+
+use term::color::{self, Color};
+
+
+/// Terminal colors used to tell datasets apart, in order of use, with CSS
+/// colors approximating them, for non-terminal renderers.
+const PALETTE: [(Color, &str); 6] = [
+    (color::BLUE, "#1f77b4"),
+    (color::MAGENTA, "#9467bd"),
+    (color::CYAN, "#17becf"),
+    (color::YELLOW, "#bcbd22"),
+    (color::GREEN, "#2ca02c"),
+    (color::RED, "#d62728"),
+];
+
+/// The color of the result of a test which is significant.
+pub const SIGNIFICANT: Color = color::GREEN;
+
+/// The color of the result of a test which is not significant.
+pub const NOT_SIGNIFICANT: Color = color::RED;
+
+const RESET: &str = "\x1b[0m";
+
+/// The CSS color for the `i`th dataset.
+pub fn css(i: usize) -> &'static str {
+    PALETTE[i % PALETTE.len()].1
+}
+
+/// Paint `s` in the terminal color `c`.
+pub fn paint(s: &str, c: Color) -> String {
+    format!("{}{}{}", foreground(c), s, RESET)
+}
+
+/// Paint the chars of `line` in the column range `[lo, hi)` with the palette
+/// color for the `i`th dataset. Colors are reused when datasets outnumber them.
+pub fn paint_span(line: &str, lo: usize, hi: usize, i: usize) -> String {
+    let c = PALETTE[i % PALETTE.len()].0;
+    let chars: Vec<char> = line.chars().collect();
+    let hi = hi.min(chars.len());
+    let lo = lo.min(hi);
+
+    let head: String = chars[..lo].iter().collect();
+    let span: String = chars[lo..hi].iter().collect();
+    let tail: String = chars[hi..].iter().collect();
+
+    format!("{}{}{}", head, paint(&span, c), tail)
+}
+
+/// The ANSI escape sequence setting the foreground to `c`, one of the 8
+/// colors of `term` or their bright variants.
+fn foreground(c: Color) -> String {
+    if c < 8 {
+        format!("\x1b[{}m", 30 + c)
+    } else {
+        format!("\x1b[{}m", 90 + c - 8)
+    }
+}
@@ -134,9 +134,9 @@ impl Border {
 }
 
 fn render_horizontal_line(c: &str, size: usize) -> String {
-    use std::iter::{FromIterator, repeat};
+    use std::iter::{FromIterator, repeat_n};
 
-    String::from_iter(repeat(c.to_string()).take(size))
+    String::from_iter(repeat_n(c.to_string(), size))
 }
 
 fn render_vertical_line(c: &str, size: usize) -> String {
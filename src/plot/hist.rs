@@ -0,0 +1,69 @@
+use histogram::Histogram;
+
+use super::{PlotOptions, axis_label};
+
+
+/// A bin of a histogram as drawn: labeled by its lower edge, with its count,
+/// and the length of its bar as a proportion of the longest.
+pub(super) struct Bar {
+    pub label: String,
+    pub count: usize,
+    pub length: f64,
+}
+
+/// The bars of `hist`. Bars are scaled by the density of each bin, which for
+/// bins of equal width is as its count, so that the densest bin has length 1.
+pub(super) fn bars(hist: &Histogram, precision: usize) -> Vec<Bar> {
+    let densities = hist.densities();
+    let max_density = densities.iter().cloned().fold(0.0, f64::max);
+
+    hist.edges()[..hist.bins()]
+        .iter()
+        .zip(hist.counts())
+        .zip(&densities)
+        .map(|((&edge, &count), &density)| Bar {
+            label: axis_label(edge, precision),
+            count,
+            length: if max_density == 0.0 { 0.0 } else { density / max_density },
+        })
+        .collect()
+}
+
+/// Render `hist` as a horizontal bar chart, one row per bin, each labeled with
+/// the bin's lower edge and count, so that the densest bin fills the width of
+/// the plot.
+pub fn render(hist: &Histogram, opts: &PlotOptions) -> Result<String, &'static str> {
+    let bar = if opts.ascii { "#" } else { "█" };
+    let rule = if opts.ascii { "|" } else { "│" };
+
+    let bars = bars(hist, opts.precision);
+    let label_width = bars.iter().map(|b| b.label.len()).max().unwrap_or(0);
+    let count_width = bars.iter().map(|b| b.count).max().unwrap_or(0).to_string().len();
+
+    // Label, space, rule, bar, space, count.
+    let fixed = label_width + 2 + 1 + count_width;
+    if opts.width <= fixed {
+        return Err("Plot width is too small");
+    }
+    let bar_width = opts.width - fixed;
+
+    let rows: Vec<String> = bars
+        .iter()
+        .map(|b| {
+            let len = (b.length * bar_width as f64).round() as usize;
+
+            format!(
+                "{l:>lw$} {r}{b:<bw$} {c:>cw$}",
+                l = b.label,
+                lw = label_width,
+                r = rule,
+                b = bar.repeat(len),
+                bw = bar_width,
+                c = b.count,
+                cw = count_width,
+            )
+        })
+        .collect();
+
+    Ok(rows.join("\n"))
+}
@@ -1,10 +1,4 @@
-mod figure;
-
-use stamp;
-
-use summary::Summary;
-
-
+// Defined first, so that it is in scope in the submodules.
 macro_rules! plot {
     ($p: expr) => {
         match $p {
@@ -14,6 +8,25 @@ macro_rules! plot {
     }
 }
 
+pub mod color;
+mod figure;
+mod hist;
+mod options;
+mod scatter;
+pub mod svg;
+
+pub use self::options::PlotOptions;
+
+use stamp;
+
+use std::io::{self, Write};
+
+use bland_altman::BlandAltman;
+use histogram::Histogram;
+use roc::Roc;
+use summary::Summary;
+
+
 struct Boxplot {
     box_lo: f64,
     box_mid: f64,
@@ -23,35 +36,64 @@ struct Boxplot {
     wh_hi: f64,
 }
 
+/// Map a sample value onto the plot axis, which is either linear or log10.
+fn scale(x: f64, log_scale: bool) -> f64 {
+    if log_scale { x.log10() } else { x }
+}
+
+/// The scaled extent of the boxplot of `summary`, from its lower to its upper
+/// whisker. Without outliers, this is widened as needed to include the mean.
+fn extent(summary: &Summary, outliers: bool, log_scale: bool) -> (f64, f64) {
+    let (lo, hi) = if outliers {
+        (summary.min(), summary.max())
+    } else {
+        (
+            summary.min_adjacent().min(summary.mean()),
+            summary.max_adjacent().max(summary.mean()),
+        )
+    };
+
+    (scale(lo, log_scale), scale(hi, log_scale))
+}
+
+/// The scaled extent of an axis shared by the boxplots of all `summaries`.
+fn shared_extent(summaries: &[&Summary], outliers: bool, log_scale: bool) -> (f64, f64) {
+    use std::f64;
+
+    summaries
+        .iter()
+        .map(|s| extent(s, outliers, log_scale))
+        .fold((f64::MAX, f64::MIN), |(lo, hi), (x, y)| (lo.min(x), hi.max(y)))
+}
+
 impl Boxplot {
-    fn from_summary(summary: &Summary) -> Self {
-        let range = summary.range();
-        let n = |x| (x - summary.min()) / range;
+    /// Positions of the boxplot of `summary`, as proportions of the scaled
+    /// axis interval `[min, max]`.
+    fn on_axis(summary: &Summary, min: f64, max: f64, outliers: bool, log_scale: bool) -> Self {
+        let range = max - min;
+        let n = |x| (scale(x, log_scale) - min) / range;
+
+        let (wh_lo, wh_hi) = if outliers {
+            (summary.min(), summary.max())
+        } else {
+            (summary.min_adjacent(), summary.max_adjacent())
+        };
 
         Boxplot {
             box_lo: n(summary.lower_quartile()),
             box_mid: n(summary.median()),
             box_hi: n(summary.upper_quartile()),
             marker: n(summary.mean()),
-            wh_lo: n(summary.min()),
-            wh_hi: n(summary.max()),
+            wh_lo: n(wh_lo),
+            wh_hi: n(wh_hi),
         }
     }
 
-    fn from_summary_no_outliers(summary: &Summary) -> Self {
-        let min = summary.min_adjacent().min(summary.mean());
-        let max = summary.max_adjacent().max(summary.mean());
-        let range = max - min;
-        let n = |x| (x - min) / range;
+    /// Positions of the boxplot of `summary`, relative to its own extent.
+    fn from_summary(summary: &Summary, outliers: bool, log_scale: bool) -> Self {
+        let (min, max) = extent(summary, outliers, log_scale);
 
-        Boxplot {
-            box_lo: n(summary.lower_quartile()),
-            box_mid: n(summary.median()),
-            box_hi: n(summary.upper_quartile()),
-            marker: n(summary.mean()),
-            wh_lo: n(summary.min_adjacent()),
-            wh_hi: n(summary.max_adjacent()),
-        }
+        Boxplot::on_axis(summary, min, max, outliers, log_scale)
     }
 }
 
@@ -93,26 +135,18 @@ struct RowChars {
 }
 
 impl RowChars {
-    pub fn render(&self, row: &mut Vec<String>, cols: &BoxplotCols) {
+    pub fn render(&self, row: &mut [String], cols: &BoxplotCols) {
         // Lower whisker extent.
-        for i in (cols.wh_lo + 1)..cols.box_lo {
-            row[i] = self.wh_lo_box_lo_fill.to_string();
-        }
+        fill(row, cols.wh_lo + 1, cols.box_lo, self.wh_lo_box_lo_fill);
 
         // Upper whisker extent.
-        for i in (cols.box_hi + 1)..cols.wh_hi {
-            row[i] = self.box_hi_wh_hi_fill.to_string();
-        }
+        fill(row, cols.box_hi + 1, cols.wh_hi, self.box_hi_wh_hi_fill);
 
         // Lower box extent.
-        for i in (cols.box_lo + 1)..cols.box_mid {
-            row[i] = self.box_lo_box_mid_fill.to_string();
-        }
+        fill(row, cols.box_lo + 1, cols.box_mid, self.box_lo_box_mid_fill);
 
         // Upper box extent.
-        for i in (cols.box_mid + 1)..cols.box_hi {
-            row[i] = self.box_mid_box_hi_fill.to_string();
-        }
+        fill(row, cols.box_mid + 1, cols.box_hi, self.box_mid_box_hi_fill);
 
         // Lower box end.
         row[cols.box_lo] = self.box_lo.to_string();
@@ -131,19 +165,23 @@ impl RowChars {
     }
 }
 
+/// Set the cells of `row` in the half-open column range `[lo, hi)` to `c`. An
+/// empty or inverted range leaves the row unchanged.
+fn fill(row: &mut [String], lo: usize, hi: usize, c: &str) {
+    for cell in row.iter_mut().take(hi).skip(lo) {
+        *cell = c.to_string();
+    }
+}
+
 struct BoxplotChars {
     marker: &'static str,
     rows: [RowChars; 3],
 }
 
 impl BoxplotChars {
-    pub fn render(&self, summary: &Summary, width: usize, outliers: bool)
+    pub fn render(&self, summary: &Summary, width: usize, outliers: bool, log_scale: bool)
                   -> Result<String, &'static str> {
-        let data = if outliers {
-            Boxplot::from_summary(summary)
-        } else {
-            Boxplot::from_summary_no_outliers(summary)
-        };
+        let data = Boxplot::from_summary(summary, outliers, log_scale);
         let cols = BoxplotCols::new(&data, width);
         let mut plot = Plot::new(width);
 
@@ -240,14 +278,17 @@ static UNICODE_CHARS: BoxplotChars = BoxplotChars {
 };
 
 fn make_row(width: usize) -> Vec<String> {
-    use std::iter::repeat;
+    use std::iter::repeat_n;
 
     let mut row = vec![];
-    row.extend(repeat(String::from(" ")).take(width));
+    row.extend(repeat_n(String::from(" "), width));
 
     row
 }
 
+/// Number of rows in a rendered boxplot.
+const BOXPLOT_HEIGHT: usize = 3;
+
 struct Plot(Vec<String>, Vec<String>, Vec<String>);
 
 impl Plot {
@@ -256,7 +297,7 @@ impl Plot {
     }
 
     fn render(&self) -> String {
-        let rows = vec![
+        let rows = [
             self.0.join(""),
             self.1.join(""),
             self.2.join(""),
@@ -266,53 +307,65 @@ impl Plot {
     }
 }
 
-pub fn summary_plot(summary: &Summary, width: usize, ascii: bool, outliers: bool)
-                    -> Result<String, &'static str> {
-    let plot_style = if ascii { &ASCII_CHARS } else { &UNICODE_CHARS };
+/// Log-scale plots are only defined for strictly positive sample data.
+fn check_log_scale(summary: &Summary, log_scale: bool) -> Result<(), &'static str> {
+    if log_scale && summary.min() <= 0.0 {
+        return Err("Log-scale plot requires positive sample data");
+    }
 
-    plot_style.render(summary, width, outliers)
+    Ok(())
 }
 
-pub fn comparison_plot(
-    summaries: &[&Summary],
-    width: usize,
-    ascii: bool,
-    border: bool,
-    outliers: bool,
-) -> Result<String, &'static str> {
+/// Render a single boxplot of `summary`, without a border or labels.
+/// Check that `summaries` can be plotted together with the given options.
+fn check_comparison(summaries: &[&Summary], opts: &PlotOptions) -> Result<(), &'static str> {
     if summaries.is_empty() {
         return Err("Cannot plot empty list of summaries");
     }
 
-    let padding = if border { 2 } else { 0 };
-    let content_width = (width - 2 * padding) as f64;
-    let border_style = if ascii {
-        figure::ASCII_BORDER
-    } else {
-        figure::UNICODE_BORDER
-    };
+    if !opts.labels.is_empty() && opts.labels.len() != summaries.len() {
+        return Err("Must provide one label per plotted summary");
+    }
 
-    use std::f64;
+    for s in summaries {
+        check_log_scale(s, opts.log_scale)?;
+    }
 
-    let plot_min = |s: &Summary| if outliers {
-        s.min()
-    } else {
-        s.min_adjacent().min(s.mean())
-    };
-    let min = summaries
-        .iter()
-        .map(|s| plot_min(s))
-        .fold(f64::MAX, |x, y| x.min(y));
+    Ok(())
+}
+
+pub fn summary_plot(summary: &Summary, opts: &PlotOptions) -> Result<String, &'static str> {
+    check_log_scale(summary, opts.log_scale)?;
+
+    let plot_style = if opts.ascii { &ASCII_CHARS } else { &UNICODE_CHARS };
+
+    plot_style.render(summary, opts.width, opts.outliers, opts.log_scale)
+}
+
+/// Render boxplots of each of `summaries` on a shared axis.
+pub fn comparison_plot(summaries: &[&Summary], opts: &PlotOptions)
+                       -> Result<String, &'static str> {
+    check_comparison(summaries, opts)?;
+
+    let outliers = opts.outliers;
+    let log_scale = opts.log_scale;
+    let width = opts.width;
+
+    let padding = if opts.border { 2 } else { 0 };
+    let gutter = label_gutter(&opts.labels, width);
 
-    let plot_max = |s: &Summary| if outliers {
-        s.max()
+    if width < 2 * padding + gutter + 1 {
+        return Err("Plot width is too small");
+    }
+
+    let content_width = (width - 2 * padding - gutter) as f64;
+    let border_style = if opts.ascii {
+        figure::ASCII_BORDER
     } else {
-        s.max_adjacent().max(s.mean())
+        figure::UNICODE_BORDER
     };
-    let max = summaries
-        .iter()
-        .map(|s| plot_max(s))
-        .fold(f64::MIN, |x, y| x.max(y));
+
+    let (min, max) = shared_extent(summaries, outliers, log_scale);
 
     // Used to compute relative widths of boxplots from their own ranges.
     let range = max - min;
@@ -320,16 +373,7 @@ pub fn comparison_plot(
     let mut plots = vec![];
 
     for s in summaries {
-        let s_min = if outliers {
-            s.min()
-        } else {
-            s.min_adjacent().min(s.mean())
-        };
-        let s_max = if outliers {
-            s.max()
-        } else {
-            s.max_adjacent().max(s.mean())
-        };
+        let (s_min, s_max) = extent(s, outliers, log_scale);
 
         // Proportion of total content width spanned by this plot.
         let p = (s_max - s_min) / range;
@@ -339,7 +383,8 @@ pub fn comparison_plot(
         assert!(1.0 <= w);
         assert!(w <= content_width);
 
-        let plot = plot!(stamp::Stamp::new(&summary_plot(s, w as usize, ascii, outliers)?))?;
+        let box_opts = opts.clone().width(w as usize);
+        let plot = plot!(stamp::Stamp::new(&summary_plot(s, &box_opts)?))?;
 
         assert!(min <= s_min);
         let offset_p = (s_min - min) / range;
@@ -347,15 +392,15 @@ pub fn comparison_plot(
         let offset = (offset_p * content_width).min(content_width - w);
         assert!(offset + w <= content_width);
 
-        plots.push((plot, padding + (offset as usize)));
+        plots.push((plot, padding + gutter + (offset as usize)));
     }
 
-    let height = &plots
+    let height = plots
         .iter()
-        .map(|&(ref p, _)| p.height())
-        .sum() + (padding * 2);
+        .map(|(p, _)| p.height())
+        .sum::<usize>() + (padding * 2);
 
-    let base = if border {
+    let base = if opts.border {
         figure::Border::new(border_style, width, height).render()
     } else {
         figure::Filled::blank(width, height).render()
@@ -364,8 +409,145 @@ pub fn comparison_plot(
     let mut all_plots = plot!(stamp::Stamp::new(&base))?;
 
     for (i, &(ref plot, left_offset)) in plots.iter().enumerate() {
-        all_plots = plot!(all_plots.layer(&plot, left_offset, padding + i * plot.height()))?;
+        all_plots = plot!(all_plots.layer(plot, left_offset, padding + i * plot.height()))?;
+    }
+
+    for (i, label) in opts.labels.iter().enumerate() {
+        let label = truncate(label, gutter - 1);
+        if label.is_empty() {
+            continue;
+        }
+
+        // Align each label with the middle row of its boxplot.
+        let row = padding + i * BOXPLOT_HEIGHT + 1;
+        let stamp = plot!(stamp::Stamp::new(&label))?;
+        all_plots = plot!(all_plots.layer(&stamp, padding, row))?;
+    }
+
+    let rendered = all_plots.render();
+
+    if !opts.color {
+        return Ok(rendered);
+    }
+
+    // Color each boxplot's rows, leaving the border and padding unpainted.
+    let lines: Vec<String> = rendered
+        .lines()
+        .enumerate()
+        .map(|(r, line)| {
+            let i = r.saturating_sub(padding) / BOXPLOT_HEIGHT;
+
+            if padding <= r && i < plots.len() {
+                color::paint_span(line, padding, width - padding, i)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+
+    Ok(lines.join("\n"))
+}
+
+/// Render a Bland-Altman plot: the difference of each pair of measurements
+/// against their mean, with rules at the bias and the limits of agreement.
+/// The plot is `opts.height` rows tall, excluding the border.
+pub fn bland_altman_plot(ba: &BlandAltman, opts: &PlotOptions) -> Result<String, &'static str> {
+    let rules = [ba.lower_limit(), ba.bias(), ba.upper_limit()];
+
+    scatter::render(ba.points(), &rules, opts)
+}
+
+/// Render a histogram as a horizontal bar chart, one row per bin.
+pub fn histogram_plot(hist: &Histogram, opts: &PlotOptions) -> Result<String, &'static str> {
+    hist::render(hist, opts)
+}
+
+/// Render a scatter plot of `points`.
+/// The plot is `opts.height` rows tall, excluding the border.
+pub fn scatter_plot(points: &[(f64, f64)], opts: &PlotOptions) -> Result<String, &'static str> {
+    scatter::render(points, &[], opts)
+}
+
+/// Render an ROC curve, the true positive rate against the false positive
+/// rate, with each step of the curve filled in.
+/// The plot is `opts.height` rows tall, excluding the border.
+pub fn roc_plot(roc: &Roc, opts: &PlotOptions) -> Result<String, &'static str> {
+    // Enough points along each segment to leave no gap between cells.
+    let steps = opts.width.max(opts.height);
+    let points: Vec<(f64, f64)> = roc.points
+        .windows(2)
+        .flat_map(|w| {
+            let (a, b) = (&w[0], &w[1]);
+            (0..=steps).map(move |i| {
+                let t = i as f64 / steps as f64;
+                (a.false_positive_rate + t * (b.false_positive_rate - a.false_positive_rate),
+                 a.true_positive_rate + t * (b.true_positive_rate - a.true_positive_rate))
+            })
+        })
+        .collect();
+
+    scatter::render(&points, &[], opts)
+}
+
+/// Like `summary_plot`, but write the plot and a trailing newline to `w`.
+pub fn summary_plot_to<W: Write>(w: &mut W, summary: &Summary, opts: &PlotOptions)
+                                 -> io::Result<()> {
+    let plot = summary_plot(summary, opts).map_err(invalid_input)?;
+
+    writeln!(w, "{}", plot)
+}
+
+/// Like `comparison_plot`, but write the plot and a trailing newline to `w`.
+pub fn comparison_plot_to<W: Write>(w: &mut W, summaries: &[&Summary], opts: &PlotOptions)
+                                    -> io::Result<()> {
+    let plot = comparison_plot(summaries, opts).map_err(invalid_input)?;
+
+    writeln!(w, "{}", plot)
+}
+
+fn invalid_input(err: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, err)
+}
+
+/// Width of the label column of a comparison plot, including a separating
+/// space. Labels may use at most a quarter of the plot width.
+fn label_gutter(labels: &[String], width: usize) -> usize {
+    let longest = labels
+        .iter()
+        .map(|l| l.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    if longest == 0 {
+        return 0;
+    }
+
+    longest.min(width / 4).max(1) + 1
+}
+
+/// Truncate `s` to at most `len` chars, marking any truncation with a `~`.
+fn truncate(s: &str, len: usize) -> String {
+    if s.chars().count() <= len {
+        return s.to_string();
     }
 
-    Ok(all_plots.render())
+    let mut t: String = s.chars().take(len.saturating_sub(1)).collect();
+    t.push('~');
+
+    t
+}
+
+/// Format an axis value with about `precision` significant digits.
+fn axis_label(v: f64, precision: usize) -> String {
+    if v == 0.0 {
+        return "0".to_string();
+    }
+
+    let digits = precision as i32 - 1 - v.abs().log10().floor() as i32;
+
+    if (0..10).contains(&digits) {
+        format!("{:.*}", digits as usize, v)
+    } else {
+        format!("{:.*e}", precision - 1, v)
+    }
 }
@@ -0,0 +1,103 @@
+/// Options for rendering plots.
+///
+/// Construct with `PlotOptions::default()` (or `new()`) and adjust with the
+/// chained setters, so that new options can be added without breaking callers:
+///
+/// ```
+/// use dent::plot::PlotOptions;
+///
+/// let opts = PlotOptions::new().width(72).ascii(true).log_scale(true);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PlotOptions {
+    pub(super) ascii: bool,
+    pub(super) border: bool,
+    pub(super) color: bool,
+    pub(super) height: usize,
+    pub(super) labels: Vec<String>,
+    pub(super) log_scale: bool,
+    pub(super) outliers: bool,
+    pub(super) precision: usize,
+    pub(super) width: usize,
+}
+
+impl Default for PlotOptions {
+    /// An 80-column, bordered, Unicode plot on a linear scale, without color,
+    /// labels, or outliers. Scatter plots are 16 rows tall, and axis labels
+    /// have 4 significant digits.
+    fn default() -> Self {
+        PlotOptions {
+            ascii: false,
+            border: true,
+            color: false,
+            height: 16,
+            labels: vec![],
+            log_scale: false,
+            outliers: false,
+            precision: 4,
+            width: 80,
+        }
+    }
+}
+
+impl PlotOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Use only ASCII characters.
+    pub fn ascii(mut self, ascii: bool) -> Self {
+        self.ascii = ascii;
+        self
+    }
+
+    /// Draw a border around comparison plots.
+    pub fn border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Paint each dataset in a distinct ANSI color.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Height of scatter plots, in rows, excluding any border. Boxplots have a
+    /// fixed height per dataset.
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Labels for the datasets of a comparison plot, in order. When present,
+    /// they are drawn in a column to the left of the boxplots.
+    pub fn labels<S: AsRef<str>>(mut self, labels: &[S]) -> Self {
+        self.labels = labels.iter().map(|l| l.as_ref().to_string()).collect();
+        self
+    }
+
+    /// Plot on a log10 scale. Requires positive sample data.
+    pub fn log_scale(mut self, log_scale: bool) -> Self {
+        self.log_scale = log_scale;
+        self
+    }
+
+    /// Include outliers, and use the min and max as the outer fences.
+    pub fn outliers(mut self, outliers: bool) -> Self {
+        self.outliers = outliers;
+        self
+    }
+
+    /// Significant digits of axis labels.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision.max(1);
+        self
+    }
+
+    /// Total width of the plot, in columns.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+}
@@ -0,0 +1,116 @@
+use stamp;
+
+use super::PlotOptions;
+use super::figure;
+
+
+struct ScatterChars {
+    point: &'static str,
+    rule: &'static str,
+}
+
+static ASCII_CHARS: ScatterChars = ScatterChars {
+    point: "*",
+    rule: "-",
+};
+
+static UNICODE_CHARS: ScatterChars = ScatterChars {
+    point: "•",
+    rule: "┄",
+};
+
+/// The interval `(lo, hi)` spanned by an axis.
+pub(super) type Interval = (f64, f64);
+
+/// The position of `x` on the interval `[lo, hi]`, as a proportion of it. A
+/// degenerate interval maps everything to the middle.
+pub(super) fn proportion(x: f64, lo: f64, hi: f64) -> f64 {
+    if hi <= lo {
+        return 0.5;
+    }
+
+    (x - lo) / (hi - lo)
+}
+
+/// Index of the cell among `n` that contains `x` on the interval `[lo, hi]`.
+fn to_cell(x: f64, lo: f64, hi: f64, n: usize) -> usize {
+    ((proportion(x, lo, hi) * (n - 1) as f64).round() as usize).min(n - 1)
+}
+
+/// The x and y intervals of a scatter plot of `points`, with the y interval
+/// including each of the y values in `rules`.
+pub(super) fn axes(points: &[(f64, f64)], rules: &[f64])
+                   -> Result<(Interval, Interval), &'static str> {
+    use std::f64;
+
+    if points.is_empty() {
+        return Err("Cannot plot empty list of points");
+    }
+
+    if points.iter().any(|&(x, y)| !x.is_finite() || !y.is_finite()) {
+        return Err("Cannot plot non-finite points");
+    }
+
+    let x = points
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(lo, hi), &(x, _)| (lo.min(x), hi.max(x)));
+    let y = points
+        .iter()
+        .map(|&(_, y)| y)
+        .chain(rules.iter().cloned())
+        .fold((f64::MAX, f64::MIN), |(lo, hi), y| (lo.min(y), hi.max(y)));
+
+    Ok((x, y))
+}
+
+/// Render a scatter plot of `points`, with horizontal rules drawn at each of
+/// the y values in `rules`. Points are drawn over rules.
+pub fn render(points: &[(f64, f64)], rules: &[f64], opts: &PlotOptions)
+              -> Result<String, &'static str> {
+    let ((x_lo, x_hi), (y_lo, y_hi)) = axes(points, rules)?;
+
+    let padding = if opts.border { 1 } else { 0 };
+
+    if opts.width < 2 * padding + 1 || opts.height == 0 {
+        return Err("Plot size is too small");
+    }
+
+    let cols = opts.width - 2 * padding;
+    let rows = opts.height;
+    let chars = if opts.ascii { &ASCII_CHARS } else { &UNICODE_CHARS };
+
+    // Row 0 is the top of the plot, so flip the y axis.
+    let row = |y| rows - 1 - to_cell(y, y_lo, y_hi, rows);
+
+    let mut grid = vec![vec![" "; cols]; rows];
+
+    for &y in rules {
+        for cell in grid[row(y)].iter_mut() {
+            *cell = chars.rule;
+        }
+    }
+
+    for &(x, y) in points {
+        grid[row(y)][to_cell(x, x_lo, x_hi, cols)] = chars.point;
+    }
+
+    let content: Vec<String> = grid.iter().map(|r| r.concat()).collect();
+    let content = content.join("\n");
+
+    if !opts.border {
+        return Ok(content);
+    }
+
+    let border_style = if opts.ascii {
+        figure::ASCII_BORDER
+    } else {
+        figure::UNICODE_BORDER
+    };
+    let base = figure::Border::new(border_style, opts.width, rows + 2).render();
+
+    let base = plot!(stamp::Stamp::new(&base))?;
+    let content = plot!(stamp::Stamp::new(&content))?;
+    let layered = plot!(base.layer(&content, 1, 1))?;
+
+    Ok(layered.render())
+}
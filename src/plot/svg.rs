@@ -0,0 +1,351 @@
+//! Render plots as standalone SVG documents.
+//!
+//! Plots share their layout with the terminal renderers: an SVG plot and a
+//! text plot made with the same `PlotOptions` agree on the placement of every
+//! boxplot, the bins and bar lengths of a histogram, and the axes of a scatter
+//! plot. The `ascii` and `color` options are ignored: each dataset is always
+//! drawn in its own color.
+
+use std::io::{self, Write};
+
+use bland_altman::BlandAltman;
+use histogram::Histogram;
+use summary::Summary;
+
+use super::{Boxplot, PlotOptions, axis_label, check_comparison, color, hist, invalid_input,
+            scatter, shared_extent};
+
+
+/// Pixels per column of plot width.
+const COL_PX: f64 = 8.0;
+
+/// Pixels per row of scatter plot height.
+const LINE_PX: f64 = 16.0;
+
+/// Approximate width of a label character, in pixels.
+const CHAR_PX: f64 = 7.0;
+
+const MARGIN_PX: f64 = 20.0;
+const ROW_PX: f64 = 40.0;
+const BOX_PX: f64 = 20.0;
+const BAR_ROW_PX: f64 = 20.0;
+const BAR_PX: f64 = 14.0;
+const AXIS_PX: f64 = 30.0;
+const MARKER_PX: f64 = 4.0;
+const POINT_PX: f64 = 2.5;
+const TICKS: usize = 5;
+
+/// Render boxplots of each of `summaries` on a shared axis, as SVG.
+pub fn comparison_plot(summaries: &[&Summary], opts: &PlotOptions)
+                       -> Result<String, &'static str> {
+    check_comparison(summaries, opts)?;
+
+    let (min, max) = shared_extent(summaries, opts.outliers, opts.log_scale);
+
+    let longest = opts.labels.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+    let gutter = if longest == 0 { 0.0 } else { longest as f64 * CHAR_PX + 10.0 };
+
+    let width = opts.width as f64 * COL_PX;
+    let height = 2.0 * MARGIN_PX + summaries.len() as f64 * ROW_PX + AXIS_PX;
+    let left = MARGIN_PX + gutter;
+    let right = width - MARGIN_PX;
+
+    if right <= left {
+        return Err("Plot width is too small");
+    }
+
+    let x = |p: f64| left + p * (right - left);
+
+    let mut svg = open(width, height, opts.border);
+
+    for (i, s) in summaries.iter().enumerate() {
+        let b = Boxplot::on_axis(s, min, max, opts.outliers, opts.log_scale);
+        let cy = MARGIN_PX + (i as f64 + 0.5) * ROW_PX;
+        let top = cy - BOX_PX / 2.0;
+        let cap = BOX_PX / 4.0;
+
+        svg += &format!("<g stroke=\"{}\" fill=\"none\">\n", color::css(i));
+        svg += &line(x(b.wh_lo), cy, x(b.box_lo), cy);
+        svg += &line(x(b.box_hi), cy, x(b.wh_hi), cy);
+        svg += &line(x(b.wh_lo), cy - cap, x(b.wh_lo), cy + cap);
+        svg += &line(x(b.wh_hi), cy - cap, x(b.wh_hi), cy + cap);
+        svg += &format!(
+            "  <rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\"/>\n",
+            x(b.box_lo),
+            top,
+            x(b.box_hi) - x(b.box_lo),
+            BOX_PX,
+        );
+        svg += &line(x(b.box_mid), top, x(b.box_mid), top + BOX_PX);
+        svg += &line(x(b.marker) - MARKER_PX, cy - MARKER_PX, x(b.marker) + MARKER_PX, cy + MARKER_PX);
+        svg += &line(x(b.marker) - MARKER_PX, cy + MARKER_PX, x(b.marker) + MARKER_PX, cy - MARKER_PX);
+        svg += "</g>\n";
+
+        if let Some(label) = opts.labels.get(i) {
+            svg += &format!(
+                "<text x=\"{:.1}\" y=\"{:.1}\" dominant-baseline=\"middle\" fill=\"{}\">{}</text>\n",
+                MARGIN_PX,
+                cy,
+                color::css(i),
+                escape(label),
+            );
+        }
+    }
+
+    // Axis, with evenly spaced ticks labeled in the units of the sample data.
+    let axis_y = MARGIN_PX + summaries.len() as f64 * ROW_PX + 5.0;
+    let value = |v: f64| if opts.log_scale { 10f64.powf(v) } else { v };
+    svg += &x_axis(left, right, axis_y, (min, max), value, opts.precision);
+
+    svg += "</svg>\n";
+
+    Ok(svg)
+}
+
+/// Render a histogram as a horizontal bar chart, one bar per bin, as SVG.
+pub fn histogram_plot(hist: &Histogram, opts: &PlotOptions) -> Result<String, &'static str> {
+    let bars = hist::bars(hist, opts.precision);
+
+    let longest = bars.iter().map(|b| b.label.len()).max().unwrap_or(0);
+    let gutter = longest as f64 * CHAR_PX + 10.0;
+    let count_width = bars.iter().map(|b| b.count).max().unwrap_or(0).to_string().len();
+
+    let width = opts.width as f64 * COL_PX;
+    let height = 2.0 * MARGIN_PX + bars.len() as f64 * BAR_ROW_PX;
+    let left = MARGIN_PX + gutter;
+    let right = width - MARGIN_PX - count_width as f64 * CHAR_PX - 10.0;
+
+    if right <= left {
+        return Err("Plot width is too small");
+    }
+
+    let mut svg = open(width, height, opts.border);
+
+    svg += &format!("<g fill=\"{}\">\n", color::css(0));
+    for (i, b) in bars.iter().enumerate() {
+        let top = MARGIN_PX + i as f64 * BAR_ROW_PX + (BAR_ROW_PX - BAR_PX) / 2.0;
+
+        svg += &format!(
+            "  <rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\"/>\n",
+            left,
+            top,
+            b.length * (right - left),
+            BAR_PX,
+        );
+    }
+    svg += "</g>\n";
+
+    for (i, b) in bars.iter().enumerate() {
+        let cy = MARGIN_PX + (i as f64 + 0.5) * BAR_ROW_PX;
+
+        svg += &format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" dominant-baseline=\"middle\" \
+             text-anchor=\"end\">{}</text>\n",
+            left - 5.0,
+            cy,
+            escape(&b.label),
+        );
+        svg += &format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" dominant-baseline=\"middle\">{}</text>\n",
+            left + b.length * (right - left) + 5.0,
+            cy,
+            b.count,
+        );
+    }
+
+    svg += "<g stroke=\"black\">\n";
+    svg += &line(left, MARGIN_PX, left, height - MARGIN_PX);
+    svg += "</g>\n";
+
+    svg += "</svg>\n";
+
+    Ok(svg)
+}
+
+/// Render a scatter plot of `points`, as SVG. The plot area is as tall as
+/// `opts.height` rows of a text plot.
+pub fn scatter_plot(points: &[(f64, f64)], opts: &PlotOptions) -> Result<String, &'static str> {
+    render_scatter(points, &[], opts)
+}
+
+/// Render a Bland-Altman plot, as `plot::bland_altman_plot` does, as SVG.
+pub fn bland_altman_plot(ba: &BlandAltman, opts: &PlotOptions) -> Result<String, &'static str> {
+    let rules = [ba.lower_limit(), ba.bias(), ba.upper_limit()];
+
+    render_scatter(ba.points(), &rules, opts)
+}
+
+/// Render a scatter plot of `points`, with dashed horizontal rules at each of
+/// the y values in `rules`, and axes labeled in the units of the data.
+fn render_scatter(points: &[(f64, f64)], rules: &[f64], opts: &PlotOptions)
+                  -> Result<String, &'static str> {
+    let ((x_lo, x_hi), (y_lo, y_hi)) = scatter::axes(points, rules)?;
+
+    if opts.height == 0 {
+        return Err("Plot size is too small");
+    }
+
+    let longest = ticks(y_lo, y_hi)
+        .map(|v| axis_label(v, opts.precision).len())
+        .max()
+        .unwrap_or(0);
+    let gutter = longest as f64 * CHAR_PX + 10.0;
+
+    let width = opts.width as f64 * COL_PX;
+    let left = MARGIN_PX + gutter;
+    let right = width - MARGIN_PX;
+    let top = MARGIN_PX;
+    let bottom = top + opts.height as f64 * LINE_PX;
+    let height = bottom + AXIS_PX + MARGIN_PX;
+
+    if right <= left {
+        return Err("Plot width is too small");
+    }
+
+    let x = |v| left + scatter::proportion(v, x_lo, x_hi) * (right - left);
+    // The top of the plot area is the greatest y.
+    let y = |v| bottom - scatter::proportion(v, y_lo, y_hi) * (bottom - top);
+
+    let mut svg = open(width, height, opts.border);
+
+    if !rules.is_empty() {
+        svg += "<g stroke=\"gray\" stroke-dasharray=\"4 4\">\n";
+        for &r in rules {
+            svg += &line(left, y(r), right, y(r));
+        }
+        svg += "</g>\n";
+    }
+
+    svg += &format!("<g fill=\"{}\">\n", color::css(0));
+    for &(px, py) in points {
+        svg += &format!(
+            "  <circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"{}\"/>\n",
+            x(px),
+            y(py),
+            POINT_PX,
+        );
+    }
+    svg += "</g>\n";
+
+    svg += &x_axis(left, right, bottom + 5.0, (x_lo, x_hi), |v| v, opts.precision);
+
+    svg += "<g stroke=\"black\">\n";
+    svg += &line(left - 5.0, top, left - 5.0, bottom);
+    for v in ticks(y_lo, y_hi) {
+        svg += &line(left - 10.0, y(v), left - 5.0, y(v));
+    }
+    svg += "</g>\n";
+
+    for v in ticks(y_lo, y_hi) {
+        svg += &format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" dominant-baseline=\"middle\" \
+             text-anchor=\"end\">{}</text>\n",
+            left - 12.0,
+            y(v),
+            axis_label(v, opts.precision),
+        );
+    }
+
+    svg += "</svg>\n";
+
+    Ok(svg)
+}
+
+/// Like `comparison_plot`, but write the SVG document to `w`.
+pub fn comparison_plot_to<W: Write>(w: &mut W, summaries: &[&Summary], opts: &PlotOptions)
+                                    -> io::Result<()> {
+    let svg = comparison_plot(summaries, opts).map_err(invalid_input)?;
+
+    w.write_all(svg.as_bytes())
+}
+
+/// Like `histogram_plot`, but write the SVG document to `w`.
+pub fn histogram_plot_to<W: Write>(w: &mut W, hist: &Histogram, opts: &PlotOptions)
+                                   -> io::Result<()> {
+    let svg = histogram_plot(hist, opts).map_err(invalid_input)?;
+
+    w.write_all(svg.as_bytes())
+}
+
+/// Like `scatter_plot`, but write the SVG document to `w`.
+pub fn scatter_plot_to<W: Write>(w: &mut W, points: &[(f64, f64)], opts: &PlotOptions)
+                                 -> io::Result<()> {
+    let svg = scatter_plot(points, opts).map_err(invalid_input)?;
+
+    w.write_all(svg.as_bytes())
+}
+
+/// Like `bland_altman_plot`, but write the SVG document to `w`.
+pub fn bland_altman_plot_to<W: Write>(w: &mut W, ba: &BlandAltman, opts: &PlotOptions)
+                                      -> io::Result<()> {
+    let svg = bland_altman_plot(ba, opts).map_err(invalid_input)?;
+
+    w.write_all(svg.as_bytes())
+}
+
+/// The opening tag of a `width` by `height` document, with any border.
+fn open(width: f64, height: f64, border: bool) -> String {
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" \
+         viewBox=\"0 0 {w} {h}\" font-family=\"monospace\" font-size=\"12\">\n",
+        w = width,
+        h = height,
+    );
+
+    if border {
+        svg += &format!(
+            "<rect x=\"0.5\" y=\"0.5\" width=\"{}\" height=\"{}\" fill=\"white\" stroke=\"black\"/>\n",
+            width - 1.0,
+            height - 1.0,
+        );
+    }
+
+    svg
+}
+
+/// `TICKS` evenly spaced values from `lo` to `hi`.
+fn ticks(lo: f64, hi: f64) -> impl Iterator<Item = f64> {
+    (0..TICKS).map(move |t| lo + t as f64 / (TICKS - 1) as f64 * (hi - lo))
+}
+
+/// A horizontal axis from `left` to `right` at `y`, spanning the interval
+/// `(lo, hi)`, with evenly spaced ticks labeled by `value` of each point.
+fn x_axis<F>(left: f64, right: f64, y: f64, (lo, hi): (f64, f64), value: F, precision: usize)
+             -> String
+    where F: Fn(f64) -> f64
+{
+    let x = |t: usize| left + t as f64 / (TICKS - 1) as f64 * (right - left);
+
+    let mut svg = String::new();
+    svg += "<g stroke=\"black\">\n";
+    svg += &line(left, y, right, y);
+    for t in 0..TICKS {
+        svg += &line(x(t), y, x(t), y + 5.0);
+    }
+    svg += "</g>\n";
+
+    for (t, v) in ticks(lo, hi).enumerate() {
+        svg += &format!(
+            "<text x=\"{:.1}\" y=\"{:.1}\" text-anchor=\"middle\">{}</text>\n",
+            x(t),
+            y + 20.0,
+            axis_label(value(v), precision),
+        );
+    }
+
+    svg
+}
+
+fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> String {
+    format!(
+        "  <line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\"/>\n",
+        x1, y1, x2, y2,
+    )
+}
+
+/// Escape text for inclusion in XML or HTML character data.
+pub fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
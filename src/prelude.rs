@@ -0,0 +1,7 @@
+//! The names which the `std` prelude would provide, and the floating point
+//! functions of `std`, for the crate without it.
+
+pub use alloc::string::{String, ToString};
+pub use alloc::vec::Vec;
+
+pub use num::Real;
@@ -0,0 +1,147 @@
+use std::io::{self, IsTerminal, Read, Write};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::{Duration, Instant};
+
+
+/// When to report progress reading inputs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Mode {
+    /// When stderr is a terminal, once reading an input takes a while.
+    Auto,
+    /// Always, from the start of each input.
+    Always,
+}
+
+static MODE: AtomicU8 = AtomicU8::new(Mode::Auto as u8);
+
+/// How long to read quietly in `Auto` mode, to not flicker for small inputs.
+const QUIET: Duration = Duration::from_secs(1);
+
+/// How often to update the report.
+const TICK: Duration = Duration::from_millis(200);
+
+pub fn set_mode(mode: Mode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+fn mode() -> Mode {
+    if MODE.load(Ordering::Relaxed) == Mode::Always as u8 { Mode::Always } else { Mode::Auto }
+}
+
+/// Wraps a reader of an input, reporting the bytes read from it on stderr, and
+/// what fraction of it they are if its size is known.
+pub struct Progress<R> {
+    inner: R,
+    name: String,
+    size: Option<u64>,
+    read: u64,
+    start: Instant,
+    last: Option<Instant>,
+    enabled: bool,
+    terminal: bool,
+}
+
+impl<R: Read> Progress<R> {
+    pub fn new(inner: R, name: &str, size: Option<u64>) -> Self {
+        let terminal = io::stderr().is_terminal();
+
+        Progress {
+            inner,
+            name: name.to_string(),
+            size,
+            read: 0,
+            start: Instant::now(),
+            last: None,
+            enabled: terminal || mode() == Mode::Always,
+            terminal,
+        }
+    }
+
+    fn tick(&mut self) {
+        let now = Instant::now();
+
+        if mode() == Mode::Auto && now - self.start < QUIET {
+            return;
+        }
+        if self.last.is_some_and(|last| now - last < TICK) {
+            return;
+        }
+
+        self.last = Some(now);
+        self.report();
+    }
+
+    fn report(&self) {
+        let status = match self.size {
+            Some(size) if size > 0 => {
+                let pct = (100 * self.read / size).min(100);
+                format!("{} of {} ({}%)", bytes(self.read), bytes(size), pct)
+            },
+            _ => bytes(self.read),
+        };
+
+        let mut stderr = io::stderr();
+        let _ = write!(stderr, "\rdent: reading {}: {}", self.name, status);
+        let _ = stderr.flush();
+    }
+
+    /// Finish the report at the end of the input, erasing it on a terminal.
+    fn finish(&mut self) {
+        if self.last.is_none() && mode() == Mode::Always {
+            self.report();
+            self.last = Some(Instant::now());
+        }
+
+        if self.last.is_some() {
+            if self.terminal {
+                eprint!("\r\x1b[K");
+            } else {
+                eprintln!();
+            }
+        }
+
+        self.enabled = false;
+    }
+
+    /// Count `n` more bytes of the input as read, with 0 marking its end. For
+    /// inputs read other than through `Read`, such as mapped files.
+    pub fn advance(&mut self, n: usize) {
+        if self.enabled {
+            self.read += n as u64;
+
+            if n == 0 {
+                self.finish();
+            } else {
+                self.tick();
+            }
+        }
+    }
+}
+
+impl<R: Read> Read for Progress<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+
+        self.advance(n);
+
+        Ok(n)
+    }
+}
+
+/// Format a number of bytes with a decimal SI prefix, such as `1.2 GB`.
+fn bytes(n: u64) -> String {
+    const PREFIXES: &[&str] = &["k", "M", "G", "T", "P"];
+
+    if n < 1000 {
+        return format!("{} B", n);
+    }
+
+    let mut x = n as f64 / 1000.0;
+    let mut i = 0;
+    while x >= 1000.0 && i + 1 < PREFIXES.len() {
+        x /= 1000.0;
+        i += 1;
+    }
+
+    format!("{:.1} {}B", x, PREFIXES[i])
+}
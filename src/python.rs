@@ -0,0 +1,175 @@
+//! Python bindings of summaries, Welch's t-test, and linear regression, by
+//! PyO3, with the `python` feature.
+//!
+//! Build the extension module as a `cdylib`, and install it as `dent.so` on
+//! the Python path:
+//!
+//! ```sh
+//! cargo rustc --lib --release --features python,pyo3/extension-module \
+//!     --crate-type cdylib
+//! cp target/release/libdent.so dent.so
+//! ```
+//!
+//! Errors are raised as a `ValueError` with the message `dent` prints.
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use error::Error;
+use lr;
+use summary;
+use t_test;
+
+
+impl From<Error> for PyErr {
+    fn from(e: Error) -> Self {
+        PyValueError::new_err(e.to_string())
+    }
+}
+
+/// The summary statistics of a sample, as `summary::Summary`.
+#[pyclass(name = "Summary", module = "dent", frozen)]
+pub struct Summary(summary::Summary);
+
+#[pymethods]
+impl Summary {
+    /// Summarize `data`, a sequence of numbers.
+    #[new]
+    pub fn new(data: Vec<f64>) -> PyResult<Self> {
+        Ok(Summary(summary::Summary::new(&data)?))
+    }
+
+    #[getter]
+    pub fn size(&self) -> f64 {
+        self.0.size()
+    }
+
+    #[getter]
+    pub fn min(&self) -> f64 {
+        self.0.min()
+    }
+
+    #[getter]
+    pub fn max(&self) -> f64 {
+        self.0.max()
+    }
+
+    #[getter]
+    pub fn range(&self) -> f64 {
+        self.0.range()
+    }
+
+    #[getter]
+    pub fn mean(&self) -> f64 {
+        self.0.mean()
+    }
+
+    #[getter]
+    pub fn median(&self) -> f64 {
+        self.0.median()
+    }
+
+    #[getter]
+    pub fn lower_quartile(&self) -> f64 {
+        self.0.lower_quartile()
+    }
+
+    #[getter]
+    pub fn upper_quartile(&self) -> f64 {
+        self.0.upper_quartile()
+    }
+
+    #[getter]
+    pub fn iqr(&self) -> f64 {
+        self.0.iqr()
+    }
+
+    #[getter]
+    pub fn standard_deviation(&self) -> f64 {
+        self.0.standard_deviation()
+    }
+
+    #[getter]
+    pub fn standard_error(&self) -> f64 {
+        self.0.standard_error()
+    }
+
+    #[getter]
+    pub fn unbiased_variance(&self) -> f64 {
+        self.0.unbiased_variance()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// The results of a t-test, as `t_test::TTest`.
+#[pyclass(name = "TTest", module = "dent", frozen, get_all)]
+pub struct TTest {
+    pub p: f64,
+    pub t: f64,
+    pub df: f64,
+}
+
+#[pymethods]
+impl TTest {
+    fn __repr__(&self) -> String {
+        format!("TTest(p={}, t={}, df={})", self.p, self.t, self.df)
+    }
+}
+
+/// Welch's t-test of two summaries, as `t_test::welch_t_test()`.
+#[pyfunction]
+pub fn welch_t_test(s1: &Summary, s2: &Summary) -> PyResult<TTest> {
+    let t = t_test::welch_t_test(&s1.0, &s2.0)?;
+
+    Ok(TTest { p: t.p, t: t.t, df: t.df })
+}
+
+/// A simple linear regression, as `lr::LinearRegression`.
+#[pyclass(name = "LinearRegression", module = "dent", frozen)]
+pub struct LinearRegression(lr::LinearRegression);
+
+#[pymethods]
+impl LinearRegression {
+    /// Fit `data`, a sequence of `(x, y)` pairs, to a linear model.
+    #[new]
+    pub fn new(data: Vec<(f64, f64)>) -> PyResult<Self> {
+        Ok(LinearRegression(lr::LinearRegression::new(&data)?))
+    }
+
+    #[getter]
+    pub fn intercept(&self) -> f64 {
+        self.0.intercept()
+    }
+
+    #[getter]
+    pub fn slope(&self) -> f64 {
+        self.0.slope()
+    }
+
+    #[getter]
+    pub fn r(&self) -> f64 {
+        self.0.r()
+    }
+
+    #[getter]
+    pub fn standard_error(&self) -> f64 {
+        self.0.standard_error()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+/// The `dent` Python module.
+#[pymodule]
+fn dent(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_class::<Summary>()?;
+    m.add_class::<TTest>()?;
+    m.add_class::<LinearRegression>()?;
+    m.add_function(wrap_pyfunction!(python::welch_t_test, m)?)?;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use dist::Distribution;
+use error::Error;
+
+
+/// A small, fast pseudorandom number generator, the xoshiro256** generator of
+/// Blackman and Vigna [1], for simulation rather than cryptography.
+///
+/// The sequence of a seed is fixed, so that samples can be reproduced across
+/// runs and versions.
+///
+/// [1]: http://prng.di.unimi.it/
+#[derive(Clone, Debug)]
+pub struct Rng {
+    s: [u64; 4],
+}
+
+impl Rng {
+    /// A generator whose state is expanded from `seed` by SplitMix64, as its
+    /// authors recommend.
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut next = || {
+            x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        };
+
+        Rng { s: [next(), next(), next(), next()] }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        result
+    }
+
+    /// A value uniformly distributed on the open interval (0, 1), taking the
+    /// top 53 bits of the next output, so neither 0 nor 1 is ever returned.
+    pub fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 0.5) * (-53f64).exp2()
+    }
+
+    /// A value drawn from `dist`, by inverting its CDF.
+    pub fn sample<D: Distribution + ?Sized>(&mut self, dist: &D) -> Result<f64, Error> {
+        let p = self.next_f64();
+
+        dist.quantile(p)
+    }
+}
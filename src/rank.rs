@@ -0,0 +1,43 @@
+/// The rank of each value of `data`, in the order of `data`, from 1 for the
+/// least. Tied values share the mean of the ranks they span, so the ranks of
+/// `n` values always sum to `n(n + 1)/2`.
+pub fn ranks(data: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..data.len()).collect();
+    order.sort_by(|&i, &j| data[i].total_cmp(&data[j]));
+
+    let mut ranks = vec![0.0; data.len()];
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = i + 1;
+        while j < order.len() && data[order[j]] == data[order[i]] {
+            j += 1;
+        }
+
+        // The values at `i..j` of the order are tied for ranks `i + 1` to `j`.
+        let rank = (i + j + 1) as f64 / 2.0;
+        for &k in &order[i..j] {
+            ranks[k] = rank;
+        }
+
+        i = j;
+    }
+
+    ranks
+}
+
+/// Rank the values of several samples together, as rank-based tests do, as
+/// though they were one pooled sample. The ranks of each sample are returned
+/// in the order of its values.
+pub fn joint_ranks(samples: &[&[f64]]) -> Vec<Vec<f64>> {
+    let ranks = ranks(&samples.concat());
+    let mut ranks = ranks.as_slice();
+
+    samples
+        .iter()
+        .map(|s| {
+            let (sample, rest) = ranks.split_at(s.len());
+            ranks = rest;
+            sample.to_vec()
+        })
+        .collect()
+}
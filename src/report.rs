@@ -0,0 +1,82 @@
+use dent::fmt;
+use dent::plot::{self, PlotOptions};
+use dent::plot::svg::escape;
+use dent::summary::Summary;
+use dent::t_test::TTest;
+
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; margin-bottom: 1em; }
+th, td { border: 1px solid #ccc; padding: 0.25em 0.75em; }
+td.num { text-align: right; font-family: monospace; }
+";
+
+/// Width of formatted numbers in report tables.
+const NUM_WIDTH: usize = 10;
+
+/// Render a self-contained HTML report of the summaries of each of `sources`,
+/// with an embedded SVG boxplot and the results of a t-test, if any.
+pub fn html(
+    sources: &[&str],
+    summaries: &[Summary],
+    t_test: Option<&TTest>,
+    opts: &PlotOptions,
+) -> Result<String, &'static str> {
+    let summary_refs: Vec<&Summary> = summaries.iter().collect();
+    let svg = plot::svg::comparison_plot(&summary_refs, &opts.clone().labels(sources))?;
+
+    let mut s = String::new();
+
+    s += "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n";
+    s += "<title>dent report</title>\n";
+    s += &format!("<style>\n{}</style>\n", STYLE);
+    s += "</head>\n<body>\n<h1>dent report</h1>\n";
+
+    s += "<h2>Boxplots</h2>\n";
+    s += &svg;
+
+    s += "<h2>Summary</h2>\n<table>\n<tr>";
+    for h in &["Source", "Size", "Min", "Q1", "Median", "Q3", "Max", "Mean", "Std Dev", "Std Err"] {
+        s += &format!("<th>{}</th>", h);
+    }
+    s += "</tr>\n";
+
+    for (src, summ) in sources.iter().zip(summaries) {
+        let values = [
+            summ.size(),
+            summ.min(),
+            summ.lower_quartile(),
+            summ.median(),
+            summ.upper_quartile(),
+            summ.max(),
+            summ.mean(),
+            summ.standard_deviation(),
+            summ.standard_error(),
+        ];
+
+        s += &format!("<tr><td>{}</td>", escape(src));
+        for v in &values {
+            s += &num_cell(*v);
+        }
+        s += "</tr>\n";
+    }
+    s += "</table>\n";
+
+    if let (Some(t), [s1, s2]) = (t_test, summaries) {
+        s += "<h2>Welch's t-test</h2>\n<table>\n";
+        s += &format!("<tr><th>m₂ - m₁</th>{}</tr>\n", num_cell(s2.mean() - s1.mean()));
+        s += &format!("<tr><th>t</th>{}</tr>\n", num_cell(t.t));
+        s += &format!("<tr><th>DF</th>{}</tr>\n", num_cell(t.df));
+        s += &format!("<tr><th>p</th>{}</tr>\n", num_cell(t.p));
+        s += "</table>\n";
+    }
+
+    s += "</body>\n</html>\n";
+
+    Ok(s)
+}
+
+fn num_cell(x: f64) -> String {
+    format!("<td class=\"num\">{}</td>", fmt::f(x, NUM_WIDTH))
+}
@@ -0,0 +1,158 @@
+use std;
+
+use dist::{Distribution, Normal};
+use error::Error;
+use fmt;
+use rank::{joint_ranks, ranks};
+
+
+/// A point of an ROC curve: the rates at which scores of at least `threshold`
+/// flag negative and positive subjects.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RocPoint {
+    pub threshold: f64,
+    pub false_positive_rate: f64,
+    pub true_positive_rate: f64,
+}
+
+/// The receiver operating characteristic of a score which detects positive
+/// subjects, with the area under its curve.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Roc {
+    /// The curve, from `(0, 0)`, at an infinite threshold, to `(1, 1)`, with
+    /// a point for each distinct score, in decreasing order of score.
+    pub points: Vec<RocPoint>,
+
+    /// The area under the curve: the probability that a positive subject
+    /// scores higher than a negative one, counting ties as one half.
+    pub auc: f64,
+
+    /// DeLong's standard error of `auc`, and the bounds of its confidence
+    /// interval at level `confidence`, within 0 and 1.
+    pub standard_error: f64,
+    pub lower: f64,
+    pub upper: f64,
+    pub confidence: f64,
+
+    pub positives: usize,
+    pub negatives: usize,
+}
+
+/// Formats the results as `dent` prints them, one per line. A precision, as
+/// in `{:.3}`, gives the number of significant digits of each.
+impl std::fmt::Display for Roc {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+        let ci = format!("{} CI", fmt::level(self.confidence));
+
+        writeln!(f, "{:>w$} = {}", "AUC", fmt::stat(self.auc, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "SE", fmt::stat(self.standard_error, p), w = w)?;
+        writeln!(f, "{:>w$} = {} to {}", ci, fmt::stat(self.lower, p), fmt::stat(self.upper, p),
+                 w = w)?;
+        writeln!(f, "{:>w$} = {}", "Positives", self.positives, w = w)?;
+        writeln!(f, "{:>w$} = {}", "Negatives", self.negatives, w = w)
+    }
+}
+
+impl Roc {
+    /// Evaluate a detector from the score it gave each subject, with whether
+    /// the subject is truly positive. Higher scores are taken to mean
+    /// positive. There must be at least two of both positives and negatives.
+    ///
+    /// The confidence interval of the area, at level `confidence`, is that of
+    /// DeLong, DeLong, and Clarke-Pearson (1988) [1], which makes no
+    /// assumption about the distributions of the scores.
+    ///
+    /// [1]: https://doi.org/10.2307/2531595
+    pub fn new(scores: &[(f64, bool)], confidence: f64) -> Result<Roc, Error> {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            return Err(Error::Undefined);
+        }
+        if scores.is_empty() {
+            return Err(Error::EmptySample);
+        }
+        if scores.iter().any(|&(s, _)| !s.is_finite()) {
+            return Err(Error::NonFinite);
+        }
+
+        let pos: Vec<f64> = scores.iter().filter(|&&(_, l)| l).map(|&(s, _)| s).collect();
+        let neg: Vec<f64> = scores.iter().filter(|&&(_, l)| !l).map(|&(s, _)| s).collect();
+        let (m, n) = (pos.len() as f64, neg.len() as f64);
+
+        if pos.len() < 2 || neg.len() < 2 {
+            return Err(Error::Undefined);
+        }
+
+        // The joint rank of a value, less its rank among its own sample, is
+        // the number of values of the other sample below it, counting ties as
+        // one half. These give the components of DeLong's variance: for each
+        // positive, the fraction of negatives it outscores, and for each
+        // negative, the fraction of positives which outscore it.
+        let joint = joint_ranks(&[&pos, &neg]);
+        let v10: Vec<f64> = joint[0]
+            .iter()
+            .zip(ranks(&pos))
+            .map(|(r, own)| (r - own) / n)
+            .collect();
+        let v01: Vec<f64> = joint[1]
+            .iter()
+            .zip(ranks(&neg))
+            .map(|(r, own)| (m - (r - own)) / m)
+            .collect();
+
+        let auc = v10.iter().sum::<f64>() / m;
+        let var = |v: &[f64]| {
+            v.iter().map(|x| (x - auc).powi(2)).sum::<f64>() / (v.len() - 1) as f64
+        };
+        let standard_error = (var(&v10) / m + var(&v01) / n).sqrt();
+
+        let z = Normal::standard().quantile(0.5 + confidence / 2.0)?;
+        let lower = (auc - z * standard_error).max(0.0);
+        let upper = (auc + z * standard_error).min(1.0);
+
+        Ok(Roc {
+            points: curve(scores, m, n),
+            auc,
+            standard_error,
+            lower,
+            upper,
+            confidence,
+            positives: pos.len(),
+            negatives: neg.len(),
+        })
+    }
+}
+
+/// The points of the ROC curve of `scores`, with `m` positives and `n`
+/// negatives, lowering the threshold through each distinct score.
+fn curve(scores: &[(f64, bool)], m: f64, n: f64) -> Vec<RocPoint> {
+    let mut sorted = scores.to_vec();
+    sorted.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut points = vec![RocPoint {
+        threshold: f64::INFINITY,
+        false_positive_rate: 0.0,
+        true_positive_rate: 0.0,
+    }];
+    let (mut tp, mut fp) = (0.0, 0.0);
+
+    for (i, &(s, l)) in sorted.iter().enumerate() {
+        if l { tp += 1.0 } else { fp += 1.0 }
+
+        // Subjects with equal scores are flagged together.
+        if sorted.get(i + 1).is_some_and(|next| next.0 == s) {
+            continue;
+        }
+
+        points.push(RocPoint {
+            threshold: s,
+            false_positive_rate: fp / n,
+            true_positive_rate: tp / m,
+        });
+    }
+
+    points
+}
@@ -0,0 +1,51 @@
+use std::error;
+use std::process::{Command, Stdio};
+use std::time::Instant;
+
+
+/// Run a shell command `runs` times, after `warmup` untimed runs, and return
+/// the wall-clock time of each timed run, in seconds. Its output is discarded,
+/// and it failing is an error.
+pub fn time(command: &str, runs: usize, warmup: usize) -> Result<Vec<f64>, Box<dyn error::Error>> {
+    for _ in 0..warmup {
+        run(command)?;
+    }
+
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            run(command)?;
+
+            Ok(start.elapsed().as_secs_f64())
+        })
+        .collect()
+}
+
+fn run(command: &str) -> Result<(), Box<dyn error::Error>> {
+    let status = shell(command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|e| format!("Could not run {:?}: {}", command, e))?;
+
+    if !status.success() {
+        return Err(format!("Command {:?} failed: {}", command, status).into());
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+fn shell(command: &str) -> Command {
+    let mut c = Command::new("cmd");
+    c.arg("/C").arg(command);
+    c
+}
+
+#[cfg(not(windows))]
+fn shell(command: &str) -> Command {
+    let mut c = Command::new("sh");
+    c.arg("-c").arg(command);
+    c
+}
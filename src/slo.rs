@@ -0,0 +1,74 @@
+use field::Field;
+use unit::{self, Unit};
+
+
+/// How a percentile must compare to the bound of a `Target`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Comparison {
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+/// A service level objective bounding a percentile, such as `p99<250ms`.
+#[derive(Clone, Debug)]
+pub struct Target {
+    /// The target as written.
+    pub spec: String,
+
+    /// The percentile, as a fraction in [0, 1].
+    pub percentile: f64,
+    pub comparison: Comparison,
+
+    /// The bound, in the base unit of its dimension if it has a unit suffix.
+    pub bound: f64,
+    pub unit: Option<Unit>,
+}
+
+impl Target {
+    /// Parse a target written as a percentile, a comparison, `<`, `<=`, `>`,
+    /// or `>=`, and a bound, which may have a unit suffix, as in `p99<250`,
+    /// `p50<=1.5ms`, or `p1>1000`.
+    pub fn parse(s: &str) -> Result<Target, String> {
+        let invalid = || format!("Invalid target {:?}; expected a percentile, a comparison, and \
+                                  a bound, such as p99<250", s);
+
+        let i = s.find(['<', '>']).ok_or_else(invalid)?;
+        let (name, rest) = (s[..i].trim(), &s[i..]);
+
+        let (comparison, bound) = [
+            ("<=", Comparison::LessEqual),
+            (">=", Comparison::GreaterEqual),
+            ("<", Comparison::Less),
+            (">", Comparison::Greater),
+        ]
+            .iter()
+            .find_map(|&(op, c)| rest.strip_prefix(op).map(|b| (c, b.trim())))
+            .ok_or_else(invalid)?;
+
+        let percentile = match Field::parse_list(name) {
+            Ok(ref fields) if fields.len() == 1 => match fields[0] {
+                Field::Percentile(p) => p,
+                _ => return Err(invalid()),
+            },
+            _ => return Err(invalid()),
+        };
+        let (bound, unit) = match unit::parse(bound) {
+            Ok((x, unit)) if x.is_finite() => (x, unit),
+            _ => return Err(invalid()),
+        };
+
+        Ok(Target { spec: s.to_string(), percentile, comparison, bound, unit })
+    }
+
+    /// Whether a value of the percentile meets the target.
+    pub fn meets(&self, x: f64) -> bool {
+        match self.comparison {
+            Comparison::Less => x < self.bound,
+            Comparison::LessEqual => x <= self.bound,
+            Comparison::Greater => x > self.bound,
+            Comparison::GreaterEqual => x >= self.bound,
+        }
+    }
+}
@@ -1,4 +1,10 @@
+use std;
+
 use error::Error;
+use fmt;
+use num;
+use t_test::t_critical;
+#[cfg(not(feature = "std"))] use prelude::*;
 
 
 /// Wraps a sorted `Vec` of sample data and provides methods for computing
@@ -19,16 +25,24 @@ impl Summarizer {
     ///   - The data are sorted
     ///
     pub fn new(data: &[f64]) -> Result<Self, Error> {
+        Summarizer::from_vec(Vec::from(data))
+    }
+
+    /// Construct a `Summarizer` as `new()` does, from a slice of 32-bit
+    /// floating point numbers, which are kept as `f64`.
+    pub fn from_f32(data: &[f32]) -> Result<Self, Error> {
+        Summarizer::from_vec(data.iter().map(|&x| f64::from(x)).collect())
+    }
+
+    fn from_vec(mut data: Vec<f64>) -> Result<Self, Error> {
         if data.is_empty() {
             return Err(Error::EmptySample);
         }
 
         if data.iter().any(|x| !x.is_finite()) {
-            return Err(Error::BadSample);
+            return Err(Error::NonFinite);
         }
 
-        let mut data = Vec::from(data);
-
         // Won't panic: we have checked that each float is finite.
         data.sort_by(|a, b| a.partial_cmp(b).unwrap_or_else(|| unreachable!()));
 
@@ -37,6 +51,21 @@ impl Summarizer {
         Ok(s)
     }
 
+    /// Merge several samples into one, as when each is a shard of the same
+    /// experiment rather than a separate group.
+    pub fn pool(samples: &[Summarizer]) -> Result<Self, Error> {
+        let mut data: Vec<f64> = samples.iter().flat_map(|s| s.data.iter().cloned()).collect();
+
+        if data.is_empty() {
+            return Err(Error::EmptySample);
+        }
+
+        // Won't panic: each sample holds only finite floats.
+        data.sort_by(|a, b| a.partial_cmp(b).unwrap_or_else(|| unreachable!()));
+
+        Ok(Summarizer { data })
+    }
+
     /// Get a shared reference to owned copy of sorted sample data.
     pub fn as_slice(&self) -> &[f64] {
         self.data.as_slice()
@@ -98,16 +127,72 @@ impl Summarizer {
         t / self.size()
     }
 
-    /// The 50th percentile.
-    pub fn median(&self) -> f64 {
-        let d = &self.data;
-        let n = d.len();
+    /// The geometric mean, defined only for positive sample data.
+    pub fn geometric_mean(&self) -> Result<f64, Error> {
+        if self.data[0] <= 0.0 {
+            return Err(Error::Undefined);
+        }
+
+        let t: f64 = self.data.iter().map(|x| x.ln()).sum();
+
+        Ok((t / self.size()).exp())
+    }
 
-        if n % 2 == 0 {
-            (d[(n / 2) - 1] + d[n / 2]) / 2.0
-        } else {
-            d[(n - 1) / 2]
+    /// The harmonic mean, defined only for positive sample data. Of rates of
+    /// equal amounts of work, it is the overall rate: the total work over the
+    /// total time.
+    pub fn harmonic_mean(&self) -> Result<f64, Error> {
+        if self.data[0] <= 0.0 {
+            return Err(Error::Undefined);
         }
+
+        let t: f64 = self.data.iter().map(|x| x.recip()).sum();
+
+        Ok(self.size() / t)
+    }
+
+    /// The reciprocals of positive sample data, such as the durations per unit
+    /// of work of rates. Their mean is the reciprocal of the harmonic mean.
+    pub fn reciprocals(&self) -> Result<Summarizer, Error> {
+        if self.data[0] <= 0.0 {
+            return Err(Error::Undefined);
+        }
+
+        Summarizer::from_vec(self.data.iter().map(|x| x.recip()).collect())
+    }
+
+    /// The interquartile mean, the mean of the middle half of the sorted data,
+    /// a robust estimate of location. When the size is not a multiple of 4,
+    /// the values at the quartiles count in part, so that the weights of the
+    /// values always sum to half the size [1].
+    ///
+    /// [1]: https://en.wikipedia.org/wiki/Interquartile_mean
+    pub fn interquartile_mean(&self) -> f64 {
+        sorted_interquartile_mean(&self.data)
+    }
+
+    /// The midhinge, the mean of the lower and upper quartiles, a robust
+    /// estimate of location.
+    pub fn midhinge(&self) -> f64 {
+        (self.lower_quartile() + self.upper_quartile()) / 2.0
+    }
+
+    /// The median absolute deviation from the median, unscaled. Multiply by
+    /// about 1.4826 for a robust estimate of the standard deviation of normally
+    /// distributed data.
+    pub fn median_absolute_deviation(&self) -> f64 {
+        let m = self.median();
+        let mut deviations: Vec<f64> = self.data.iter().map(|x| (x - m).abs()).collect();
+
+        // Won't panic: deviations of finite data are finite or infinite, never NaN.
+        deviations.sort_by(|a, b| a.partial_cmp(b).unwrap_or_else(|| unreachable!()));
+
+        sorted_median(&deviations)
+    }
+
+    /// The 50th percentile.
+    pub fn median(&self) -> f64 {
+        sorted_median(&self.data)
     }
 
     /// Closest-ranks percentile computed via linear interpolation.
@@ -119,7 +204,7 @@ impl Summarizer {
     /// boundary percentiles correspond to the sample min and max.
     pub fn percentile(&self, p: f64) -> Result<f64, Error> {
         if !p.is_finite() { return Err(Error::Undefined); }
-        if p < 0.0 || 1.0 < p {
+        if !(0.0..=1.0).contains(&p) {
             return Err(Error::Undefined);
         }
 
@@ -142,6 +227,59 @@ impl Summarizer {
         Ok(x)
     }
 
+    /// A distribution-free confidence interval of percentile `p` at `level`,
+    /// such as 0.95: a pair of order statistics between which the percentile
+    /// of the population lies with at least that probability, as the number
+    /// of values below it is binomial [1]. A bound is `None` if the sample is
+    /// too small to give it, as the upper bound of a high percentile of a
+    /// small sample is.
+    ///
+    /// [1]: https://doi.org/10.1002/9780470316771.ch5
+    pub fn percentile_interval(&self, p: f64, level: f64)
+                               -> Result<(Option<f64>, Option<f64>), Error> {
+        if !((0.0..=1.0).contains(&p) && 0.0 < level && level < 1.0) {
+            return Err(Error::Undefined);
+        }
+
+        let n = self.data.len();
+        let tail = (1.0 - level) / 2.0;
+
+        // The probability that at most `k` values lie below the percentile.
+        let cdf = |k: usize| -> Result<f64, Error> {
+            match k {
+                k if k >= n => Ok(1.0),
+                _ if p == 0.0 => Ok(1.0),
+                _ if p == 1.0 => Ok(0.0),
+                k => num::inc_beta(1.0 - p, (n - k) as f64, (k + 1) as f64),
+            }
+        };
+
+        // The least `k` whose `cdf(k)` meets `test`, which is `n` if none
+        // before it does.
+        let first = |test: &dyn Fn(f64) -> bool| -> Result<usize, Error> {
+            let (mut lo, mut hi) = (0, n);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                if test(cdf(mid)?) { hi = mid } else { lo = mid + 1 }
+            }
+            Ok(lo)
+        };
+
+        // The lower bound is the value of rank `k + 1` for the greatest `k`
+        // with `cdf(k) <= tail`, and the upper that of rank `k + 1` for the
+        // least with `cdf(k) >= 1 - tail`.
+        let lower = match first(&|c| c > tail)? {
+            0 => None,
+            k => Some(self.data[k - 1]),
+        };
+        let upper = match first(&|c| c >= 1.0 - tail)? {
+            k if k >= n => None,
+            k => Some(self.data[k]),
+        };
+
+        Ok((lower, upper))
+    }
+
     /// The difference between the minimum and maximum value.
     pub fn range(&self) -> f64 {
         self.max() - self.min()
@@ -176,14 +314,75 @@ impl Summarizer {
     pub fn standard_error(&self) -> f64 {
         self.standard_deviation() / self.size().sqrt()
     }
+
+    /// The confidence interval of the mean at `level`, such as 0.95, from the
+    /// t-distribution. It needs at least two values.
+    pub fn mean_ci(&self, level: f64) -> Result<(f64, f64), Error> {
+        mean_ci(self.mean(), self.standard_error(), self.size(), level)
+    }
+
+    /// The distribution-free confidence interval of the median at `level`,
+    /// as by `percentile_interval`, which is undefined if the sample is too
+    /// small to bound it, as one of fewer than six values is at 0.95.
+    pub fn median_ci(&self, level: f64) -> Result<(f64, f64), Error> {
+        match self.percentile_interval(0.5, level)? {
+            (Some(lower), Some(upper)) => Ok((lower, upper)),
+            _ => Err(Error::Undefined),
+        }
+    }
+}
+
+/// The confidence interval at `level` of a mean with standard error `se`, of
+/// a sample of size `n`.
+fn mean_ci(mean: f64, se: f64, n: f64, level: f64) -> Result<(f64, f64), Error> {
+    if n < 2.0 {
+        return Err(Error::Undefined);
+    }
+
+    let margin = t_critical(level, n - 1.0)? * se;
+
+    Ok((mean - margin, mean + margin))
+}
+
+/// The interquartile mean of non-empty, sorted data.
+fn sorted_interquartile_mean(d: &[f64]) -> f64 {
+    let n = d.len() as f64;
+    let (lower, upper) = (n / 4.0, 3.0 * n / 4.0);
+
+    // Each value `i` spans `[i, i + 1)`, weighted by its overlap with the
+    // middle half, `[n/4, 3n/4]`.
+    let t: f64 = d
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            let i = i as f64;
+            let w = (i + 1.0).min(upper) - i.max(lower);
+
+            w.max(0.0) * x
+        })
+        .sum();
+
+    t / (n / 2.0)
+}
+
+/// The median of non-empty, sorted data.
+fn sorted_median(d: &[f64]) -> f64 {
+    let n = d.len();
+
+    if n.is_multiple_of(2) {
+        (d[(n / 2) - 1] + d[n / 2]) / 2.0
+    } else {
+        d[(n - 1) / 2]
+    }
 }
 
 /// Like a static `Summarizer`, with all fields computed upon initialization.
 ///
-/// Does not retain a sorted copy of the sample data, and so cannot compute
-/// arbitrary percentiles. For descriptions of individual methods, see the
-/// `Summarizer` documentation.
+/// Unless constructed by `with_data()`, does not retain a sorted copy of the
+/// sample data, and so cannot compute arbitrary percentiles. For descriptions
+/// of individual methods, see the `Summarizer` documentation.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Summary {
     iqr: f64,
     len: usize,
@@ -199,6 +398,8 @@ pub struct Summary {
     standard_error: f64,
     unbiased_variance: f64,
     upper_quartile: f64,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    sorted: Option<Vec<f64>>,
 }
 
 impl Summary {
@@ -214,24 +415,67 @@ impl Summary {
     pub fn new(data: &[f64]) -> Result<Self, Error> {
         let s = Summarizer::new(data)?;
 
+        Ok(Summary::from(&s))
+    }
+
+    /// Construct a `Summary` as `new()` does, from a slice of 32-bit floating
+    /// point numbers. Statistics are computed in `f64`.
+    pub fn from_f32(data: &[f32]) -> Result<Self, Error> {
+        let s = Summarizer::from_f32(data)?;
+
+        Ok(Summary::from(&s))
+    }
+
+    /// Construct a `Summary` as `new()` does, retaining a sorted copy of the
+    /// sample data for statistics, plots, or resamples which need the values
+    /// themselves. The data are available from `sorted()`.
+    pub fn with_data(data: &[f64]) -> Result<Self, Error> {
+        let s = Summarizer::new(data)?;
+
+        Ok(Summary::from(s))
+    }
+
+    /// Restore a `Summary` of `size` values from statistics computed earlier,
+    /// such as those of a saved summary: the mean and unbiased variance, the
+    /// `(min, max)` and `(min_adjacent, max_adjacent)` values, and the
+    /// quartiles `(lower, median, upper)`. The range, IQR, standard deviation,
+    /// and standard error are computed from these.
+    pub fn from_parts(size: usize, mean: f64, unbiased_variance: f64, extremes: (f64, f64),
+                      adjacent: (f64, f64), quartiles: (f64, f64, f64))
+                      -> Result<Self, Error> {
+        if size == 0 {
+            return Err(Error::EmptySample);
+        }
+
+        let (min, max) = extremes;
+        let (min_adjacent, max_adjacent) = adjacent;
+        let (lower_quartile, median, upper_quartile) = quartiles;
+        let standard_deviation = unbiased_variance.sqrt();
+
         Ok(Summary {
-            iqr: s.iqr(),
-            len: s.data.len(),
-            lower_quartile: s.lower_quartile(),
-            min: s.min(),
-            min_adjacent: s.min_adjacent(),
-            max: s.max(),
-            max_adjacent: s.max_adjacent(),
-            mean: s.mean(),
-            median: s.median(),
-            range: s.range(),
-            upper_quartile: s.upper_quartile(),
-            unbiased_variance: s.unbiased_variance(),
-            standard_deviation: s.standard_deviation(),
-            standard_error: s.standard_error(),
+            iqr: upper_quartile - lower_quartile,
+            len: size,
+            lower_quartile,
+            min,
+            min_adjacent,
+            max,
+            max_adjacent,
+            mean,
+            median,
+            range: max - min,
+            standard_deviation,
+            standard_error: standard_deviation / (size as f64).sqrt(),
+            unbiased_variance,
+            upper_quartile,
+            sorted: None,
         })
     }
 
+    /// The sorted sample data, if retained by `with_data()`.
+    pub fn sorted(&self) -> Option<&[f64]> {
+        self.sorted.as_deref()
+    }
+
     pub fn size(&self) -> f64 {
         self.len as f64
     }
@@ -244,6 +488,16 @@ impl Summary {
         self.iqr
     }
 
+    /// The interquartile mean, if the sorted data were retained by
+    /// `with_data()`.
+    pub fn interquartile_mean(&self) -> Option<f64> {
+        self.sorted().map(sorted_interquartile_mean)
+    }
+
+    pub fn midhinge(&self) -> f64 {
+        (self.lower_quartile + self.upper_quartile) / 2.0
+    }
+
     pub fn lower_quartile(&self) -> f64 {
         self.lower_quartile
     }
@@ -287,4 +541,99 @@ impl Summary {
     pub fn standard_error(&self) -> f64 {
         self.standard_error
     }
+
+    pub fn mean_ci(&self, level: f64) -> Result<(f64, f64), Error> {
+        mean_ci(self.mean, self.standard_error, self.size(), level)
+    }
+}
+
+impl<'a> From<&'a Summarizer> for Summary {
+    fn from(s: &'a Summarizer) -> Self {
+        Summary {
+            iqr: s.iqr(),
+            len: s.data.len(),
+            lower_quartile: s.lower_quartile(),
+            min: s.min(),
+            min_adjacent: s.min_adjacent(),
+            max: s.max(),
+            max_adjacent: s.max_adjacent(),
+            mean: s.mean(),
+            median: s.median(),
+            range: s.range(),
+            upper_quartile: s.upper_quartile(),
+            unbiased_variance: s.unbiased_variance(),
+            standard_deviation: s.standard_deviation(),
+            standard_error: s.standard_error(),
+            sorted: None,
+        }
+    }
+}
+
+/// Keeps the sorted sample data of the `Summarizer`, as `with_data()` does.
+impl From<Summarizer> for Summary {
+    fn from(s: Summarizer) -> Self {
+        let mut summary = Summary::from(&s);
+        summary.sorted = Some(s.data);
+
+        summary
+    }
+}
+
+/// Titles of the columns of a summary table, and their minimum widths.
+const COLUMNS: &[(&str, usize)] = &[
+    ("Size", 6),
+    ("Min Adj", 10),
+    ("Q1", 10),
+    ("Median", 10),
+    ("Q3", 10),
+    ("Max Adj", 10),
+    ("Mean", 10),
+    ("Std Dev", 10),
+];
+
+/// Formats the summary table printed by `dent`. A precision, as in `{:.3}`,
+/// gives the number of significant digits of each value.
+impl std::fmt::Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let values = [
+            self.size(),
+            self.min_adjacent(),
+            self.lower_quartile(),
+            self.median(),
+            self.upper_quartile(),
+            self.max_adjacent(),
+            self.mean(),
+            self.standard_deviation(),
+        ];
+        let cells: Vec<String> = values
+            .iter()
+            .zip(COLUMNS)
+            .map(|(&x, &(_, width))| fmt::cell(x, width, f.precision()))
+            .collect();
+        let widths: Vec<usize> = cells
+            .iter()
+            .zip(COLUMNS)
+            .map(|(c, &(title, width))| width.max(title.len()).max(c.len()))
+            .collect();
+
+        let titles: Vec<String> = COLUMNS
+            .iter()
+            .zip(&widths)
+            .map(|(&(title, _), &w)| format!("{:>w$}", title, w = w))
+            .collect();
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(c, &w)| format!("{:>w$}", c, w = w))
+            .collect();
+
+        writeln!(f, "{}", titles.join("  "))?;
+        writeln!(f, "{}", cells.join("  "))
+    }
+}
+
+impl std::fmt::Display for Summarizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&Summary::from(self), f)
+    }
 }
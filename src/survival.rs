@@ -0,0 +1,249 @@
+use std;
+
+use dist::{ChiSquared, Distribution, Normal};
+use error::Error;
+use fmt;
+
+
+/// The Kaplan-Meier estimate at one distinct time of a sample.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SurvivalStep {
+    pub time: f64,
+
+    /// The number of subjects neither failed nor censored before `time`.
+    pub at_risk: usize,
+    pub events: usize,
+    pub censored: usize,
+
+    /// The estimated probability of surviving past `time`, with the bounds
+    /// of its confidence interval at the `confidence` of the estimate. The
+    /// bounds are NaN once the estimate reaches 0.
+    pub survival: f64,
+    pub lower: f64,
+    pub upper: f64,
+}
+
+/// The Kaplan-Meier estimate of the survival function of a sample of times to
+/// an event, such as failure, some of which may be censored: known only to
+/// exceed the time at which the subject was last observed.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KaplanMeier {
+    /// The estimate at each distinct time, in increasing order.
+    pub steps: Vec<SurvivalStep>,
+    pub size: usize,
+    pub events: usize,
+
+    /// The median survival time, with the bounds of its confidence interval,
+    /// each `None` if the survival curve, or that bound of its confidence
+    /// band, never falls to one half.
+    pub median: Option<f64>,
+    pub median_lower: Option<f64>,
+    pub median_upper: Option<f64>,
+
+    /// The level of the confidence intervals.
+    pub confidence: f64,
+}
+
+/// Formats the median survival as `dent` prints it, one value per line, with
+/// `NA` for values which the data do not reach. A precision, as in `{:.3}`,
+/// gives the number of significant digits of each.
+impl std::fmt::Display for KaplanMeier {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+        let na = |x: Option<f64>| x.map_or("NA".to_string(), |x| fmt::stat(x, p));
+        let ci = format!("{} CI", fmt::level(self.confidence));
+
+        writeln!(f, "{:>w$} = {}", "Size", self.size, w = w)?;
+        writeln!(f, "{:>w$} = {}", "Events", self.events, w = w)?;
+        writeln!(f, "{:>w$} = {}", "Median", na(self.median), w = w)?;
+        writeln!(f, "{:>w$} = {} to {}", ci, na(self.median_lower), na(self.median_upper), w = w)
+    }
+}
+
+impl KaplanMeier {
+    /// Estimate the survival function of `data`, each the time to an event
+    /// and whether the event was observed, rather than censored. Times must
+    /// not be negative.
+    ///
+    /// The confidence intervals, at level `confidence`, are of the log of the
+    /// survival, by Greenwood's formula, as with the default of R's `survfit`,
+    /// and those of the median are where the bounds of the intervals cross one
+    /// half [1].
+    ///
+    /// [1]: https://doi.org/10.2307/2530286
+    pub fn new(data: &[(f64, bool)], confidence: f64) -> Result<KaplanMeier, Error> {
+        if !(confidence > 0.0 && confidence < 1.0) {
+            return Err(Error::Undefined);
+        }
+        let mut data = check(data)?;
+        data.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let z = Normal::standard().quantile(0.5 + confidence / 2.0)?;
+        let mut steps: Vec<SurvivalStep> = vec![];
+        let mut at_risk = data.len();
+        let (mut survival, mut greenwood) = (1.0, 0.0);
+
+        for group in data.chunk_by(|a, b| a.0 == b.0) {
+            let events = group.iter().filter(|&&(_, e)| e).count();
+            let (n, d) = (at_risk as f64, events as f64);
+
+            survival *= 1.0 - d / n;
+            greenwood += if events < at_risk { d / (n * (n - d)) } else { f64::NAN };
+
+            let se = greenwood.sqrt();
+            // Not `min`, which would take the bound of 1 over NaN.
+            let upper = survival * (z * se).exp();
+            steps.push(SurvivalStep {
+                time: group[0].0,
+                at_risk,
+                events,
+                censored: group.len() - events,
+                survival,
+                lower: survival * (-z * se).exp(),
+                upper: if upper > 1.0 { 1.0 } else { upper },
+            });
+
+            at_risk -= group.len();
+        }
+
+        let crossing = |band: fn(&SurvivalStep) -> f64| {
+            let curve: Vec<(f64, f64)> = steps.iter().map(|s| (s.time, band(s))).collect();
+            half_crossing(&curve)
+        };
+
+        Ok(KaplanMeier {
+            size: data.len(),
+            events: data.iter().filter(|&&(_, e)| e).count(),
+            median: crossing(|s| s.survival),
+            median_lower: crossing(|s| s.lower),
+            median_upper: crossing(|s| s.upper),
+            steps,
+            confidence,
+        })
+    }
+
+    /// The estimated probability of surviving past time `t`.
+    pub fn survival(&self, t: f64) -> f64 {
+        self.steps.iter().take_while(|s| s.time <= t).last().map_or(1.0, |s| s.survival)
+    }
+}
+
+/// Copy `data`, checking that it is a nonempty sample of finite, nonnegative
+/// times.
+fn check(data: &[(f64, bool)]) -> Result<Vec<(f64, bool)>, Error> {
+    if data.is_empty() {
+        return Err(Error::EmptySample);
+    }
+    if data.iter().any(|&(t, _)| !t.is_finite()) {
+        return Err(Error::NonFinite);
+    }
+    if data.iter().any(|&(t, _)| t < 0.0) {
+        return Err(Error::BadSample);
+    }
+
+    Ok(data.to_vec())
+}
+
+/// The first time at which a decreasing curve of `(time, value)` falls to one
+/// half, ignoring NaN values. As in R, if the curve is one half until a later
+/// drop, it is the midpoint of the two times.
+fn half_crossing(curve: &[(f64, f64)]) -> Option<f64> {
+    let tolerance = f64::EPSILON.sqrt();
+    let mut rest = curve.iter().filter(|&&(_, y)| y < 0.5 + tolerance);
+
+    let &(t, y) = rest.next()?;
+    if (y - 0.5).abs() < tolerance {
+        if let Some(&(next, _)) = rest.find(|&&(_, z)| z < y) {
+            return Some((t + next) / 2.0);
+        }
+    }
+
+    Some(t)
+}
+
+/// The results of a log-rank test comparing the survival of two samples.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LogRank {
+    pub chi_squared: f64,
+    pub p: f64,
+
+    /// The number of events in each sample, and the number expected were the
+    /// survival functions of both the same.
+    pub observed: [f64; 2],
+    pub expected: [f64; 2],
+}
+
+/// Formats the results as `dent` prints them, one per line. A precision, as
+/// in `{:.3}`, gives the number of significant digits of each.
+impl std::fmt::Display for LogRank {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+
+        writeln!(f, "{:>w$} = {}", "Chi-squared", fmt::stat(self.chi_squared, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "p", fmt::stat(self.p, p), w = w)
+    }
+}
+
+/// Test the null hypothesis that two samples of times to an event, as taken
+/// by `KaplanMeier::new`, have the same survival function, by the log-rank
+/// test [1]. It is most powerful when the hazards of the samples are
+/// proportional.
+///
+/// [1]: https://en.wikipedia.org/wiki/Logrank_test
+pub fn log_rank(a: &[(f64, bool)], b: &[(f64, bool)]) -> Result<LogRank, Error> {
+    let a = check(a)?;
+    let b = check(b)?;
+
+    let mut pooled: Vec<(f64, bool, usize)> = a
+        .iter()
+        .map(|&(t, e)| (t, e, 0))
+        .chain(b.iter().map(|&(t, e)| (t, e, 1)))
+        .collect();
+    pooled.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+    let mut at_risk = [a.len() as f64, b.len() as f64];
+    let mut observed = [0.0; 2];
+    let mut expected = [0.0; 2];
+    let mut variance = 0.0;
+
+    for group in pooled.chunk_by(|x, y| x.0 == y.0) {
+        let mut events = [0.0; 2];
+        let mut leaving = [0.0; 2];
+        for &(_, e, i) in group {
+            leaving[i] += 1.0;
+            if e {
+                events[i] += 1.0;
+            }
+        }
+
+        let n = at_risk[0] + at_risk[1];
+        let d = events[0] + events[1];
+
+        if d > 0.0 {
+            for i in 0..2 {
+                observed[i] += events[i];
+                expected[i] += d * at_risk[i] / n;
+            }
+            if n > 1.0 {
+                variance += d * (at_risk[0] / n) * (at_risk[1] / n) * (n - d) / (n - 1.0);
+            }
+        }
+
+        at_risk[0] -= leaving[0];
+        at_risk[1] -= leaving[1];
+    }
+
+    if variance <= 0.0 {
+        return Err(Error::Undefined);
+    }
+
+    let chi_squared = (observed[0] - expected[0]).powi(2) / variance;
+    let p = ChiSquared::new(1.0)?.sf(chi_squared)?;
+
+    Ok(LogRank { chi_squared, p, observed, expected })
+}
@@ -1,16 +1,87 @@
+use std;
+
+use dist::{Distribution, StudentsT};
 use error::Error;
+use fmt;
+#[cfg(not(feature = "std"))] use prelude::*;
 use summary::Summary;
 
 
-/// The results and parameters of a two-sided, unequal-variances t-test.
+/// The results and parameters of a t-test, two-sided and assuming unequal
+/// variances unless noted otherwise.
+#[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TTest {
     pub p: f64,
     pub t: f64,
     pub df: f64,
 }
 
+/// Formats the test statistics as `dent` prints them, one per line. A
+/// precision, as in `{:.3}`, gives the number of significant digits of each.
+impl std::fmt::Display for TTest {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let w = fmt::LABEL_WIDTH;
+        let p = f.precision();
+
+        writeln!(f, "{:>w$} = {}", "p", fmt::stat(self.p, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "t", fmt::stat(self.t, p), w = w)?;
+        writeln!(f, "{:>w$} = {}", "DF", fmt::stat(self.df, p), w = w)
+    }
+}
+
+impl TTest {
+    /// The same test against `alternative`, given a two-sided test of two
+    /// samples, or of pairs, whose t statistic is positive when the first
+    /// tends to be greater, as for `welch_t_test`.
+    pub fn with_alternative(&self, alternative: Alternative) -> Result<TTest, Error> {
+        let (t, df) = (self.t, self.df);
+        let p = match alternative {
+            Alternative::TwoSided => return Ok(TTest { df, p: self.p, t }),
+            Alternative::Less => StudentsT::new(df)?.cdf(-t)?,
+            Alternative::Greater => StudentsT::new(df)?.cdf(t)?,
+        };
+
+        Ok(TTest { df, p, t })
+    }
+}
+
+/// The alternative hypothesis of a test of two samples, of how the mean of
+/// the second compares to that of the first. A one-sided alternative has all
+/// of the critical region of the test on its side, so it finds a difference in
+/// its direction with less evidence, and never one in the other.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Alternative {
+    /// The means differ.
+    TwoSided,
+    /// The mean of the second sample is less than that of the first.
+    Less,
+    /// The mean of the second sample is greater than that of the first.
+    Greater,
+}
+
+impl Alternative {
+    /// The name of the alternative, as given on the command line.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Alternative::TwoSided => "two-sided",
+            Alternative::Less => "less",
+            Alternative::Greater => "greater",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Alternative> {
+        match s {
+            "two-sided" => Some(Alternative::TwoSided),
+            "less" => Some(Alternative::Less),
+            "greater" => Some(Alternative::Greater),
+            _ => None,
+        }
+    }
+}
+
 fn t_test_2_sided(t: f64, df: f64) -> Result<TTest, Error> {
-    let p = 1.0 - t_atv(t.abs(), df as f64)?;
+    let p = 1.0 - StudentsT::new(df)?.a(t.abs())?;
 
     Ok(TTest { df, p, t })
 }
@@ -22,6 +93,93 @@ pub fn welch_t_test(s1: &Summary, s2: &Summary) -> Result<TTest, Error> {
     t_test_2_sided(t, df)
 }
 
+/// Conduct a two-sided Student's t-test, which assumes the populations have
+/// equal variances and pools the variances of the samples to estimate it.
+pub fn student_t_test(s1: &Summary, s2: &Summary) -> Result<TTest, Error> {
+    let df = s1.size() + s2.size() - 2.0;
+    if df < 1.0 {
+        return Err(Error::Undefined);
+    }
+
+    let se = pooled_sd(s1, s2) * (s1.size().recip() + s2.size().recip()).sqrt();
+    let t = (s1.mean() - s2.mean()) / se;
+
+    t_test_2_sided(t, df)
+}
+
+/// The pooled standard deviation of two samples: the square root of the mean
+/// of their variances, weighted by their degrees of freedom.
+pub fn pooled_sd(s1: &Summary, s2: &Summary) -> f64 {
+    // A sample of one value contributes no degrees of freedom, nor squares.
+    let ss = |s: &Summary| if s.size() < 2.0 { 0.0 } else { (s.size() - 1.0) * s.unbiased_variance() };
+
+    ((ss(s1) + ss(s2)) / (s1.size() + s2.size() - 2.0)).sqrt()
+}
+
+/// Conduct a one-sided, unequal-variances t-test of whether the mean of `new`
+/// exceeds that of `baseline` by more than a `margin`, a fraction of the mean
+/// of `baseline`, such as 0.05 for 5%. This tests for a regression when greater
+/// values are worse, as for run times.
+///
+/// As with `welch_t_test`, the t statistic is negative when `new` tends to be
+/// greater, here than the baseline scaled by `1 + margin`.
+pub fn regression_t_test(baseline: &Summary, new: &Summary, margin: f64) -> Result<TTest, Error> {
+    let k = 1.0 + margin;
+
+    let n1 = baseline.size();
+    let var1 = k.powi(2) * baseline.unbiased_variance();
+    let n2 = new.size();
+    let var2 = new.unbiased_variance();
+
+    let t = (k * baseline.mean() - new.mean()) / ((var1 / n1) + (var2 / n2)).sqrt();
+    let df = welch_satterthwaite_df(var1, n1, var2, n2);
+
+    // The probability of a t statistic at most `t`.
+    let p = StudentsT::new(df)?.cdf(t)?;
+
+    Ok(TTest { df, p, t })
+}
+
+/// Conduct a two-sided paired t-test of measurements `(a, b)` of the same
+/// subjects, which tests whether the mean of the differences is zero.
+///
+/// As with `welch_t_test`, the t statistic is positive when the `a` values
+/// tend to be greater.
+pub fn paired_t_test(data: &[(f64, f64)]) -> Result<TTest, Error> {
+    let diffs: Vec<f64> = data.iter().map(|&(a, b)| a - b).collect();
+
+    paired_t_test_of(&diffs)
+}
+
+/// Conduct a paired t-test as `paired_t_test` does, of pairs of 32-bit
+/// floating point numbers, whose differences are taken in `f64`.
+pub fn paired_t_test_f32(data: &[(f32, f32)]) -> Result<TTest, Error> {
+    let diffs: Vec<f64> = data.iter().map(|&(a, b)| f64::from(a) - f64::from(b)).collect();
+
+    paired_t_test_of(&diffs)
+}
+
+fn paired_t_test_of(diffs: &[f64]) -> Result<TTest, Error> {
+    let s = Summary::new(diffs)?;
+
+    if s.size() < 2.0 {
+        return Err(Error::Undefined);
+    }
+
+    let t = s.mean() / s.standard_error();
+
+    t_test_2_sided(t, s.size() - 1.0)
+}
+
+/// Pair the `i`th values of `a` and `b`, as for `paired_t_test`.
+pub fn pairs(a: &[f64], b: &[f64]) -> Result<Vec<(f64, f64)>, Error> {
+    if a.len() != b.len() {
+        return Err(Error::MismatchedLengths(a.len(), b.len()));
+    }
+
+    Ok(a.iter().cloned().zip(b.iter().cloned()).collect())
+}
+
 fn welch_t_statistic(s1: &Summary, s2: &Summary) -> (f64, f64) {
     let n1 = s1.size();
     let m1 = s1.mean();
@@ -42,30 +200,18 @@ fn welch_t_statistic(s1: &Summary, s2: &Summary) -> (f64, f64) {
 /// Degrees of freedom, approximated using the Welch-Satterthwaite equation [1].
 ///
 /// [1]: http://www.itl.nist.gov/div898/handbook/mpc/section5/mpc571.htm
-fn welch_satterthwaite_df(var1: f64, n1: f64, var2: f64, n2: f64) -> f64 {
+pub(crate) fn welch_satterthwaite_df(var1: f64, n1: f64, var2: f64, n2: f64) -> f64 {
     let df1 = n1 - 1.0;
     let df2 = n2 - 1.0;
 
     let num = ((var1 / n1) + (var2 / n2)).powi(2);
     let den = var1.powi(2) / (n1.powi(2) * df1) + var2.powi(2) / (n2.powi(2) * df2);
-    let appx = num / den;
 
-    appx
+    num / den
 }
 
-/// The definite integral of the density function of Student's t-distribution
-/// over an interval [-t, t]. Also called the A(t|ν) function.
-///
-/// See equation 6.4.9 in [1].
-///
-/// [1]: "Numerical Recipes in C", 2nd Ed., p. 228
-fn t_atv(t: f64, df: f64) -> Result<f64, Error> {
-    use num;
-
-    let x = df / (df + t.powi(2));
-    let a = 0.5 * df;
-    let b = 0.5;
-    let ib = num::inc_beta(x, a, b)?;
-
-    Ok(1.0 - ib)
+/// The critical value of Student's t-distribution with `df` degrees of freedom
+/// for a two-sided confidence interval, as by `StudentsT::critical`.
+pub fn t_critical(confidence: f64, df: f64) -> Result<f64, Error> {
+    StudentsT::new(df)?.critical(confidence)
 }
@@ -0,0 +1,116 @@
+/// Alignment of the cells of a table column.
+#[derive(Clone, Copy)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+struct Column {
+    title: String,
+    align: Align,
+    min_width: usize,
+}
+
+/// A table of preformatted cells, rendered with each column padded to the
+/// width of its widest cell.
+pub struct Table {
+    columns: Vec<Column>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Table { columns: vec![], rows: vec![] }
+    }
+
+    /// Add a column, at least `min_width` characters wide in plain text.
+    pub fn column(mut self, title: &str, align: Align, min_width: usize) -> Self {
+        self.columns.push(Column { title: title.to_string(), align, min_width });
+        self
+    }
+
+    /// Add a row of cells, one per column.
+    pub fn row(&mut self, cells: Vec<String>) {
+        assert_eq!(cells.len(), self.columns.len());
+
+        self.rows.push(cells);
+    }
+
+    /// Render as plain text, separating columns with two spaces.
+    pub fn text(&self) -> String {
+        let widths = self.widths(true);
+        let header: Vec<&str> = self.columns.iter().map(|c| c.title.as_str()).collect();
+
+        let mut s = self.line(&header, &widths, "", "  ", "");
+        for r in &self.rows {
+            s += &self.line(r, &widths, "", "  ", "");
+        }
+
+        s
+    }
+
+    /// Render as a GitHub-flavored Markdown table, aligned so that it also
+    /// reads well as plain text.
+    pub fn markdown(&self) -> String {
+        let widths = self.widths(false);
+        let header: Vec<&str> = self.columns.iter().map(|c| c.title.as_str()).collect();
+
+        let rule: Vec<String> = self.columns
+            .iter()
+            .zip(&widths)
+            .map(|(c, &w)| match c.align {
+                Align::Left => format!(":{}", "-".repeat(w + 1)),
+                Align::Right => format!("{}:", "-".repeat(w + 1)),
+            })
+            .collect();
+
+        let mut s = self.line(&header, &widths, "| ", " | ", " |");
+        s += &format!("|{}|\n", rule.join("|"));
+        for r in &self.rows {
+            s += &self.line(r, &widths, "| ", " | ", " |");
+        }
+
+        s
+    }
+
+    fn widths(&self, use_min: bool) -> Vec<usize> {
+        self.columns
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let min = if use_min { c.min_width } else { 0 };
+                self.rows
+                    .iter()
+                    .map(|r| len(&r[i]))
+                    .chain(Some(len(&c.title)))
+                    .fold(min, usize::max)
+            })
+            .collect()
+    }
+
+    fn line<S: AsRef<str>>(&self, cells: &[S], widths: &[usize],
+                           start: &str, sep: &str, end: &str) -> String {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&self.columns)
+            .zip(widths)
+            .map(|((cell, col), &w)| pad(cell.as_ref(), col.align, w))
+            .collect();
+
+        format!("{}{}{}\n", start, cells.join(sep), end)
+    }
+}
+
+/// Length in characters, rather than bytes, so labels like "m₁" line up.
+fn len(s: &str) -> usize {
+    s.chars().count()
+}
+
+fn pad(s: &str, align: Align, width: usize) -> String {
+    let fill = " ".repeat(width.saturating_sub(len(s)));
+
+    match align {
+        Align::Left => format!("{}{}", s, fill),
+        Align::Right => format!("{}{}", fill, s),
+    }
+}
@@ -0,0 +1,157 @@
+use unit::{self, Unit};
+
+
+const SECONDS_PER_DAY: f64 = 86400.0;
+
+/// Parse a timestamp as seconds since the Unix epoch: either that number of
+/// seconds itself, or an RFC 3339 date and time, such as
+/// `2024-05-01T12:30:00Z` or `2024-05-01T14:30:00.25+02:00`. A time without
+/// an offset is taken to be in UTC, and a date alone to be its midnight.
+pub fn parse(s: &str) -> Result<f64, String> {
+    if let Ok(t) = s.parse::<f64>() {
+        if t.is_finite() {
+            return Ok(t);
+        }
+    }
+
+    parse_rfc3339(s).ok_or_else(|| format!("Value {:?} is not a timestamp", s))
+}
+
+fn parse_rfc3339(s: &str) -> Option<f64> {
+    let (date, time) = match s.find(['T', 't', ' ']) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+
+    let mut parts = date.splitn(3, '-');
+    let year: i64 = digits(parts.next()?, 4)?;
+    let month: i64 = digits(parts.next()?, 2)?;
+    let day: i64 = digits(parts.next()?, 2)?;
+
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day) as f64;
+    let time = match time {
+        Some(t) => t,
+        None => return Some(days * SECONDS_PER_DAY),
+    };
+
+    // The offset from UTC follows the time, as `Z` or `±HH:MM`.
+    let (clock, offset) = match time.find(['Z', 'z', '+', '-']) {
+        Some(i) => (&time[..i], &time[i..]),
+        None => (time, ""),
+    };
+    let offset = match offset {
+        "" | "Z" | "z" => 0.0,
+        o => {
+            let sign = if o.starts_with('-') { -1.0 } else { 1.0 };
+            let (h, m) = o[1..].split_once(':')?;
+            sign * (digits(h, 2)? * 3600 + digits(m, 2)? * 60) as f64
+        },
+    };
+
+    let mut fields = clock.splitn(3, ':');
+    let hour: i64 = digits(fields.next()?, 2)?;
+    let minute: i64 = digits(fields.next()?, 2)?;
+    let second = match fields.next() {
+        Some(s) if s.len() >= 2 && s.bytes().all(|b| b.is_ascii_digit() || b == b'.') => {
+            s.parse::<f64>().ok()?
+        },
+        Some(_) => return None,
+        None => 0.0,
+    };
+
+    // A leap second is allowed, as the 60th second of a minute.
+    if hour > 23 || minute > 59 || second >= 61.0 {
+        return None;
+    }
+
+    Some(days * SECONDS_PER_DAY + (hour * 3600 + minute * 60) as f64 + second - offset)
+}
+
+/// Parse a field of exactly `n` decimal digits.
+fn digits(s: &str, n: usize) -> Option<i64> {
+    if s.len() != n || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    s.parse().ok()
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The number of days from the Unix epoch to a date of the proleptic Gregorian
+/// calendar, by Howard Hinnant's algorithm [1].
+///
+/// [1]: https://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+    era * 146097 + doe - 719468
+}
+
+/// The date of a number of days from the Unix epoch, the inverse of
+/// `days_from_civil`.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+
+    (yoe + era * 400 + if month <= 2 { 1 } else { 0 }, month, day)
+}
+
+/// Format seconds since the Unix epoch as an RFC 3339 timestamp in UTC, to
+/// the microsecond, with fractional seconds only if there are any.
+pub fn format(t: f64) -> String {
+    let micros = (t * 1e6).round() as i64;
+    let per_day = SECONDS_PER_DAY as i64 * 1_000_000;
+    let (year, month, day) = civil_from_days(micros.div_euclid(per_day));
+    let rest = micros.rem_euclid(per_day);
+    let (seconds, fraction) = (rest / 1_000_000, rest % 1_000_000);
+
+    let mut s = format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, seconds / 3600,
+                        seconds / 60 % 60, seconds % 60);
+    if fraction > 0 {
+        s.push_str(format!(".{:06}", fraction).trim_end_matches('0'));
+    }
+    s.push('Z');
+
+    s
+}
+
+/// Parse a length of time in seconds, such as `30s`, `5m`, `1h`, or `1d`, as
+/// a duration is parsed, or with a suffix `d` for days. A plain number is in
+/// seconds.
+pub fn parse_interval(s: &str) -> Result<f64, String> {
+    let seconds = match s.strip_suffix('d').map(|d| d.parse::<f64>()) {
+        Some(Ok(d)) => Some(d * SECONDS_PER_DAY),
+        _ => match unit::parse(s) {
+            Ok((x, None)) | Ok((x, Some(Unit::Seconds))) => Some(x),
+            _ => None,
+        },
+    };
+
+    match seconds {
+        Some(x) if x > 0.0 && x.is_finite() => Ok(x),
+        _ => Err(format!("Invalid length of time {:?}; expected a positive duration such as \
+                          30s, 5m, 1h, or 1d", s)),
+    }
+}
@@ -0,0 +1,176 @@
+use std::error;
+
+use dent::rank;
+use dent::summary::{Summarizer, Summary};
+
+
+/// A transformation of sample data, applied to each value before analysis.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Transform {
+    Log10,
+    Log2,
+    Sqrt,
+    /// Standardize each sample by its own mean and standard deviation.
+    ZScore,
+    /// Standardize each sample by its own median and scaled median absolute
+    /// deviation.
+    RobustZScore,
+    Reciprocal,
+    /// Replace values with their ranks among those of all samples analyzed
+    /// together, with ties sharing their mean rank.
+    Rank,
+    /// Convert durations to rates, dividing each into this amount of work,
+    /// per unit of time of the durations, so that rates are per second.
+    Rate(f64),
+}
+
+use self::Transform::*;
+
+/// The unit of rates from `Transform::Rate`.
+const RATE_UNIT: &str = "ops/s";
+
+/// Names of transforms, as given to `--transform`.
+pub const NAMES: &[&str] = &[
+    "log", "log10", "log2", "sqrt", "zscore", "robust-zscore", "reciprocal", "rank",
+];
+
+impl Transform {
+    pub fn parse(name: &str) -> Result<Transform, String> {
+        match name {
+            "log" | "log10" => Ok(Log10),
+            "log2" => Ok(Log2),
+            "sqrt" => Ok(Sqrt),
+            "zscore" => Ok(ZScore),
+            "robust-zscore" => Ok(RobustZScore),
+            "reciprocal" => Ok(Reciprocal),
+            "rank" => Ok(Rank),
+            _ => Err(format!("Unknown transform {:?}; expected one of {}", name, NAMES.join(", "))),
+        }
+    }
+
+    /// Name of the transform in output.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            Log10 => "log10",
+            Log2 => "log2",
+            Sqrt => "sqrt",
+            ZScore => "zscore",
+            RobustZScore => "robust-zscore",
+            Reciprocal => "reciprocal",
+            Rank => "rank",
+            Rate(_) => "rate",
+        }
+    }
+
+    /// Whether the transform converts durations to rates.
+    pub fn is_rate(&self) -> bool {
+        matches!(*self, Rate(_))
+    }
+
+    /// Whether the transform puts data on a log scale.
+    pub fn is_log(&self) -> bool {
+        *self == Log10 || *self == Log2
+    }
+
+    /// The unit of transformed values in `unit`, such as `log10(ms)` or
+    /// `1/ms`. Z-scores and ranks have no unit.
+    fn unit(&self, unit: &str) -> Option<String> {
+        match *self {
+            Log10 | Log2 | Sqrt => Some(format!("{}({})", self.name(), unit)),
+            Reciprocal => Some(format!("1/{}", unit)),
+            Rate(_) => Some(RATE_UNIT.to_string()),
+            ZScore | RobustZScore | Rank => None,
+        }
+    }
+
+    pub fn apply(&self, data: Vec<f64>) -> Result<Vec<f64>, Box<dyn error::Error>> {
+        let (valid, requirement): (fn(f64) -> bool, &str) = match *self {
+            Log10 | Log2 | Rate(_) => (|x| x > 0.0, "positive"),
+            Sqrt => (|x| x >= 0.0, "nonnegative"),
+            Reciprocal => (|x| x != 0.0, "nonzero"),
+            ZScore => return z_scores(data, false),
+            RobustZScore => return z_scores(data, true),
+            Rank => return Ok(rank::ranks(&data)),
+        };
+
+        if !data.iter().all(|&x| valid(x)) {
+            return Err(format!("The {} transform requires {} sample data", self.name(),
+                               requirement).into());
+        }
+
+        let f: fn(f64) -> f64 = match *self {
+            Log10 => f64::log10,
+            Log2 => f64::log2,
+            Sqrt => f64::sqrt,
+            Reciprocal => f64::recip,
+            Rate(work) => return Ok(data.into_iter().map(|x| work / x).collect()),
+            ZScore | RobustZScore | Rank => unreachable!(),
+        };
+
+        Ok(data.into_iter().map(f).collect())
+    }
+}
+
+/// The scale of the median absolute deviation which estimates the standard
+/// deviation of normally distributed data.
+const MAD_SCALE: f64 = 1.4826;
+
+/// Standardize `data`, in its order, by its mean and standard deviation, or
+/// if `robust`, by its median and scaled median absolute deviation, which
+/// outliers hardly affect.
+pub fn z_scores(data: Vec<f64>, robust: bool) -> Result<Vec<f64>, Box<dyn error::Error>> {
+    let (center, scale) = if robust {
+        let s = Summarizer::new(&data)?;
+        let mad = MAD_SCALE * s.median_absolute_deviation();
+
+        if mad <= 0.0 {
+            return Err("Robust z-scores require that fewer than half of the values equal the \
+                        median".into());
+        }
+
+        (s.median(), mad)
+    } else {
+        let s = Summary::new(&data)?;
+        let sd = s.standard_deviation();
+
+        if sd.is_nan() || sd <= 0.0 {
+            return Err("The zscore transform requires at least two distinct values".into());
+        }
+
+        (s.mean(), sd)
+    };
+
+    Ok(data.into_iter().map(|x| (x - center) / scale).collect())
+}
+
+/// Apply `transform`, if any, to `data`.
+pub fn apply(transform: Option<Transform>, data: Vec<f64>)
+             -> Result<Vec<f64>, Box<dyn error::Error>> {
+    match transform {
+        Some(t) => t.apply(data),
+        None => Ok(data),
+    }
+}
+
+/// Apply `transform`, if any, to samples analyzed together. Ranks are of the
+/// values of all of them, pooled; other transforms apply to each alone.
+pub fn apply_jointly(transform: Option<Transform>, samples: Vec<Vec<f64>>)
+                     -> Result<Vec<Vec<f64>>, Box<dyn error::Error>> {
+    if transform == Some(Rank) {
+        let samples: Vec<&[f64]> = samples.iter().map(|s| s.as_slice()).collect();
+
+        return Ok(rank::joint_ranks(&samples));
+    }
+
+    samples.into_iter().map(|s| apply(transform, s)).collect()
+}
+
+/// The unit of values in `unit` after `transform`, if any. Rates have a unit
+/// even if the durations had none.
+pub fn unit(transform: Option<Transform>, unit: Option<&str>) -> Option<String> {
+    match transform {
+        Some(Rate(_)) => Some(RATE_UNIT.to_string()),
+        Some(t) => unit.and_then(|u| t.unit(u)),
+        None => unit.map(String::from),
+    }
+}
@@ -0,0 +1,249 @@
+use std::io::{self, IsTerminal, Write};
+
+use crossterm::QueueableCommand;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+
+use dent::anova::one_way_anova;
+use dent::fmt;
+use dent::histogram::Histogram;
+use dent::plot::{self, PlotOptions};
+use dent::summary::{Summarizer, Summary};
+use dent::t_test::welch_t_test;
+
+
+
+/// Significance levels to choose from, in order, besides that of `--alpha`.
+const ALPHAS: &[f64] = &[0.001, 0.01, 0.05, 0.1];
+
+/// Rows used by everything but the plot: the status line and a blank line
+/// above it, and a blank line, the test result, the outliers, and the keys
+/// below it.
+const CHROME_ROWS: usize = 6;
+
+#[derive(Clone, Copy, PartialEq)]
+enum View {
+    Boxplot,
+    Histogram,
+    Ecdf,
+}
+
+impl View {
+    fn name(&self) -> &'static str {
+        match *self {
+            View::Boxplot => "boxplot",
+            View::Histogram => "histogram",
+            View::Ecdf => "ECDF",
+        }
+    }
+
+    fn next(&self) -> View {
+        match *self {
+            View::Boxplot => View::Histogram,
+            View::Histogram => View::Ecdf,
+            View::Ecdf => View::Boxplot,
+        }
+    }
+}
+
+/// What the UI shows. The histogram, ECDF, and outliers are those of the
+/// selected sample.
+struct State {
+    view: View,
+    log_scale: bool,
+    alpha: f64,
+    outliers: bool,
+    selected: usize,
+}
+
+impl State {
+    /// Update the state for a key press, returning whether to keep running.
+    fn handle(&mut self, key: KeyEvent, samples: usize) -> bool {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('b') => self.view = View::Boxplot,
+            KeyCode::Char('h') => self.view = View::Histogram,
+            KeyCode::Char('e') => self.view = View::Ecdf,
+            KeyCode::Tab => self.view = self.view.next(),
+            KeyCode::Char('l') => self.log_scale = !self.log_scale,
+            KeyCode::Char('a') => {
+                self.alpha = ALPHAS.iter().cloned().find(|&a| a > self.alpha).unwrap_or(ALPHAS[0]);
+            },
+            KeyCode::Char('A') => {
+                let last = ALPHAS[ALPHAS.len() - 1];
+                self.alpha = ALPHAS.iter().rev().cloned().find(|&a| a < self.alpha).unwrap_or(last);
+            },
+            KeyCode::Char('o') => self.outliers = !self.outliers,
+            KeyCode::Right | KeyCode::Char('j') => self.selected = (self.selected + 1) % samples,
+            KeyCode::Left | KeyCode::Char('k') => {
+                self.selected = (self.selected + samples - 1) % samples;
+            },
+            _ => {},
+        }
+
+        true
+    }
+}
+
+/// Explore `samples` interactively until the user quits, in the alternate
+/// screen of the terminal, which is restored even if drawing fails. Plots are
+/// drawn with `opts`, as wide as the terminal, and tests are first at level
+/// `alpha`.
+pub fn run(sources: &[String], samples: &[Summarizer], opts: &PlotOptions, alpha: f64)
+           -> io::Result<()> {
+    if !io::stdout().is_terminal() {
+        return Err(io::Error::other("The terminal UI requires a terminal"));
+    }
+
+    let mut out = io::stdout();
+
+    terminal::enable_raw_mode()?;
+    out.queue(EnterAlternateScreen)?.queue(Hide)?.flush()?;
+
+    let result = interact(&mut out, sources, samples, opts, alpha);
+
+    out.queue(Show)?.queue(LeaveAlternateScreen)?.flush()?;
+    terminal::disable_raw_mode()?;
+
+    result
+}
+
+fn interact<W: Write>(out: &mut W, sources: &[String], samples: &[Summarizer],
+                      opts: &PlotOptions, alpha: f64) -> io::Result<()> {
+    let summaries: Vec<Summary> = samples.iter().map(Summary::from).collect();
+    let mut state = State {
+        view: View::Boxplot,
+        log_scale: false,
+        alpha,
+        outliers: false,
+        selected: 0,
+    };
+
+    loop {
+        let (width, height) = terminal::size()?;
+        let lines = render(&state, sources, samples, &summaries, opts, width as usize,
+                           height as usize);
+
+        out.queue(MoveTo(0, 0))?.queue(Clear(ClearType::All))?;
+        // The terminal is in raw mode, so lines must also return the cursor.
+        write!(out, "{}", lines.join("\r\n"))?;
+        out.flush()?;
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !state.handle(key, samples.len()) {
+                return Ok(());
+            }
+        }
+    }
+}
+
+/// Render the screen as at most `height` lines, each fit to `width` columns.
+fn render(state: &State, sources: &[String], samples: &[Summarizer], summaries: &[Summary],
+          opts: &PlotOptions, width: usize, height: usize) -> Vec<String> {
+    let alpha = state.alpha;
+    let plot_height = height.saturating_sub(CHROME_ROWS).max(1);
+
+    let status = format!("{} | log scale: {} | α = {} | sample: {}", state.view.name(),
+                         if state.log_scale { "on" } else { "off" }, alpha,
+                         sources[state.selected]);
+    let mut lines = vec![fit(&status, width), String::new()];
+
+    let plot = plot_view(state, sources, samples, summaries, opts.clone().width(width),
+                         plot_height);
+    // Plots are already as wide as the terminal, and may be colored.
+    let plot_lines: Vec<String> = match plot {
+        Ok(p) => p.lines().map(String::from).collect(),
+        Err(e) => vec![fit(e, width)],
+    };
+    lines.extend(plot_lines.into_iter().take(plot_height));
+    while lines.len() < 2 + plot_height {
+        lines.push(String::new());
+    }
+
+    lines.push(String::new());
+    lines.push(fit(&test_result(summaries, alpha), width));
+    lines.push(fit(&outliers(&sources[state.selected], &samples[state.selected]), width));
+    lines.push(fit("[b]oxplot [h]istogram [e]cdf [l]og scale [a/A] α [o]utliers [←/→] sample \
+                    [q]uit", width));
+
+    lines.truncate(height);
+    lines
+}
+
+/// Truncate plain text to `width` characters.
+fn fit(s: &str, width: usize) -> String {
+    s.chars().take(width).collect()
+}
+
+fn plot_view(state: &State, sources: &[String], samples: &[Summarizer], summaries: &[Summary],
+             opts: PlotOptions, height: usize) -> Result<String, &'static str> {
+    if state.view == View::Boxplot {
+        let refs: Vec<&Summary> = summaries.iter().collect();
+        let opts = opts.labels(sources).log_scale(state.log_scale).outliers(state.outliers);
+
+        return plot::comparison_plot(&refs, &opts);
+    }
+
+    let data = samples[state.selected].as_slice();
+    let data: Vec<f64> = if state.log_scale {
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err("A log scale requires positive sample data");
+        }
+        data.iter().map(|x| x.log10()).collect()
+    } else {
+        data.to_vec()
+    };
+
+    if state.view == View::Histogram {
+        let hist = Histogram::with_sturges_bins(&data).map_err(|_| "Cannot bin sample data")?;
+        return plot::histogram_plot(&hist, &opts);
+    }
+
+    // The data are sorted, so the ECDF at the `i`th value is `(i + 1)/n`.
+    let n = data.len() as f64;
+    let points: Vec<(f64, f64)> = data
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| (x, (i + 1) as f64 / n))
+        .collect();
+
+    // Leave room for the border.
+    plot::scatter_plot(&points, &opts.height(height.saturating_sub(2).max(1)))
+}
+
+/// Compare two samples with a t-test, or more with an ANOVA, at level `alpha`.
+fn test_result(summaries: &[Summary], alpha: f64) -> String {
+    let refs: Vec<&Summary> = summaries.iter().collect();
+    let (test, p) = match refs.as_slice() {
+        [_] => return "One sample, so no test".to_string(),
+        [s1, s2] => ("Welch's t-test", welch_t_test(s1, s2).map(|t| t.p)),
+        _ => ("One-way ANOVA", one_way_anova(&refs).map(|a| a.p)),
+    };
+
+    match p {
+        Ok(p) if p < alpha => format!("{}: p = {}, significant at α = {}", test,
+                                      fmt::stat(p, Some(4)), alpha),
+        Ok(p) => format!("{}: p = {}, not significant at α = {}", test, fmt::stat(p, Some(4)),
+                         alpha),
+        Err(e) => format!("{}: {}", test, e),
+    }
+}
+
+/// List the outliers of a sample: its values beyond the adjacent values.
+fn outliers(source: &str, s: &Summarizer) -> String {
+    let outliers: Vec<String> = s
+        .as_slice()
+        .iter()
+        .filter(|&&x| x < s.min_adjacent() || x > s.max_adjacent())
+        .map(|&x| fmt::stat(x, Some(4)))
+        .collect();
+
+    if outliers.is_empty() {
+        format!("Outliers of {}: none", source)
+    } else {
+        format!("Outliers of {} ({} of {}): {}", source, outliers.len(), s.size(),
+                outliers.join(", "))
+    }
+}
@@ -0,0 +1,163 @@
+use std::num::ParseFloatError;
+
+use self::Unit::*;
+
+
+/// A dimension of measurement. Values with a unit suffix are normalized to
+/// the base unit of their dimension.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Unit {
+    /// Durations, in seconds.
+    Seconds,
+    /// Sizes of data, in bytes.
+    Bytes,
+}
+
+/// Unit suffixes, with their dimension and size in its base unit.
+const SUFFIXES: &[(&str, Unit, f64)] = &[
+    ("ns", Seconds, 1e-9),
+    ("us", Seconds, 1e-6),
+    ("µs", Seconds, 1e-6),
+    ("μs", Seconds, 1e-6),
+    ("ms", Seconds, 1e-3),
+    ("s", Seconds, 1.0),
+    ("min", Seconds, 60.0),
+    ("h", Seconds, 3600.0),
+    ("B", Bytes, 1.0),
+    ("kB", Bytes, 1e3),
+    ("KB", Bytes, 1e3),
+    ("MB", Bytes, 1e6),
+    ("GB", Bytes, 1e9),
+    ("TB", Bytes, 1e12),
+    ("KiB", Bytes, 1024.0),
+    ("MiB", Bytes, 1048576.0),
+    ("GiB", Bytes, 1073741824.0),
+    ("TiB", Bytes, 1099511627776.0),
+];
+
+impl Unit {
+    /// Symbol of the base unit.
+    pub fn symbol(&self) -> &'static str {
+        match *self {
+            Seconds => "s",
+            Bytes => "B",
+        }
+    }
+}
+
+/// A unit of time to which durations are converted, seconds by default.
+#[derive(Clone, Copy, Debug)]
+pub struct TimeUnit {
+    symbol: &'static str,
+    seconds: f64,
+}
+
+impl Default for TimeUnit {
+    fn default() -> Self {
+        TimeUnit { symbol: "s", seconds: 1.0 }
+    }
+}
+
+impl TimeUnit {
+    /// Parse the symbol of a unit of time, such as `ms`.
+    pub fn parse(s: &str) -> Result<TimeUnit, String> {
+        SUFFIXES
+            .iter()
+            .find(|&&(suffix, unit, _)| suffix == s && unit == Seconds)
+            .map(|&(symbol, _, seconds)| TimeUnit { symbol, seconds })
+            .ok_or_else(|| format!("Unknown unit of time {:?}; expected one of {}", s, TIME_UNITS))
+    }
+
+    pub fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    /// Convert a duration in seconds to this unit.
+    pub fn convert(&self, seconds: f64) -> f64 {
+        seconds / self.seconds
+    }
+
+    /// Convert a duration in this unit to seconds.
+    pub fn seconds(&self, x: f64) -> f64 {
+        x * self.seconds
+    }
+}
+
+/// The units of time accepted by `TimeUnit::parse`, for messages.
+const TIME_UNITS: &str = "ns, us, ms, s, min, h";
+
+/// Parse a number with an optional unit suffix, such as `12.3ms` or `1.2 KiB`,
+/// normalized to the base unit of its dimension. Durations may also be written
+/// as a clock time, `[H:]M:S`, or as a sum of amounts of time, as in
+/// `1h2m3.5s`.
+pub fn parse(s: &str) -> Result<(f64, Option<Unit>), ParseFloatError> {
+    let err = match s.parse() {
+        Ok(x) => return Ok((x, None)),
+        Err(e) => e,
+    };
+
+    SUFFIXES
+        .iter()
+        .filter_map(|&(suffix, unit, size)| {
+            let x = s.strip_suffix(suffix)?.trim_end().parse::<f64>().ok()?;
+            Some((x * size, Some(unit)))
+        })
+        .next()
+        .or_else(|| parse_duration(s).map(|x| (x, Some(Seconds))))
+        .ok_or(err)
+}
+
+/// Parse a duration in seconds, written as a clock time such as `00:02:31.5`
+/// or `2:31`, or as amounts of time run together, as printed by Go's
+/// `time.Duration`, such as `1m23.4s`.
+fn parse_duration(s: &str) -> Option<f64> {
+    if s.contains(':') {
+        let parts: Vec<&str> = s.split(':').collect();
+        let (seconds, init) = parts.split_last()?;
+
+        // Only the seconds may have a fractional part.
+        if parts.len() > 3 || !init.iter().all(|p| is_digits(p)) {
+            return None;
+        }
+
+        let whole = init.iter().fold(0.0, |acc, p| acc * 60.0 + p.parse::<f64>().unwrap_or(0.0));
+
+        return decimal(seconds).map(|s| whole * 60.0 + s);
+    }
+
+    let mut rest = s;
+    let mut total = 0.0;
+
+    while !rest.is_empty() {
+        let n = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+        let x = decimal(&rest[..n])?;
+        rest = &rest[n..];
+
+        let n = rest.find(|c: char| c.is_ascii_digit() || c == '.').unwrap_or(rest.len());
+        let size = match &rest[..n] {
+            "m" => 60.0,
+            unit => SUFFIXES
+                .iter()
+                .find(|&&(suffix, u, _)| suffix == unit && u == Seconds)
+                .map(|&(_, _, size)| size)?,
+        };
+        rest = &rest[n..];
+
+        total += x * size;
+    }
+
+    Some(total)
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parse a plain decimal number, such as `31.5`, but not `1e3` or `inf`.
+fn decimal(s: &str) -> Option<f64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit() || b == b'.') {
+        return None;
+    }
+
+    s.parse().ok()
+}
@@ -0,0 +1,125 @@
+//! Bindings of summaries and t-tests for JavaScript, by `wasm-bindgen`, with
+//! the `wasm` feature.
+//!
+//! Build them as a `cdylib` for `wasm32-unknown-unknown`, without the default
+//! features, which need C libraries:
+//!
+//! ```sh
+//! cargo rustc --lib --release --target wasm32-unknown-unknown \
+//!     --no-default-features --features wasm --crate-type cdylib
+//! wasm-bindgen --out-dir pkg target/wasm32-unknown-unknown/release/dent.wasm
+//! ```
+//!
+//! Errors are thrown as a JavaScript `Error` with the message `dent` prints.
+use wasm_bindgen::prelude::*;
+
+use summary;
+use t_test;
+
+
+/// The summary statistics of a sample, as `summary::Summary`.
+#[wasm_bindgen]
+pub struct Summary(summary::Summary);
+
+#[wasm_bindgen]
+impl Summary {
+    /// Summarize `data`, an array of numbers or a `Float64Array`.
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: &[f64]) -> Result<Summary, JsError> {
+        Ok(Summary(summary::Summary::new(data)?))
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn size(&self) -> f64 {
+        self.0.size()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn min(&self) -> f64 {
+        self.0.min()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn max(&self) -> f64 {
+        self.0.max()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn range(&self) -> f64 {
+        self.0.range()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn mean(&self) -> f64 {
+        self.0.mean()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn median(&self) -> f64 {
+        self.0.median()
+    }
+
+    #[wasm_bindgen(getter, js_name = lowerQuartile)]
+    pub fn lower_quartile(&self) -> f64 {
+        self.0.lower_quartile()
+    }
+
+    #[wasm_bindgen(getter, js_name = upperQuartile)]
+    pub fn upper_quartile(&self) -> f64 {
+        self.0.upper_quartile()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn iqr(&self) -> f64 {
+        self.0.iqr()
+    }
+
+    #[wasm_bindgen(getter, js_name = standardDeviation)]
+    pub fn standard_deviation(&self) -> f64 {
+        self.0.standard_deviation()
+    }
+
+    #[wasm_bindgen(getter, js_name = standardError)]
+    pub fn standard_error(&self) -> f64 {
+        self.0.standard_error()
+    }
+
+    #[wasm_bindgen(getter, js_name = unbiasedVariance)]
+    pub fn unbiased_variance(&self) -> f64 {
+        self.0.unbiased_variance()
+    }
+}
+
+/// The results of a t-test, as `t_test::TTest`.
+#[wasm_bindgen]
+#[derive(Clone, Copy)]
+pub struct TTest {
+    pub p: f64,
+    pub t: f64,
+    pub df: f64,
+}
+
+impl From<t_test::TTest> for TTest {
+    fn from(t: t_test::TTest) -> Self {
+        TTest { p: t.p, t: t.t, df: t.df }
+    }
+}
+
+/// Welch's t-test of two summaries, as `t_test::welch_t_test()`.
+#[wasm_bindgen(js_name = welchTTest)]
+pub fn welch_t_test(s1: &Summary, s2: &Summary) -> Result<TTest, JsError> {
+    Ok(t_test::welch_t_test(&s1.0, &s2.0)?.into())
+}
+
+/// Student's t-test of two summaries, as `t_test::student_t_test()`.
+#[wasm_bindgen(js_name = studentTTest)]
+pub fn student_t_test(s1: &Summary, s2: &Summary) -> Result<TTest, JsError> {
+    Ok(t_test::student_t_test(&s1.0, &s2.0)?.into())
+}
+
+/// The paired t-test of the values of `a` and `b` at equal indices, as
+/// `t_test::paired_t_test()`.
+#[wasm_bindgen(js_name = pairedTTest)]
+pub fn paired_t_test(a: &[f64], b: &[f64]) -> Result<TTest, JsError> {
+    Ok(t_test::paired_t_test(&t_test::pairs(a, b)?)?.into())
+}
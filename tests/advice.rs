@@ -0,0 +1,70 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+use dent::advice::{Test, advise};
+use dent::dist::{LogNormal, Normal};
+use dent::random::Rng;
+use dent::summary::Summarizer;
+
+
+fn sample<D: dent::dist::Distribution>(dist: &D, n: usize, seed: u64) -> Summarizer {
+    let mut rng = Rng::new(seed);
+    let data: Vec<f64> = (0..n).map(|_| rng.sample(dist).unwrap()).collect();
+
+    Summarizer::new(&data).unwrap()
+}
+
+#[test]
+fn test_advise_welch_for_normal_samples() {
+    let normal = Normal::new(10.0, 2.0).unwrap();
+    let a = sample(&normal, 15, 1);
+    let b = sample(&normal, 15, 2);
+
+    let advice = advise(&a, &b, 0.05).unwrap();
+    assert_eq!(advice.test, Test::Welch);
+    assert_eq!(advice.sizes, [15, 15]);
+    assert!(advice.explain(["a", "b"]).starts_with("Both samples are consistent"));
+}
+
+#[test]
+fn test_advise_welch_for_large_samples() {
+    let skewed = LogNormal::new(0.0, 1.0).unwrap();
+    let a = sample(&skewed, 100, 3);
+    let b = sample(&skewed, 100, 4);
+
+    let advice = advise(&a, &b, 0.05).unwrap();
+    assert_eq!(advice.test, Test::Welch);
+    assert!(advice.explain(["a", "b"]).contains("at least 30 values"));
+}
+
+#[test]
+fn test_advise_mann_whitney() {
+    let a = Summarizer::new(&[1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30]).unwrap();
+    let b = Summarizer::new(&[0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.13, 1.29]).unwrap();
+
+    let advice = advise(&a, &b, 0.05).unwrap();
+    assert_eq!(advice.test, Test::MannWhitney);
+
+    let explanation = advice.explain(["a", "b"]);
+    assert!(explanation.starts_with("b (Shapiro-Wilk p = "));
+    assert!(explanation.contains(") is not consistent with a normal distribution"));
+    assert!(explanation.contains("spreads are similar"));
+}
+
+#[test]
+fn test_advise_permutation() {
+    // Too few values to check.
+    let a = Summarizer::new(&[1.0, 2.0]).unwrap();
+    let b = Summarizer::new(&[3.0, 4.0, 5.0]).unwrap();
+    let advice = advise(&a, &b, 0.05).unwrap();
+    assert_eq!(advice.test, Test::Permutation);
+    assert!(advice.explain(["a", "b"]).starts_with("a has fewer than 3 values"));
+
+    // Not normal, small, and of different spreads.
+    let a = Summarizer::new(&[5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.0, 5.2]).unwrap();
+    let b = Summarizer::new(&[0.0, 10.0, 0.0, 10.0, 0.0, 10.0, 0.0, 10.0]).unwrap();
+    let advice = advise(&a, &b, 0.05).unwrap();
+    assert_eq!(advice.test, Test::Permutation);
+    assert!(advice.explain(["a", "b"]).contains("spreads differ"));
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::anova::{brown_forsythe, one_way_anova};
+use dent::histogram::Histogram;
+use dent::summary::{Summarizer, Summary};
+
+
+#[test]
+fn test_one_way_anova() {
+    let groups = [
+        Summary::new(&[6.0, 8.0, 4.0, 5.0, 3.0, 4.0]).unwrap(),
+        Summary::new(&[8.0, 12.0, 9.0, 11.0, 6.0, 8.0]).unwrap(),
+        Summary::new(&[13.0, 9.0, 11.0, 8.0, 7.0, 12.0]).unwrap(),
+    ];
+    let refs: Vec<&Summary> = groups.iter().collect();
+
+    let anova = one_way_anova(&refs).unwrap();
+
+    // With 2 numerator degrees of freedom, the F survival function has the
+    // closed form `(1 + 2F/d2)^(-d2/2)`.
+    assert_appx_eq!("F", 1e-12, 9.264705882352942, anova.f);
+    assert_appx_eq!("p", 1e-12, 0.002398777329392908, anova.p);
+    assert_eq!(anova.df_between, 2.0);
+    assert_eq!(anova.df_within, 15.0);
+}
+
+#[test]
+fn test_one_way_anova_undefined() {
+    let s = Summary::new(&[1.0, 2.0, 3.0]).unwrap();
+    let c = Summary::new(&[1.0, 1.0]).unwrap();
+
+    assert!(one_way_anova(&[&s]).is_err());
+    assert!(one_way_anova(&[&c, &c]).is_err());
+}
+
+#[test]
+fn test_histogram() {
+    let data = [0.0, 1.0, 1.5, 2.0, 2.5, 3.0, 4.0];
+    let hist = Histogram::new(&data, 4).unwrap();
+
+    assert_eq!(hist.bins(), 4);
+    assert_eq!(hist.counts(), &[1, 2, 2, 2]);
+    assert_eq!(hist.edges(), &[0.0, 1.0, 2.0, 3.0, 4.0]);
+
+    let sturges = Histogram::with_sturges_bins(&data).unwrap();
+    assert_eq!(sturges.bins(), 4);
+    assert_eq!(sturges.counts().iter().sum::<usize>(), data.len());
+
+    assert!(Histogram::new(&data, 0).is_err());
+    assert!(Histogram::new(&[], 4).is_err());
+}
+
+#[test]
+fn test_histogram_equal_frequency() {
+    let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 20.0, 50.0, 100.0, 1000.0, 10000.0];
+    let hist = Histogram::with_equal_frequency_bins(&data, 4).unwrap();
+
+    assert_eq!(hist.edges(), &[1.0, 4.0, 7.0, 50.0, 10000.0]);
+    assert_eq!(hist.counts(), &[3, 3, 3, 4]);
+
+    let densities = hist.densities();
+    assert!((densities[0] - 3.0 / 13.0 / 3.0).abs() < 1e-15);
+    assert!(densities[0] > densities[2] && densities[2] > densities[3]);
+
+    // Ties which would leave a bin without width merge it into the next.
+    let tied = Histogram::with_equal_frequency_bins(&[1.0, 1.0, 1.0, 1.0, 2.0, 3.0], 4).unwrap();
+    assert_eq!(tied.edges(), &[1.0, 1.75, 3.0]);
+    assert_eq!(tied.counts(), &[4, 2]);
+
+    let constant = Histogram::with_equal_frequency_bins(&[3.0, 3.0], 4).unwrap();
+    assert_eq!(constant.counts(), &[2]);
+
+    assert!(Histogram::with_equal_frequency_bins(&data, 0).is_err());
+}
+
+#[test]
+fn test_brown_forsythe() {
+    let a = Summarizer::new(&[1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30]).unwrap();
+    let b = Summarizer::new(&[0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.13, 1.29]).unwrap();
+
+    let bf = brown_forsythe(&[&a, &b]).unwrap();
+
+    // An ANOVA of the absolute deviations from the medians, computed directly.
+    assert_appx_eq!("F", 1e-12, 0.01402873338551959, bf.f);
+    assert_appx_eq!("p", 1e-12, 0.907190956954309, bf.p);
+    assert_eq!(bf.df_within, 16.0);
+}
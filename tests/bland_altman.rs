@@ -0,0 +1,37 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::bland_altman::BlandAltman;
+use support::fixture;
+use support::fs::read_data;
+
+
+#[test]
+fn test_bland_altman() {
+    let a = read_data(&fixture::path("paired_a"));
+    let b = read_data(&fixture::path("paired_b"));
+    let pairs: Vec<(f64, f64)> = a.into_iter().zip(b).collect();
+
+    let ba = BlandAltman::new(&pairs).unwrap();
+
+    let precision = 1e-14;
+
+    // Computed independently with Python's `statistics` module.
+    assert_appx_eq!("Bias", precision, 0.4339060569456872, ba.bias());
+    assert_appx_eq!("SD", precision, 0.3812597234133482, ba.standard_deviation());
+    assert_appx_eq!("Lower", precision,
+                    0.4339060569456872 - 1.96 * 0.3812597234133482, ba.lower_limit());
+    assert_appx_eq!("Upper", precision,
+                    0.4339060569456872 + 1.96 * 0.3812597234133482, ba.upper_limit());
+    assert_eq!(ba.size(), 60.0);
+}
+
+#[test]
+fn test_bland_altman_bad_sample() {
+    assert!(BlandAltman::new(&[]).is_err());
+    assert!(BlandAltman::new(&[(1.0, 2.0)]).is_err());
+    assert!(BlandAltman::new(&[(1.0, 2.0), (1.0, f64::NAN)]).is_err());
+}
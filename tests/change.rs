@@ -0,0 +1,127 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::change::{geometric_mean_ratio, harmonic_mean_change, mean_change, median_change};
+use dent::summary::{Summarizer, Summary};
+
+
+// Cushny and Peebles' hours of extra sleep for two drugs, as in R's `sleep`
+// data set, here taken as independent samples.
+const A: [f64; 10] = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+const B: [f64; 10] = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+
+#[test]
+fn test_mean_change() {
+    let a = Summary::new(&A).unwrap();
+    let b = Summary::new(&B).unwrap();
+
+    let c = mean_change(&a, &b, 0.95).unwrap();
+
+    // The interval of R's `t.test(b, a)` is [-0.2054832, 3.3654832].
+    assert_appx_eq!("absolute", 1e-14, 1.58, c.absolute);
+    assert_appx_eq!("absolute_margin", 1e-6, 1.7854832, c.absolute_margin);
+    assert_appx_eq!("relative", 1e-14, 1.58 / 0.75, c.relative);
+    assert_appx_eq!("relative_margin", 1e-5, 5.237757, c.relative_margin);
+}
+
+#[test]
+fn test_change_confidence() {
+    let a = Summary::new(&A).unwrap();
+    let b = Summary::new(&B).unwrap();
+
+    // The interval of R's `t.test(b, a, conf.level = 0.8)` is [0.4498355,
+    // 2.7101645].
+    let c = mean_change(&a, &b, 0.8).unwrap();
+    assert_eq!(c.confidence, 0.8);
+    assert_appx_eq!("absolute_margin", 1e-6, 1.1301645, c.absolute_margin);
+
+    let m = median_change(&a, &b, 0.8).unwrap();
+    assert!(m.absolute_margin < median_change(&a, &b, 0.95).unwrap().absolute_margin);
+
+    assert!(mean_change(&a, &b, 1.0).is_err());
+    assert!(median_change(&a, &b, 0.0).is_err());
+    assert!(geometric_mean_ratio(&[1.1, 0.9], 1.5).is_err());
+}
+
+#[test]
+fn test_mean_change_negative_baseline() {
+    let a = Summary::new(&[-2.0, -1.0, -3.0]).unwrap();
+    let b = Summary::new(&[1.0, 2.0, 0.0]).unwrap();
+
+    let c = mean_change(&a, &b, 0.95).unwrap();
+
+    // An increase from a negative baseline is still an increase.
+    assert_appx_eq!("absolute", 1e-14, 3.0, c.absolute);
+    assert_appx_eq!("relative", 1e-14, 1.5, c.relative);
+
+    let c = mean_change(&b, &a, 0.95).unwrap();
+    assert_appx_eq!("relative", 1e-14, -3.0, c.relative);
+}
+
+#[test]
+fn test_harmonic_mean_change() {
+    let a = Summarizer::new(&[1.0, 2.0, 4.0]).unwrap();
+    let b = Summarizer::new(&[2.0, 4.0, 8.0]).unwrap();
+
+    let c = harmonic_mean_change(&a, &b, 0.95).unwrap();
+
+    // The reciprocals have means 7/12 and 7/24, and the t critical value for
+    // their 2.941176 degrees of freedom is 3.218759. The standard errors of
+    // the harmonic means are 0.6479391 and 1.2958782.
+    assert_appx_eq!("absolute", 1e-14, 12.0 / 7.0, c.absolute);
+    assert_appx_eq!("absolute_margin", 1e-5, 4.663453, c.absolute_margin);
+    assert_appx_eq!("relative", 1e-14, 1.0, c.relative);
+    assert_appx_eq!("relative_margin", 1e-5, 3.440998, c.relative_margin);
+
+    let zero = Summarizer::new(&[0.0, 1.0]).unwrap();
+    assert!(harmonic_mean_change(&zero, &b, 0.95).is_err());
+}
+
+#[test]
+fn test_median_change() {
+    let a = Summary::new(&A).unwrap();
+    let b = Summary::new(&B).unwrap();
+
+    let c = median_change(&a, &b, 0.95).unwrap();
+
+    let se_a = 0.9290831602341976 * a.iqr() / 10f64.sqrt();
+    let se_b = 0.9290831602341976 * b.iqr() / 10f64.sqrt();
+
+    assert_appx_eq!("absolute", 1e-14, 1.4, c.absolute);
+    assert_appx_eq!("absolute_margin", 1e-9, 1.959963984540054 * (se_a.powi(2) + se_b.powi(2)).sqrt(),
+                    c.absolute_margin);
+    assert_appx_eq!("relative", 1e-12, 4.0, c.relative);
+
+    let one = Summary::new(&[1.0]).unwrap();
+    assert!(median_change(&one, &b, 0.95).is_err());
+}
+
+#[test]
+fn test_geometric_mean_ratio() {
+    let g = geometric_mean_ratio(&[1.1, 0.9, 1.25, 1.05], 0.95).unwrap();
+
+    // The logs have mean 0.0654708 and standard deviation 0.1356622, and the
+    // t critical value for 3 degrees of freedom is 3.182446.
+    let margin = 3.182446 * 0.1356622 / 2.0;
+    assert_appx_eq!("ratio", 1e-6, 0.0654708f64.exp(), g.ratio);
+    assert_appx_eq!("lower", 1e-6, (0.0654708f64 - margin).exp(), g.lower);
+    assert_appx_eq!("upper", 1e-6, (0.0654708f64 + margin).exp(), g.upper);
+
+    assert!(geometric_mean_ratio(&[1.1], 0.95).is_err());
+    assert!(geometric_mean_ratio(&[1.1, 0.0], 0.95).is_err());
+}
+
+#[test]
+fn test_geometric_mean_ratio_of_two() {
+    let g = geometric_mean_ratio(&[1.25, 0.8], 0.95).unwrap();
+
+    // The logs are ±0.2231436, and the t critical value for 1 degree of
+    // freedom is 12.7062.
+    let margin: f64 = 12.7062047 * 0.2231436;
+    assert_appx_eq!("ratio", 1e-14, 1.0, g.ratio);
+    assert_appx_eq!("lower", 1e-6, (-margin).exp(), g.lower);
+    assert_appx_eq!("upper", 1e-4, margin.exp(), g.upper);
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+use dent::changepoint::changepoints;
+
+
+/// A series whose level steps from 10 to 12 at index 30, and back down to 11
+/// at 50, with a repeating pattern of noise.
+fn series() -> Vec<f64> {
+    let noise = [0.3, -0.2, 0.1, -0.4, 0.2, 0.0, -0.1, 0.4, -0.3, 0.1, -0.2];
+    (0..70)
+        .map(|i| {
+            let level = if i < 30 { 10.0 } else if i < 50 { 12.0 } else { 11.0 };
+            level + noise[i % noise.len()]
+        })
+        .collect()
+}
+
+#[test]
+fn test_changepoints() {
+    let changes = changepoints(&series(), None).unwrap();
+
+    assert_eq!(changes.indices(), vec![30, 50]);
+    assert_eq!(changes.segments.len(), 3);
+    assert_eq!((changes.segments[0].start, changes.segments[2].end), (0, 70));
+    assert!((changes.segments[1].mean - 12.0).abs() < 0.1);
+    assert!((changes.penalty - 2.0 * 70f64.ln()).abs() < 1e-12);
+}
+
+#[test]
+fn test_changepoints_penalty() {
+    let changes = changepoints(&series(), Some(1e6)).unwrap();
+    assert!(changes.indices().is_empty());
+
+    let changes = changepoints(&series(), Some(0.0)).unwrap();
+    assert!(changes.segments.iter().all(|s| s.size() >= 2));
+    assert!(changes.segments.len() > 3);
+
+    assert!(changepoints(&series(), Some(-1.0)).is_err());
+}
+
+#[test]
+fn test_changepoints_degenerate() {
+    let changes = changepoints(&[3.0; 10], None).unwrap();
+    assert!(changes.indices().is_empty());
+    assert_eq!(changes.segments[0].mean, 3.0);
+
+    let changes = changepoints(&[1.0, 2.0, 3.0], None).unwrap();
+    assert_eq!(changes.segments.len(), 1);
+
+    // The default penalty is at least 2, even where 2 ln n is less.
+    let changes = changepoints(&[1.0], None).unwrap();
+    assert_eq!(changes.penalty, 2.0);
+
+    assert!(changepoints(&[], None).is_err());
+    assert!(changepoints(&[1.0, f64::NAN], None).is_err());
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+use dent::anova::one_way_anova;
+use dent::lr::LinearRegression;
+use dent::summary::{Summarizer, Summary};
+use dent::t_test::TTest;
+
+
+const DATA: [f64; 6] = [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0];
+
+#[test]
+fn test_summary() {
+    let s = Summary::new(&DATA).unwrap();
+
+    assert_eq!(format!("{}", s), concat!(
+        "  Size     Min Adj          Q1      Median          Q3     Max Adj        Mean     Std Dev\n",
+        "     6          -3       -1.75        -0.5        0.75           2        -0.5  1.87082869\n"));
+    assert_eq!(format!("{:.3}", s), concat!(
+        "  Size     Min Adj          Q1      Median          Q3     Max Adj        Mean     Std Dev\n",
+        "  6.00       -3.00       -1.75      -0.500       0.750        2.00      -0.500        1.87\n"));
+    assert_eq!(format!("{}", Summarizer::new(&DATA).unwrap()), format!("{}", s));
+}
+
+#[test]
+fn test_t_test() {
+    let t_test = TTest { p: 0.0123456, t: -2.5, df: 17.25 };
+
+    assert_eq!(format!("{}", t_test), concat!(
+        "           p = 0.0123456\n",
+        "           t = -2.5\n",
+        "          DF = 17.25\n"));
+    assert_eq!(format!("{:.2}", t_test), concat!(
+        "           p = 0.012\n",
+        "           t = -2.5\n",
+        "          DF = 17\n"));
+}
+
+#[test]
+fn test_linear_regression() {
+    let lr = LinearRegression::new(&[(1.0, 2.0), (2.0, 4.1), (3.0, 5.9), (4.0, 8.2)]).unwrap();
+
+    assert_eq!(format!("{:.4}", lr), concat!(
+        "       Slope = 2.040\n",
+        "   Intercept = -0.05000\n",
+        "           r = 0.9990\n",
+        "          SE = 0.06481\n"));
+}
+
+#[test]
+fn test_anova() {
+    let a = Summary::new(&[1.0, 2.0, 3.0]).unwrap();
+    let b = Summary::new(&[2.0, 3.0, 4.0]).unwrap();
+    let anova = one_way_anova(&[&a, &b]).unwrap();
+
+    assert_eq!(format!("{:.3}", anova), concat!(
+        "           F = 1.50\n",
+        "  DF between = 1.00\n",
+        "   DF within = 4.00\n",
+        "           p = 0.288\n"));
+}
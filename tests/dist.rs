@@ -0,0 +1,190 @@
+extern crate dent;
+
+#[macro_use] mod support;
+
+use std::f64::consts::FRAC_1_PI;
+
+use dent::dist::{ChiSquared, Distribution, Exponential, FisherF, Gamma, LogNormal, Normal,
+                 StudentsT, Uniform};
+
+
+#[test]
+fn test_students_t_pdf() {
+    let t = |df| StudentsT::new(df).unwrap();
+
+    // Computed with mpmath, as R's `dt(x, df)`.
+    assert_appx_eq!("pdf(0; 1)", 1e-12, FRAC_1_PI, t(1.0).pdf(0.0));
+    assert_appx_eq!("pdf(1.5; 5)", 1e-12, 0.1245173446463551, t(5.0).pdf(1.5));
+    assert_appx_eq!("pdf(-2; 3)", 1e-12, 0.0675096606638929, t(3.0).pdf(-2.0));
+    assert_appx_eq!("pdf(0.3; 30.5)", 1e-12, 0.3777431687638358, t(30.5).pdf(0.3));
+}
+
+#[test]
+fn test_students_t_cdf() {
+    let t = |df| StudentsT::new(df).unwrap();
+
+    // Computed with mpmath, as R's `pt(x, df)`.
+    assert_appx_eq!("cdf(1.5; 5)", 1e-10, 0.9030481598787633, t(5.0).cdf(1.5).unwrap());
+    assert_appx_eq!("cdf(-2; 3)", 1e-10, 0.0696629842794216, t(3.0).cdf(-2.0).unwrap());
+    assert_appx_eq!("cdf(2.5; 12)", 1e-10, 0.9860423002143374, t(12.0).cdf(2.5).unwrap());
+    assert_eq!(t(7.0).cdf(0.0).unwrap(), 0.5);
+    assert!(t(7.0).cdf(f64::NAN).is_err());
+}
+
+#[test]
+fn test_students_t_quantile() {
+    let t = |df| StudentsT::new(df).unwrap();
+
+    // Computed with mpmath, as R's `qt(p, df)`.
+    assert_appx_eq!("q(0.975; 5)", 1e-8, 2.570581835636315, t(5.0).quantile(0.975).unwrap());
+    assert_appx_eq!("q(0.05; 10)", 1e-8, -1.812461122811676, t(10.0).quantile(0.05).unwrap());
+    assert_appx_eq!("q(0.995; 2.5)", 1e-8, 7.163728138948783, t(2.5).quantile(0.995).unwrap());
+    assert_eq!(t(3.0).quantile(0.5).unwrap(), 0.0);
+
+    // The quantile function inverts the CDF.
+    let p = t(4.0).cdf(t(4.0).quantile(0.3).unwrap()).unwrap();
+    assert_appx_eq!("cdf(q(0.3))", 1e-10, 0.3, p);
+
+    assert!(t(3.0).quantile(0.0).is_err());
+    assert!(t(3.0).quantile(1.0).is_err());
+}
+
+#[test]
+fn test_students_t_df() {
+    assert!(StudentsT::new(0.0).is_err());
+    assert!(StudentsT::new(-1.0).is_err());
+    assert!(StudentsT::new(f64::NAN).is_err());
+    assert_eq!(StudentsT::new(2.5).unwrap().df(), 2.5);
+}
+
+#[test]
+fn test_normal() {
+    let z = Normal::standard();
+    let n = Normal::new(1.0, 2.0).unwrap();
+
+    // Computed with mpmath, as R's `dnorm`, `pnorm`, and `qnorm`.
+    assert_appx_eq!("pdf(1.3)", 1e-12, 0.1713685920478074, z.pdf(1.3));
+    assert_appx_eq!("pdf(2; 1, 2)", 1e-12, 0.1760326633821497, n.pdf(2.0));
+    assert_appx_eq!("cdf(1.96)", 1e-12, 0.9750021048517796, z.cdf(1.96).unwrap());
+    assert_appx_eq!("cdf(-3; 1, 2)", 1e-12, 0.02275013194817921, n.cdf(-3.0).unwrap());
+    assert_appx_eq!("sf(9)", 1e-30, 1.128588405953841e-19, z.sf(9.0).unwrap());
+
+    assert_appx_eq!("q(0.975)", 1e-12, 1.959963984540054, z.quantile(0.975).unwrap());
+    assert_appx_eq!("q(0.01)", 1e-12, -2.326347874040841, z.quantile(0.01).unwrap());
+    assert_appx_eq!("q(1e-10)", 1e-12, -6.361340902404056, z.quantile(1e-10).unwrap());
+    assert_appx_eq!("q(0.3; 10, 3)", 1e-12, 8.426798461875878,
+                    Normal::new(10.0, 3.0).unwrap().quantile(0.3).unwrap());
+
+    assert!(z.quantile(0.0).is_err());
+    assert!(Normal::new(0.0, 0.0).is_err());
+    assert!(Normal::new(f64::NAN, 1.0).is_err());
+}
+
+#[test]
+fn test_chi_squared() {
+    let chi = |df| ChiSquared::new(df).unwrap();
+
+    // Computed with mpmath, as R's `dchisq`, `pchisq`, and `qchisq`.
+    assert_appx_eq!("pdf(2; 3)", 1e-12, 0.2075537487102973, chi(3.0).pdf(2.0));
+    assert_appx_eq!("pdf(0.5; 1)", 1e-12, 0.4393912894677224, chi(1.0).pdf(0.5));
+    assert_appx_eq!("pdf(7; 4.5)", 1e-12, 0.06379597458932445, chi(4.5).pdf(7.0));
+    assert_eq!(chi(2.0).pdf(0.0), 0.5);
+    assert_eq!(chi(3.0).pdf(-1.0), 0.0);
+
+    assert_appx_eq!("cdf(2; 3)", 1e-12, 0.4275932955291202, chi(3.0).cdf(2.0).unwrap());
+    assert_appx_eq!("cdf(3.84; 1)", 1e-12, 0.9499564787512949, chi(1.0).cdf(3.84).unwrap());
+    assert_appx_eq!("cdf(30; 10)", 1e-12, 0.9991433587892247, chi(10.0).cdf(30.0).unwrap());
+    assert_appx_eq!("cdf(1; 4.5)", 1e-12, 0.05871137205476715, chi(4.5).cdf(1.0).unwrap());
+    assert_appx_eq!("sf(100; 10)", 1e-28, 5.449701982920529e-17, chi(10.0).sf(100.0).unwrap());
+    assert_eq!(chi(3.0).cdf(0.0).unwrap(), 0.0);
+
+    assert_appx_eq!("q(0.95; 1)", 1e-8, 3.841458820694124, chi(1.0).quantile(0.95).unwrap());
+    assert_appx_eq!("q(0.05; 4)", 1e-8, 0.7107230213973241, chi(4.0).quantile(0.05).unwrap());
+    assert_appx_eq!("q(0.99; 10)", 1e-8, 23.20925115895436, chi(10.0).quantile(0.99).unwrap());
+    assert_appx_eq!("q(0.5; 2.5)", 1e-8, 1.873847767780879, chi(2.5).quantile(0.5).unwrap());
+
+    assert!(ChiSquared::new(0.0).is_err());
+    assert!(chi(3.0).cdf(f64::NAN).is_err());
+}
+
+#[test]
+fn test_fisher_f() {
+    let f = |d1, d2| FisherF::new(d1, d2).unwrap();
+
+    // Computed with mpmath, as R's `df`, `pf`, and `qf`.
+    assert_appx_eq!("pdf(1; 3, 10)", 1e-12, 0.4041228114885627, f(3.0, 10.0).pdf(1.0));
+    assert_appx_eq!("pdf(2.5; 5, 2)", 1e-12, 0.09517371467518751, f(5.0, 2.0).pdf(2.5));
+    assert_appx_eq!("pdf(0.4; 1, 7)", 1e-12, 0.4874009497180978, f(1.0, 7.0).pdf(0.4));
+    assert_eq!(f(2.0, 5.0).pdf(0.0), 1.0);
+
+    assert_appx_eq!("cdf(1; 3, 10)", 1e-12, 0.5676627969783029, f(3.0, 10.0).cdf(1.0).unwrap());
+    assert_appx_eq!("cdf(2.5; 5, 2)", 1e-12, 0.6900094313951095, f(5.0, 2.0).cdf(2.5).unwrap());
+    assert_appx_eq!("cdf(0.4; 1, 7)", 1e-12, 0.4528161585563629, f(1.0, 7.0).cdf(0.4).unwrap());
+    assert_appx_eq!("sf(40; 4, 30)", 1e-22, 1.288430418861731e-11, f(4.0, 30.0).sf(40.0).unwrap());
+    assert_eq!(f(3.0, 10.0).cdf(-1.0).unwrap(), 0.0);
+
+    assert_appx_eq!("q(0.95; 3, 10)", 1e-8, 3.708264819046843, f(3.0, 10.0).quantile(0.95).unwrap());
+    assert_appx_eq!("q(0.05; 5, 2)", 1e-8, 0.1728269375857905, f(5.0, 2.0).quantile(0.05).unwrap());
+    assert_appx_eq!("q(0.99; 2, 20)", 1e-8, 5.848931924611135, f(2.0, 20.0).quantile(0.99).unwrap());
+
+    assert!(FisherF::new(1.0, 0.0).is_err());
+    assert!(FisherF::new(f64::INFINITY, 1.0).is_err());
+}
+
+#[test]
+fn test_uniform() {
+    let u = Uniform::new(2.0, 6.0).unwrap();
+
+    assert_eq!(u.pdf(3.0), 0.25);
+    assert_eq!(u.pdf(7.0), 0.0);
+    assert_eq!(u.cdf(3.0).unwrap(), 0.25);
+    assert_eq!(u.cdf(1.0).unwrap(), 0.0);
+    assert_eq!(u.cdf(8.0).unwrap(), 1.0);
+    assert_eq!(u.quantile(0.75).unwrap(), 5.0);
+
+    assert!(Uniform::new(1.0, 1.0).is_err());
+    assert!(Uniform::new(2.0, 1.0).is_err());
+}
+
+#[test]
+fn test_exponential() {
+    let e = Exponential::new(1.5).unwrap();
+
+    // Computed with mpmath, as R's `dexp`, `pexp`, and `qexp`.
+    assert_appx_eq!("pdf(0.7; 1.5)", 1e-12, 0.524906623666733, e.pdf(0.7));
+    assert_appx_eq!("cdf(0.7; 1.5)", 1e-12, 0.6500622508888446, e.cdf(0.7).unwrap());
+    assert_appx_eq!("q(0.25; 1.5)", 1e-12, 0.1917880483011873, e.quantile(0.25).unwrap());
+    assert_eq!(e.pdf(-1.0), 0.0);
+    assert_eq!(e.cdf(-1.0).unwrap(), 0.0);
+
+    assert!(Exponential::new(0.0).is_err());
+}
+
+#[test]
+fn test_log_normal() {
+    let l = LogNormal::new(0.5, 0.8).unwrap();
+
+    // Computed with mpmath, as R's `dlnorm`, `plnorm`, and `qlnorm`.
+    assert_appx_eq!("pdf(2; 0.5, 0.8)", 1e-12, 0.2421767748848334, l.pdf(2.0));
+    assert_appx_eq!("cdf(2; 0.5, 0.8)", 1e-12, 0.595390608679215, l.cdf(2.0).unwrap());
+    assert_appx_eq!("q(0.9; 0.5, 0.8)", 1e-10, 4.596252292620807, l.quantile(0.9).unwrap());
+    assert_eq!(l.pdf(0.0), 0.0);
+    assert_eq!(l.cdf(-1.0).unwrap(), 0.0);
+
+    assert!(LogNormal::new(0.0, -1.0).is_err());
+}
+
+#[test]
+fn test_gamma() {
+    let g = Gamma::new(2.5, 1.5).unwrap();
+
+    // Computed with mpmath, as R's `dgamma`, `pgamma`, and `qgamma` with a rate.
+    assert_appx_eq!("pdf(2; 2.5, 1.5)", 1e-12, 0.2919130399778488, g.pdf(2.0));
+    assert_appx_eq!("cdf(2; 2.5, 1.5)", 1e-12, 0.6937810815867216, g.cdf(2.0).unwrap());
+    assert_appx_eq!("q(0.9; 2.5, 1.5)", 1e-8, 3.078785633260373, g.quantile(0.9).unwrap());
+    assert_eq!(g.pdf(-1.0), 0.0);
+    assert_eq!(Gamma::new(1.0, 3.0).unwrap().pdf(0.0), 3.0);
+
+    assert!(Gamma::new(0.0, 1.0).is_err());
+    assert!(Gamma::new(1.0, -1.0).is_err());
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::effect_size::{cliffs_delta, cohens_d};
+use dent::summary::{Summarizer, Summary};
+
+
+// The sleep data of Cushny and Peebles, as in R's `sleep` data set.
+const A: [f64; 10] = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+const B: [f64; 10] = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+
+#[test]
+fn test_cohens_d() {
+    let a = Summary::new(&A).unwrap();
+    let b = Summary::new(&B).unwrap();
+
+    // Computed with `effsize::cohen.d(b, a)` in R.
+    assert_appx_eq!("d", 1e-6, 0.8321811, cohens_d(&a, &b));
+    assert_appx_eq!("-d", 1e-6, -0.8321811, cohens_d(&b, &a));
+    assert_eq!(cohens_d(&a, &a), 0.0);
+}
+
+#[test]
+fn test_cliffs_delta() {
+    let a = Summarizer::new(&A).unwrap();
+    let b = Summarizer::new(&B).unwrap();
+
+    // Computed with `effsize::cliff.delta(b, a)` in R.
+    assert_appx_eq!("delta", 1e-14, 0.49, cliffs_delta(&a, &b));
+    assert_appx_eq!("-delta", 1e-14, -0.49, cliffs_delta(&b, &a));
+    assert_eq!(cliffs_delta(&a, &a), 0.0);
+
+    let low = Summarizer::new(&[1.0, 2.0]).unwrap();
+    let high = Summarizer::new(&[3.0, 4.0, 5.0]).unwrap();
+    assert_eq!(cliffs_delta(&low, &high), 1.0);
+    assert_eq!(cliffs_delta(&high, &low), -1.0);
+}
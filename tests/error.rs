@@ -0,0 +1,51 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+use dent::error::Error;
+use dent::lr::LinearRegression;
+use dent::summary::Summarizer;
+use dent::t_test::pairs;
+
+
+#[test]
+fn test_non_finite() {
+    let e = Summarizer::new(&[1.0, f64::NAN]).unwrap_err();
+
+    assert!(matches!(e, Error::NonFinite));
+    assert_eq!(e.to_string(), "All sample data must be finite");
+}
+
+#[test]
+fn test_mismatched_lengths() {
+    assert_eq!(pairs(&[1.0, 2.0], &[3.0, 4.0]).unwrap(), vec![(1.0, 3.0), (2.0, 4.0)]);
+
+    let e = pairs(&[1.0, 2.0, 3.0], &[4.0]).unwrap_err();
+    assert!(matches!(e, Error::MismatchedLengths(3, 1)));
+    assert_eq!(e.to_string(), "Paired samples must have equal sizes, not 3 and 1");
+}
+
+#[test]
+fn test_singular_fit() {
+    let e = LinearRegression::new(&[(1.0, 2.0), (1.0, 3.0), (1.0, 5.0)]).unwrap_err();
+    assert!(matches!(e, Error::SingularFit));
+
+    assert!(matches!(LinearRegression::new(&[(1.0, 2.0)]), Err(Error::SingularFit)));
+}
+
+#[test]
+fn test_parse() {
+    let e = Error::Parse { line: 7, message: "Value \"x\" is not a number".to_string() };
+
+    assert_eq!(e.to_string(), "On line 7: Value \"x\" is not a number");
+}
+
+#[test]
+fn test_boxed() {
+    fn summarize(data: &[f64]) -> Result<f64, Box<dyn std::error::Error>> {
+        Ok(Summarizer::new(data)?.mean())
+    }
+
+    assert_eq!(summarize(&[1.0, 3.0]).unwrap(), 2.0);
+    assert_eq!(summarize(&[]).unwrap_err().to_string(), "Sample data set cannot be empty");
+}
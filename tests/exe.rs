@@ -1,3 +1,6 @@
+#![cfg(feature = "std")]
+#![allow(clippy::useless_vec)]
+
 mod support;
 
 use support::{assert, exe, fixture};
@@ -29,6 +32,90 @@ fn test_stdin() {
     assert::exit_ok(&out);
 }
 
+#[test]
+fn test_follow() {
+    let summary = exe::run_with_stdin(fixture::file("normal_0_1"), &["-s"]);
+
+    // The input ends at once, so its summary is drawn once, at the end.
+    let out = exe::run_with_stdin(fixture::file("normal_0_1"), &["--follow"]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert_eq!(out.stdout, summary.stdout);
+
+    let out = exe::run_with_stdin(fixture::file("normal_0_1"),
+                                  &["summary", "--follow", "--interval", "0.5"]);
+    assert::exit_ok(&out);
+    assert_eq!(out.stdout, summary.stdout);
+
+    assert::exit_fail(&exe::run_with_stdin(fixture::file("normal_0_1"),
+                                           &["--follow", "--interval", "0"]));
+}
+
+#[test]
+fn test_progress() {
+    let path = &fixture::path("normal_0_1");
+    let summary = exe::run(&[path]);
+
+    let out = exe::run(&["--progress", path]);
+    assert::exit_ok(&out);
+    assert_eq!(out.stdout, summary.stdout);
+    assert::stderr_includes(&out, "dent: reading ");
+    assert::stderr_includes(&out, "normal_0_1: 1.5 kB of 1.5 kB (100%)\n");
+
+    let out = exe::run_with_stdin(fixture::file("normal_0_1"), &["-s", "--progress"]);
+    assert::exit_ok(&out);
+    assert::stderr_includes(&out, "dent: reading stdin: 1.5 kB\n");
+
+    // Progress is only reported unasked when stderr is a terminal.
+    assert::stderr_is_empty(&summary);
+}
+
+#[test]
+fn test_many_inputs_keep_order() {
+    let names = ["normal_5_2", "normal_0_1", "near_1000", "normal_3_1", "near_0", "lognormal_0_1"];
+    let paths: Vec<String> = names.iter().map(|n| fixture::path(n)).collect();
+    let mut args = vec!["summary", "--format", "csv"];
+    args.extend(paths.iter().map(|p| p.as_str()));
+
+    let out = exe::run(&args);
+    assert::exit_ok(&out);
+
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    let sources: Vec<&str> = stdout.lines().skip(1).map(|l| l.split(',').next().unwrap()).collect();
+    assert_eq!(sources, paths);
+}
+
+#[test]
+fn test_large_file() {
+    use std::env;
+    use std::fs::{self, File};
+    use std::process;
+
+    // Larger than a chunk of a mapped file, with CRLF line endings and a blank
+    // line on either side of the chunk boundary.
+    let path = env::temp_dir().join(format!("dent_test_large_file_{}", process::id()));
+    let mut text = String::new();
+    for i in 0..200_000 {
+        text += &format!("{}.{}\r\n", i % 1000, i % 7);
+        if i % 1000 == 0 {
+            text += "\r\n";
+        }
+    }
+    fs::write(&path, text).unwrap();
+    let file = path.to_str().unwrap();
+
+    let mapped = exe::run(&["summary", "--format", "json", file]);
+    let piped = exe::run_with_stdin(File::open(file).unwrap(),
+                                    &["summary", "--format", "json", "-s"]);
+    fs::remove_file(&path).unwrap();
+
+    assert::exit_ok(&mapped);
+    assert::stdout_includes(&mapped, "\"size\": 200000");
+    assert::exit_ok(&piped);
+    assert_eq!(String::from_utf8_lossy(&mapped.stdout).replace(file, "stdin"),
+               String::from_utf8_lossy(&piped.stdout));
+}
+
 #[test]
 fn test_lax() {
     {
@@ -38,7 +125,9 @@ fn test_lax() {
     }
     {
         let path = &fixture::path("bad_lines");
-        assert::exit_fail(&exe::run(&[path]));
+        let out = exe::run(&[path]);
+        assert::exit_fail(&out);
+        assert::stderr_includes(&out, "bad_lines:3: Value \"not numeric\" is not a number");
         assert::exit_ok(&exe::run(&[path, "--lax"]));
     }
     {
@@ -74,6 +163,46 @@ fn test_comparison_plot() {
     assert::stdout_eq_file(&out, "comparison_plot.out");
 }
 
+#[test]
+fn test_ministat() {
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+    let out = exe::run(&["--style", "ministat", "-w", "76", path1, path2]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "ministat.out");
+
+    let out = exe::run(&["summary", "--style", "ministat", path1, path1]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "    N           Min           Max        Median");
+    assert::stdout_includes(&out, "x 100    -2.5985268     2.4168665");
+    assert!(!String::from_utf8_lossy(&out.stdout).contains("confidence"));
+
+    let out = exe::run(&["--style", "ministat", "--format", "json", path1]);
+    assert::exit_fail(&out);
+}
+
+#[test]
+fn test_effect_size() {
+    let path1 = &fixture::path("near_0");
+    let path2 = &fixture::path("near_1000");
+
+    // Each value of the second sample exceeds any of the first.
+    let out = exe::run(&["--effect-size", "cliff", "--format", "csv", path1, path2, path1]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, &format!("\nSource,{0},{1},{0}\n", path1, path2));
+    assert::stdout_includes(&out, &format!("\n{},0,1,0\n", path1));
+    assert::stdout_includes(&out, &format!("\n{},-1,0,-1\n", path2));
+
+    let out = exe::run(&["--effect-size", "cohen", path1, path2]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Cohen's d of each column relative to each row:\n");
+
+    let out = exe::run(&["--effect-size", "cohen", path1]);
+    assert::exit_fail(&out);
+}
+
 #[test]
 fn test_comparison_plot_outliers() {
     let path1 = &fixture::path("normal_0_1");
@@ -229,3 +358,1470 @@ fn test_tsv_3() {
     assert::stderr_is_empty(&out);
     assert::stdout_eq_file(&out, "tsv_3.out");
 }
+
+#[test]
+fn test_plot_log() {
+    let paths = [
+        fixture::path("lognormal_0_1"),
+        fixture::path("lognormal_2_1"),
+    ];
+    let out = exe::run(&["-p", "-w", "90", "--log", &paths[0], &paths[1]]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "log_plot.out");
+}
+
+#[test]
+fn test_plot_log_summary() {
+    let paths = [
+        fixture::path("lognormal_0_1"),
+        fixture::path("lognormal_2_1"),
+    ];
+    let out = exe::run(&["-p", "-w", "90", "--log-summary", &paths[0], &paths[1]]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "log_summary.out");
+}
+
+#[test]
+fn test_transform() {
+    let paths = [
+        fixture::path("lognormal_0_1"),
+        fixture::path("lognormal_2_1"),
+    ];
+    let out = exe::run(&["-p", "-w", "90", "--transform", "log", &paths[0], &paths[1]]);
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "log_summary.out");
+
+    let path = &fixture::path("units_ms");
+
+    let out = exe::run(&["--transform", "log2", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Unit: log2(s)\n");
+
+    let out = exe::run(&["--transform", "reciprocal", "--time-unit", "ms", "--format", "csv", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, ",1/ms,5,");
+
+    let out = exe::run(&["--transform", "zscore", "--format", "json", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"transform\": \"zscore\"");
+    assert::stdout_includes(&out, "\"mean\": 0");
+    assert!(!String::from_utf8_lossy(&out.stdout).contains("\"unit\""));
+
+    let path = &fixture::path("normal_0_1");
+    assert::exit_fail(&exe::run(&["--transform", "sqrt", path]));
+    assert::exit_ok(&exe::run(&["--transform", "zscore", path]));
+    assert::exit_fail(&exe::run(&["--transform", "exp", path]));
+    assert::exit_fail(&exe::run(&["--transform", "log", "--log-summary", path]));
+
+    let out = exe::run(&["--transform", "robust-zscore", "--format", "json", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"transform\": \"robust-zscore\"");
+}
+
+#[test]
+fn test_zscore() {
+    use std::env;
+    use std::fs;
+    use std::process::Output;
+
+    let path = &fixture::path("normal_0_1_ext_outlier");
+    let data = support::fs::read_data(path);
+
+    let values = |out: &Output| -> Vec<f64> {
+        String::from_utf8_lossy(&out.stdout).lines().map(|l| l.parse().unwrap()).collect()
+    };
+
+    // Each value is standardized in its place.
+    let out = exe::run(&["zscore", path]);
+    assert::exit_ok(&out);
+    let z = values(&out);
+    assert_eq!(z.len(), data.len());
+    let i = (0..data.len()).max_by(|&i, &j| data[i].total_cmp(&data[j])).unwrap();
+    assert!((0..z.len()).all(|j| z[j] <= z[i]));
+
+    // The outlier stands out more from robust scores, which it hardly affects.
+    let robust = values(&exe::run(&["zscore", "--robust", path]));
+    assert!(robust[i] > z[i]);
+
+    let out = exe::run_with_stdin(fixture::file("paired_a"), &["zscore", "--precision", "3"]);
+    assert::exit_ok(&out);
+    assert!(String::from_utf8_lossy(&out.stdout).starts_with("0.128\n0.449\n1.44\n1.00\n"));
+
+    // Most values equal the median, so its absolute deviation is 0.
+    let tied = env::temp_dir().join("dent_test_zscore_tied");
+    fs::write(&tied, "1\n1\n1\n4\n").unwrap();
+    let tied = tied.to_str().unwrap();
+
+    assert::exit_ok(&exe::run(&["zscore", tied]));
+    let out = exe::run(&["zscore", "--robust", tied]);
+    assert::exit_code(&out, 2);
+    assert::stderr_includes(&out, "fewer than half of the values equal the median");
+}
+
+#[test]
+fn test_transform_rank() {
+    let path1 = &fixture::path("near_0");
+    let path2 = &fixture::path("near_1000");
+
+    // The samples are ranked together, and each value of the first is less
+    // than any of the second.
+    let out = exe::run(&["summary", "--transform", "rank", "--format", "csv",
+                         "--fields", "n,mean,min,max", path1, path2]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Source,Size,Mean,Min,Max\n");
+    assert::stdout_includes(&out, "near_0,6,3.5,1,6\n");
+    assert::stdout_includes(&out, "near_1000,7,10,7,13\n");
+
+    let out = exe::run(&["--transform", "rank", "--format", "json", path1]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"transform\": \"rank\"");
+}
+
+#[test]
+fn test_as_rate() {
+    let path = &fixture::path("units_ms");
+
+    // Durations in any unit become operations per second, whose harmonic
+    // mean is the work over the mean duration, 1.2414s / 5.
+    let out = exe::run(&["summary", "--as-rate", "1", "--time-unit", "ms", "--format", "csv",
+                         "--fields", "min,max,hmean", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "Source,Unit,Min,Max,HarmonicMean\n");
+    assert::stdout_includes(&out,
+                            ",ops/s,0.8333333333333334,1111.1111111111113,4.027710649266958\n");
+
+    let out = exe::run(&["summary", "--as-rate", "100", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Unit: ops/s\n");
+    assert::stdout_includes(&out, "Hmean");
+
+    assert::exit_code(&exe::run(&["summary", "--as-rate", "0", path]), 2);
+    assert::exit_code(&exe::run(&["--as-rate", "1", "--transform", "log", path]), 2);
+}
+
+#[test]
+fn test_as_rate_comparison() {
+    let paths = [fixture::path("lognormal_0_1"), fixture::path("lognormal_2_1")];
+
+    // Rates are compared by their harmonic means, so the t-test is that of
+    // the durations, and the change of the overall rate is the inverse of
+    // that of the mean duration, 1 / (1 + 621%) - 1.
+    let out = exe::run(&["--as-rate", "1", &paths[0], &paths[1]]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "T-test of the harmonic means");
+    assert::stdout_includes(&out, "p = 0.0000000002330564630170784\n");
+    assert::stdout_includes(&out, "Δ hmean = ");
+    assert::stdout_includes(&out, "(-86.1% ± 4.46%)\n");
+    assert::stdout_includes(&out, "Difference in harmonic means is significant");
+    assert::stdout_includes(&out, "lognormal_2_1 is 86.1% slower than");
+
+    // Other comparisons would be of the arithmetic means of the rates.
+    let out = exe::run(&["--as-rate", "1", "--format", "json", &paths[0], &paths[1]]);
+    assert::exit_code(&out, 2);
+    let out = exe::run(&["--as-rate", "1", "--auto", &paths[0], &paths[1]]);
+    assert::exit_code(&out, 2);
+}
+
+#[test]
+fn test_plot_log_nonpositive() {
+    let path = &fixture::path("normal_0_1");
+
+    assert::exit_fail(&exe::run(&["-p", "--log", path]));
+    assert::exit_fail(&exe::run(&["--log-summary", path]));
+}
+
+#[test]
+fn test_color() {
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+
+    let out = exe::run(&["-p", "-w", "90", "--color", "always", path1, path2]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "comparison_plot_color.out");
+
+    let out = exe::run(&["-p", "-w", "90", "--color", "never", path1, path2]);
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "comparison_plot.out");
+
+    assert::exit_fail(&exe::run(&["--color", "sometimes", path1]));
+}
+
+#[test]
+fn test_svg() {
+    use std::env;
+    use support::fs::read_string;
+
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+    let svg = env::temp_dir().join("dent_test_svg.svg");
+    let svg = svg.to_str().unwrap();
+
+    let out = exe::run(&["-w", "90", "--svg", svg, path1, path2]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert_eq!(read_string(svg), fixture::read("comparison_plot.svg"));
+}
+
+#[test]
+fn test_svg_plots() {
+    use std::env;
+    use support::fs::read_string;
+
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+    let svg = env::temp_dir().join("dent_test_svg_plots.svg");
+    let svg = svg.to_str().unwrap();
+
+    // The text output is unchanged by writing a plot.
+    let out = exe::run(&["hist", "-w", "70", "--svg", svg, path1]);
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "hist.out");
+    assert_eq!(read_string(svg).matches("height=\"14.0\"").count(), 8);
+
+    let out = exe::run(&["lr", "--svg", svg, &fixture::path("linear")]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert!(read_string(svg).contains("<circle"));
+
+    let out = exe::run(&["--bland-altman", "--svg", svg, path1, path2]);
+    assert::exit_ok(&out);
+    let plot = read_string(svg);
+    assert_eq!(plot.matches("<circle").count(), 100);
+    assert!(plot.contains("stroke-dasharray"));
+
+    let out = exe::run(&["--paired", "--differences", "--svg", svg,
+                         &fixture::path("paired")]);
+    assert::exit_ok(&out);
+    assert!(read_string(svg).contains("height=\"14.0\""));
+}
+
+#[test]
+fn test_report_html() {
+    use std::env;
+    use support::fs::read_string;
+
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+    let html = env::temp_dir().join("dent_test_report.html");
+    let html = html.to_str().unwrap();
+
+    let out = exe::run(&["report", "--html", html, path1, path2]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_is_empty(&out);
+    assert_eq!(read_string(html), fixture::read("report.html"));
+
+    assert::exit_fail(&exe::run(&["report", path1]));
+}
+
+#[test]
+fn test_markdown_2() {
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+    let out = exe::run(&["-p", "-w", "70", "--format", "markdown", path1, path2]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "markdown_2.out");
+}
+
+#[test]
+fn test_markdown_3() {
+    let paths = [
+        fixture::path("normal_0_1"),
+        fixture::path("normal_3_1"),
+        fixture::path("normal_5_2"),
+    ];
+    let out = exe::run(&["--format", "markdown", &paths[0], &paths[1], &paths[2]]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "markdown_3.out");
+}
+
+#[test]
+fn test_bland_altman() {
+    let path1 = &fixture::path("paired_a");
+    let path2 = &fixture::path("paired_b");
+    let out = exe::run(&["-p", "-w", "70", "--bland-altman", path1, path2]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "bland_altman.out");
+
+    let path3 = &fixture::path("normal_0_1");
+    assert::exit_fail(&exe::run(&["--bland-altman", path1, path3]));
+    assert::exit_fail(&exe::run(&["--bland-altman", path1]));
+}
+
+#[test]
+fn test_unit_suffixes() {
+    let path = &fixture::path("units_ms");
+
+    let out = exe::run(&[path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "units.out");
+
+    let out = exe::run(&["--format", "csv", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Source,Unit,Size,");
+    assert::stdout_includes(&out, ",s,5,0.24828,");
+
+    let out = exe::run(&["--format", "json", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"unit\": \"s\"");
+
+    assert::exit_fail(&exe::run(&[&fixture::path("units_mixed")]));
+}
+
+#[test]
+fn test_durations() {
+    let path = &fixture::path("durations");
+
+    let out = exe::run(&[path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "durations.out");
+
+    let out = exe::run(&["--time-unit", "min", "--format", "long", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "unit: min\n");
+    assert::stdout_includes(&out, "max: 60\n");
+
+    assert::exit_fail(&exe::run(&["--time-unit", "fortnight", path]));
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn test_gzip() {
+    let plain = exe::run(&[&fixture::path("normal_0_1")]);
+
+    let out = exe::run(&[&fixture::path("normal_0_1.gz")]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert_eq!(out.stdout, plain.stdout);
+
+    let out = exe::run_with_stdin(fixture::file("normal_0_1.gz"), &["-s"]);
+    assert::exit_ok(&out);
+    assert_eq!(out.stdout, plain.stdout);
+}
+
+#[cfg(feature = "zstd")]
+#[test]
+fn test_zstd() {
+    let plain = exe::run(&[&fixture::path("normal_0_1")]);
+
+    let out = exe::run(&[&fixture::path("normal_0_1.zst")]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert_eq!(out.stdout, plain.stdout);
+
+    let out = exe::run_with_stdin(fixture::file("normal_0_1.zst"), &["-s"]);
+    assert::exit_ok(&out);
+    assert_eq!(out.stdout, plain.stdout);
+}
+
+#[test]
+fn test_import_criterion() {
+    let out = exe::run(&["import", "criterion", &fixture::path("criterion")]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "criterion.out");
+
+    let out = exe::run(&["import", "criterion", "--format", "csv", &fixture::path("criterion")]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\nfib/20/base,ns,20,");
+    assert::stdout_includes(&out, "\nfib/20/new,ns,20,");
+    assert::stdout_includes(&out, "\nparse/new,ns,20,");
+
+    assert::exit_fail(&exe::run(&["import", "criterion", &fixture::path("criterion_bad")]));
+    assert::exit_fail(&exe::run(&["import", "criterion", &fixture::path("paired")]));
+}
+
+#[test]
+fn test_label() {
+    let (a, b) = (&fixture::path("normal_0_1"), &fixture::path("normal_3_1"));
+
+    let out = exe::run(&["--label", "before", "--label", "after", a, b]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "before\n  Size ");
+    assert::stdout_includes(&out, "after\n  Size ");
+
+    let out = exe::run(&["--label", "before", "--label", "after", "--format", "csv", a, b]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\nbefore,");
+    assert::stdout_includes(&out, "\nafter,");
+
+    let out = exe::run(&["summary", "--label", "x", "--label", "y", "--cols", "3,4",
+                         "--format", "csv", &fixture::path("wide.txt"), &fixture::path("wide.txt")]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\nx:3,40,");
+    assert::stdout_includes(&out, "\ny:4,40,");
+
+    let out = exe::run_with_stdin(fixture::file("normal_0_1"), &["-s", "--label", "sample"]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "sample\n  Size ");
+
+    assert::exit_fail(&exe::run(&["--label", "before", a, b]));
+}
+
+#[test]
+fn test_run() {
+    let out = exe::run(&["run", "-n", "3", "--warmup", "1", "--format", "csv", "true", "exit 0"]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "\ntrue,s,3,");
+    assert::stdout_includes(&out, "\nexit 0,s,3,");
+
+    let out = exe::run(&["run", "-n", "3", "--time-unit", "ms", "--label", "noop", "true"]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Unit: ms\n\nnoop\n");
+
+    assert::exit_fail(&exe::run(&["run", "-n", "3", "false"]));
+    assert::exit_fail(&exe::run(&["run", "-n", "1", "true"]));
+}
+
+#[test]
+fn test_diagnostics() {
+    let out = exe::run(&["--no-such-flag"]);
+    assert::exit_code(&out, 2);
+    assert::stderr_includes(&out, "--no-such-flag");
+
+    let missing = &fixture::path("missing");
+    let out = exe::run(&[missing]);
+    assert::exit_code(&out, 2);
+    assert::stderr_includes(&out, &format!("Could not open file {:?}: ", missing));
+
+    let out = exe::run_with_stdin(fixture::file("bad_lines"), &["-s"]);
+    assert::exit_code(&out, 2);
+    assert::stderr_includes(&out, "stdin:3: Value \"not numeric\" is not a number");
+
+    let out = exe::run(&["lr", &fixture::path("paired_lax")]);
+    assert::exit_code(&out, 2);
+    assert::stderr_includes(&out, "paired_lax:1: Value \"before\" is not a number");
+}
+
+#[test]
+fn test_closed_pipe() {
+    let path = &fixture::path("normal_0_1");
+    let out = exe::run_to_closed_pipe(&["--plot", path, path]);
+
+    // Killed by SIGPIPE, or done writing before the pipe closed, but no panic.
+    assert!(out.status.code().is_none_or(|c| c == 0), "Unexpected status {}", out.status);
+    assert::stderr_is_empty(&out);
+}
+
+#[test]
+fn test_fail_on_reject() {
+    let (a, b) = (&fixture::path("normal_0_1"), &fixture::path("normal_3_1"));
+
+    let out = exe::run(&["--fail-on-reject", a, b]);
+    assert::exit_code(&out, 1);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "p = ");
+
+    assert::exit_code(&exe::run(&["--fail-on-reject", a, a]), 0);
+    assert::exit_code(&exe::run(&["ttest", "--fail-on-reject", "--format", "json", a, b]), 1);
+    assert::exit_code(&exe::run(&["--fail-on-reject", a, &fixture::path("missing")]), 2);
+
+    // Without the flag, a significant difference is not a failure.
+    assert::exit_code(&exe::run(&[a, b]), 0);
+    assert::exit_code(&exe::run(&[a, &fixture::path("missing")]), 2);
+}
+
+#[test]
+fn test_max_regression() {
+    let (a, b) = (&fixture::path("normal_3_1"), &fixture::path("normal_5_2"));
+
+    let out = exe::run(&["--max-regression", "5%", a, b]);
+    assert::exit_code(&out, 1);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "\nRegression of more than 5%: yes\n");
+
+    let out = exe::run(&["ttest", "--max-regression", "1.5", a, b]);
+    assert::exit_code(&out, 0);
+    assert::stdout_includes(&out, "\nRegression of more than 150%: no\n");
+
+    // An improvement is never a regression.
+    assert::exit_code(&exe::run(&["--max-regression", "0%", b, a]), 0);
+    assert::exit_code(&exe::run(&["--max-regression", "5%", "--format", "json", a, b]), 1);
+    assert::exit_code(&exe::run(&["--max-regression", "five", a, b]), 2);
+    assert::exit_code(&exe::run(&["--max-regression", "5%", a]), 2);
+}
+
+#[test]
+fn test_alternative() {
+    let (a, b) = (&fixture::path("normal_0_1"), &fixture::path("normal_3_1"));
+
+    let out = exe::run(&["--alternative", "greater", "--fail-on-reject", a, b]);
+    assert::exit_code(&out, 1);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "Increase in means is significant");
+
+    // A one-sided test never finds a difference in the other direction.
+    let out = exe::run(&["ttest", "--alternative", "less", "--fail-on-reject", a, b]);
+    assert::exit_code(&out, 0);
+    assert::stdout_includes(&out, "Decrease in means is not significant");
+
+    let out = exe::run(&["--alternative", "less", "--format", "json", a, b]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, r#""alternative": "less""#);
+
+    assert::exit_code(&exe::run(&["--alternative", "both", a, b]), 2);
+    assert::exit_code(&exe::run(&["--alternative", "less", "--auto", a, b]), 2);
+}
+
+#[test]
+fn test_non_inferiority() {
+    let (a, b) = (&fixture::path("normal_3_1"), &fixture::path("normal_5_2"));
+
+    let out = exe::run(&["--non-inferiority", "5%", a, a]);
+    assert::exit_code(&out, 1);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "\nNon-inferior: no\n");
+    assert::stdout_includes(&out, "95% upper = +7.64%\n");
+
+    let out = exe::run(&["ttest", "--non-inferiority", "0.5", a, a]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\nNon-inferior: yes\n");
+
+    // A better new sample is non-inferior by any margin.
+    assert::exit_ok(&exe::run(&["--non-inferiority", "0", b, a]));
+    assert::exit_code(&exe::run(&["--non-inferiority", "1", a, b]), 1);
+    assert::exit_code(&exe::run(&["--non-inferiority", "-5%", a, b]), 2);
+    assert::exit_code(&exe::run(&["--non-inferiority", "5ms", a, b]), 2);
+    assert::exit_code(&exe::run(&["--non-inferiority", "5%", a]), 2);
+}
+
+#[test]
+fn test_differences() {
+    let path = &fixture::path("paired");
+
+    let out = exe::run(&["--paired", "--differences", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "2 - 1\n");
+    assert::stdout_includes(&out, "    Positive = 51 (85.0%)\n");
+    assert::stdout_includes(&out, "    Negative = 9 (15.0%)\n");
+    assert::stdout_includes(&out, "      95% CI = ");
+    assert::stdout_includes(&out, " │");
+
+    let out = exe::run(&["ttest", "--paired", "--differences", "--confidence", "0.9",
+                         "--format", "json", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, r#""confidence": 0.9"#);
+    assert::stdout_includes(&out, r#""negative": 9"#);
+    assert::stdout_includes(&out, r#""edges": ["#);
+
+    assert::exit_code(&exe::run(&["--differences", path]), 2);
+    assert::exit_code(&exe::run(&["--paired", "--differences", "--format", "csv", path]), 2);
+}
+
+#[test]
+fn test_robust() {
+    let (a, b) = (&fixture::path("normal_3_1"), &fixture::path("normal_5_2"));
+
+    let out = exe::run(&["--robust", "--fail-on-reject", a, b]);
+    assert::exit_code(&out, 1);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "    HL shift = 2.111946795\n");
+    assert::stdout_includes(&out, "      95% CI = 1.70006264 to 2.5390198299999995\n");
+    assert::stdout_includes(&out, "Shift in location is significant");
+
+    let out = exe::run(&["ttest", "--robust", "--confidence", "0.9", "--format", "json", a, b]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, r#""hodges_lehmann": {"#);
+    assert::stdout_includes(&out, r#""confidence": 0.9"#);
+
+    assert::exit_code(&exe::run(&["--robust", a]), 2);
+    assert::exit_code(&exe::run(&["--robust", "--auto", a, b]), 2);
+}
+
+#[test]
+fn test_baseline() {
+    use std::env;
+    use support::fs::read_string;
+
+    let baseline = env::temp_dir().join("dent_test_baseline.json");
+    let baseline = baseline.to_str().unwrap();
+
+    let out = exe::run(&["baseline", "save", "--label", "before", baseline,
+                         &fixture::path("normal_3_1")]);
+    assert::exit_ok(&out);
+    assert::stdout_is_empty(&out);
+    assert_eq!(read_string(baseline), fixture::read("baseline.json"));
+
+    let out = exe::run(&["baseline", "compare", baseline, &fixture::path("normal_5_2")]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "Baseline: before (");
+    assert::stdout_includes(&out, "m₁ ± SE = 2.9474672002999993 ± ");
+    assert::stdout_includes(&out, "m₂ - m₁ ± SE = 2.245222732700001 ± ");
+
+    let out = exe::run_with_stdin(fixture::file("normal_3_1"),
+                                  &["baseline", "compare", "--format", "json", baseline]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"source\": \"before\"");
+    assert::stdout_includes(&out, "\"mean_difference\": 0.0");
+
+    let path = &fixture::path("normal_5_2");
+    assert::exit_code(&exe::run(&["baseline", "compare", "--max-regression", "10%", baseline, path]),
+                      1);
+    assert::exit_fail(&exe::run(&["baseline", "compare", baseline, &fixture::path("units_ms")]));
+    assert::exit_fail(&exe::run(&["baseline", "compare", &fixture::path("normal_0_1"), path]));
+}
+
+#[test]
+fn test_named_stdin() {
+    let out = exe::run_with_stdin(fixture::file("named"), &["-s"]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "named.out");
+
+    let out = exe::run_with_stdin(fixture::file("named"), &["-s", "--format", "csv"]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\nbefore,100,");
+    assert::stdout_includes(&out, "\nafter,100,");
+}
+
+#[test]
+fn test_from_hyperfine() {
+    let path = &fixture::path("hyperfine.json");
+
+    let out = exe::run(&["--from", "hyperfine", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "hyperfine.out");
+
+    let out = exe::run(&["--from", "hyperfine", "--time-unit", "ms", "--format", "csv", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\nsleep 0.1,ms,10,104.19");
+    assert::stdout_includes(&out, "\nsleep 0.12,ms,10,124.05");
+
+    assert::exit_fail(&exe::run(&["--from", "hyperfine", &fixture::path("normal_0_1")]));
+}
+
+#[test]
+fn test_from_go() {
+    let (old, new) = (&fixture::path("go_old.txt"), &fixture::path("go_new.txt"));
+
+    let out = exe::run(&["--from", "go", "--time-unit", "ns", old]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "Unit: ns\n");
+    assert::stdout_includes(&out, "\nParse-8\n");
+    assert::stdout_includes(&out, "\nSplit-8\n");
+
+    // Benchmarks in both inputs are compared, and the rest summarized.
+    let out = exe::run(&["--from", "go", "--format", "csv", old, new]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "go_old.txt:Parse-8,s,6,");
+    assert::stdout_includes(&out, "go_new.txt:Join-8,s,6,");
+
+    let out = exe::run(&["--from", "go", "--time-unit", "ns", old, new]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "m₂ - m₁ ± SE = -157.20000000000005 ± ");
+    assert::stdout_includes(&out, "m₂ - m₁ ± SE = 0.6166666666667879 ± ");
+    assert::stdout_includes(&out, "go_new.txt:Join-8\n");
+
+    assert::exit_fail(&exe::run(&["--from", "go", &fixture::path("normal_0_1")]));
+}
+
+#[test]
+fn test_geomean() {
+    let (old, new) = (&fixture::path("go_old.txt"), &fixture::path("go_new.txt"));
+
+    let out = exe::run(&["--from", "go", "--geomean", "--time-unit", "ns", old, new]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Parse-8    1.256650e3  1.099450e3   1.143×\n");
+    assert::stdout_includes(&out, "Geometric mean speedup: 1.069× (95% CI 0.4551× to 2.510×), of \
+                                   2 benchmarks\n");
+    assert::stdout_includes(&out, "Skipped 2 benchmarks not in both results\n");
+
+    let out = exe::run(&["--from", "go", "--geomean", "--format", "json", old, new]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"speedup\": 1.1429805811996907");
+    assert::stdout_includes(&out, "\"ratio\": 1.0687059865612178");
+
+    assert::exit_fail(&exe::run(&["--from", "go", "--geomean", "--format", "csv", old, new]));
+    assert::exit_fail(&exe::run(&["--geomean", old, new]));
+
+    // Only one benchmark has a baseline.
+    let path = &fixture::path("criterion");
+    assert::exit_fail(&exe::run(&["import", "criterion", "--geomean", path]));
+}
+
+#[test]
+fn test_from_jmh() {
+    let out = exe::run(&["--from", "jmh", "--time-unit", "us", "--format", "csv",
+                         &fixture::path("jmh.json")]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "\norg.example.ParseBench.parse(size=10),us,10,12.3368,");
+    assert::stdout_includes(&out, "\norg.example.ParseBench.parse(size=100),us,10,117.0414,");
+
+    // Throughputs are in operations per second, and have no unit.
+    let out = exe::run(&["--from", "jmh", "--format", "csv", &fixture::path("jmh_thrpt.json")]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\norg.example.ParseBench.parse(size=10),10,80820.1");
+
+    assert::exit_fail(&exe::run(&["--from", "jmh", &fixture::path("jmh_thrpt.json"),
+                                  &fixture::path("jmh.json")]));
+    assert::exit_fail(&exe::run(&["--from", "jmh", &fixture::path("hyperfine.json")]));
+}
+
+#[test]
+fn test_paired() {
+    let path = &fixture::path("paired");
+
+    let out = exe::run(&["--paired", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "paired.out");
+
+    let out = exe::run(&["ttest", "--paired", "--cols", "1,2", path]);
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "paired.out");
+
+    // The same analysis as of the two files of which it is made.
+    let out = exe::run(&["-p", "-w", "70", "--bland-altman", "--paired", path]);
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "bland_altman.out");
+
+    // Lax parsing drops whole pairs, keeping the rest aligned.
+    let path = &fixture::path("paired_lax");
+    let out = exe::run(&["--paired", "--lax", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "           n = 3\n");
+
+    assert::exit_fail(&exe::run(&["--paired", path]));
+    assert::exit_fail(&exe::run(&["--paired", "--cols", "1,2,1", "--lax", path]));
+}
+
+#[test]
+fn test_subcommand_summary() {
+    let path = &fixture::path("all_numeric_lines");
+    let out = exe::run(&["summary", path, path]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert!(!String::from_utf8_lossy(&out.stdout).contains("p ="));
+
+    let file = fixture::file("all_numeric_lines");
+    assert::exit_ok(&exe::run_with_stdin(file, &["summary"]));
+}
+
+#[test]
+fn test_subcommand_ttest() {
+    let path = &fixture::path("all_numeric_lines");
+    let out = exe::run(&["ttest", path, path]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "comparison.out");
+
+    assert::exit_fail(&exe::run(&["ttest", path]));
+    assert::exit_fail(&exe::run(&["ttest", path, path, path]));
+}
+
+#[test]
+fn test_subcommand_anova() {
+    let paths = [
+        fixture::path("normal_0_1"),
+        fixture::path("normal_3_1"),
+        fixture::path("normal_5_2"),
+    ];
+    let out = exe::run(&[
+        "anova", "-p", "-w", "70", "--color", "never", &paths[0], &paths[1], &paths[2],
+    ]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "anova.out");
+
+    assert::exit_fail(&exe::run(&["anova", &paths[0]]));
+}
+
+#[test]
+fn test_subcommand_lr() {
+    let path = &fixture::path("linear");
+    let out = exe::run(&["lr", "-p", "-w", "70", path]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "lr.out");
+
+    let bad = &fixture::path("normal_0_1");
+    assert::exit_fail(&exe::run(&["lr", bad]));
+}
+
+#[test]
+fn test_subcommand_lr_timestamps() {
+    use std::env;
+    use std::fs;
+
+    let trend = env::temp_dir().join("dent_test_lr_timestamps");
+    fs::write(&trend, "2024-05-01T00:00:00Z 10ms\n\
+                       2024-05-02T00:00:00Z 10.9ms\n\
+                       1714737600 12.0ms\n\
+                       2024-05-04T02:00:00+02:00 12.4ms\n").unwrap();
+    let path = trend.to_str().unwrap();
+
+    let out = exe::run(&["lr", "--timestamps", "--time-unit", "ms", "--precision", "3", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "x: days since 2024-05-01T00:00:00Z\nUnit: ms\n");
+    assert::stdout_includes(&out, "Slope = 0.789\n");
+    assert::stdout_includes(&out, "Intercept = 10.0\n");
+
+    let out = exe::run(&["lr", "--timestamps", "--per", "hour", "--format", "json", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"per\": \"hour\"");
+    assert::stdout_includes(&out, "\"origin\": \"2024-05-01T00:00:00Z\"");
+
+    // Timestamps are not numbers without --timestamps.
+    assert::exit_fail(&exe::run(&["lr", path]));
+}
+
+#[test]
+fn test_subcommand_hist() {
+    let path = &fixture::path("normal_0_1");
+
+    let out = exe::run(&["hist", "-w", "70", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "hist.out");
+
+    let out = exe::run(&["hist", "--ascii", "--bins", "5", "-w", "70", path]);
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "hist_ascii.out");
+
+    let out = exe::run(&["hist", "--equal-frequency", "--bins", "4", "-w", "70", path]);
+    assert::exit_ok(&out);
+    assert_eq!(String::from_utf8_lossy(&out.stdout).matches(" 25\n").count(), 4);
+
+    assert::exit_fail(&exe::run(&["hist", "--bins", "zero", path]));
+}
+
+#[test]
+fn test_json() {
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+    let out = exe::run(&["-p", "--format", "json", path1, path2]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "json_2.out");
+
+    let path = &fixture::path("linear");
+    let out = exe::run(&["lr", "--format", "json", path]);
+
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "lr_json.out");
+}
+
+#[test]
+fn test_csv() {
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+    let out = exe::run(&["--format", "csv", path1, path2]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "csv_2.out");
+}
+
+#[test]
+fn test_format_tsv() {
+    let paths = [
+        fixture::path("normal_0_1"),
+        fixture::path("normal_3_1"),
+        fixture::path("normal_5_2"),
+    ];
+    let out = exe::run(&["anova", "--format", "tsv", &paths[0], &paths[1], &paths[2]]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "anova_tsv.out");
+}
+
+#[test]
+fn test_precision() {
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+    let out = exe::run(&["-p", "-w", "70", "--precision", "3", "--color", "never", path1, path2]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "precision.out");
+
+    assert::exit_fail(&exe::run(&["--precision", "0", path1]));
+    assert::exit_fail(&exe::run(&["--precision", "many", path1]));
+}
+
+#[test]
+fn test_fields() {
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+    let out = exe::run(&["--fields", "n,mean,p99,geomean,mad", path1, path2]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "fields.out");
+
+    let out = exe::run(&["summary", "--format", "csv", "--fields", "n,p50", path1]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Source,Size,p50\n");
+
+    assert::exit_fail(&exe::run(&["--fields", "n,p101", path1]));
+    assert::exit_fail(&exe::run(&["--fields", "n,bogus", path1]));
+}
+
+#[test]
+fn test_long() {
+    let path1 = &fixture::path("normal_0_1");
+    let path2 = &fixture::path("normal_5_2");
+    let out = exe::run(&["--format", "long", path1, path2]);
+
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "long_2.out");
+
+    let path = &fixture::path("linear");
+    let out = exe::run(&["lr", "--format", "long", "--precision", "4", path]);
+
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "slope: 1.950\n");
+}
+
+#[test]
+fn test_csv_column() {
+    let path = &fixture::path("wide.csv");
+
+    let out = exe::run(&["--csv", "--col", "time_v1", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "csv_col.out");
+
+    // By index, the header row is detected and skipped.
+    let out = exe::run(&["--csv", "--col", "3", path]);
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "csv_col.out");
+
+    assert::exit_fail(&exe::run(&["--csv", "--col", "missing", path]));
+    assert::exit_fail(&exe::run(&["--csv", "--col", "0", path]));
+    assert::exit_fail(&exe::run(&["--csv", "--col", "2", path]));
+    assert::exit_fail(&exe::run(&["--col", "3", path]));
+}
+
+#[test]
+fn test_delimiter_field() {
+    let out = exe::run(&["--field", "3", &fixture::path("wide.txt")]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "csv_col.out");
+
+    let path = &fixture::path("wide.ssv");
+
+    let out = exe::run(&["--delimiter", ";", "--field", "3", path]);
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "csv_col.out");
+
+    let out = exe::run(&["--delimiter", ";", "--col", "time_v1", path]);
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "csv_col.out");
+
+    assert::exit_fail(&exe::run(&["--delimiter", ";;", "--field", "3", path]));
+    assert::exit_fail(&exe::run(&["--delimiter", ";", "--field", "x", path]));
+    assert::exit_fail(&exe::run(&["--field", "3", path]));
+}
+
+#[test]
+fn test_cols() {
+    let out = exe::run(&["--csv", "--cols", "time_v1,time_v2", &fixture::path("wide.csv")]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "cols.out");
+
+    let out = exe::run(&["summary", "--cols", "3,4", "--format", "csv", &fixture::path("wide.txt")]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\n3,40,10.138655,");
+    assert::stdout_includes(&out, "\n4,40,10.780657500000002,");
+
+    let path = &fixture::path("wide.txt");
+    assert::exit_fail(&exe::run(&["ttest", "--cols", "3,4,1", path]));
+    assert::exit_fail(&exe::run(&["--cols", "3,9", path]));
+}
+
+#[test]
+fn test_group_by() {
+    let path = &fixture::path("grouped.csv");
+
+    let out = exe::run(&["--csv", "--group-by", "variant", "--value", "latency", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "grouped.out");
+
+    let out = exe::run(&["--csv", "--group-by", "1", "--value", "2", path]);
+    assert::exit_ok(&out);
+    assert::stdout_eq_file(&out, "grouped.out");
+
+    assert::exit_fail(&exe::run(&["--csv", "--group-by", "variant", path]));
+    assert::exit_fail(&exe::run(&["ttest", "--csv", "--group-by", "1", "--value", "2", path]));
+}
+
+#[test]
+fn test_pool() {
+    let (a, b) = (&fixture::path("normal_0_1"), &fixture::path("normal_3_1"));
+
+    let out = exe::run(&["summary", "--pool", "--format", "json", a, b]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, r#""source": "pooled""#);
+    assert::stdout_includes(&out, r#""size": 200.0"#);
+
+    // Groups of the same name are pooled across inputs.
+    let path = &fixture::path("grouped.csv");
+    let out = exe::run(&["summary", "--csv", "--group-by", "variant", "--value", "latency",
+                         "--pool", "--format", "csv", path, path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\nb,40,");
+    assert!(!String::from_utf8_lossy(&out.stdout).contains("grouped.csv:"));
+}
+
+#[test]
+fn test_tui_requires_terminal() {
+    let path = &fixture::path("normal_0_1");
+    let out = exe::run(&["tui", path]);
+
+    assert::exit_fail(&out);
+    assert::stdout_is_empty(&out);
+    assert::stderr_includes(&out, "The terminal UI requires a terminal");
+}
+
+#[test]
+fn test_config() {
+    let config = &fixture::path("config.toml");
+    let path = &fixture::path("near_0");
+
+    let out = exe::run(&["--config", config, "summary", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "| Source ");
+    assert::stdout_includes(&out, "| 6.00 |");
+
+    // Options on the command line take precedence.
+    let out = exe::run(&["summary", path, "--config", config, "--format", "text",
+                         "--precision", "5"]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "6.0000     -3.0000");
+
+    let out = exe::run(&["--config", &fixture::path("bad_config.toml"), path]);
+    assert::exit_fail(&out);
+    assert::stderr_includes(&out, "Unknown setting \"theme\" on line 2");
+
+    let out = exe::run(&["--config", &fixture::path("missing.toml"), path]);
+    assert::exit_fail(&out);
+}
+
+#[test]
+fn test_config_default_path() {
+    let path = &fixture::path("near_0");
+    let out = exe::run_with_env(&[("XDG_CONFIG_HOME", "tests/support/fixture/xdg")], &[path]);
+
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "  6.00       -3.00");
+}
+
+#[test]
+fn test_alpha() {
+    let path = &fixture::path("near_0");
+
+    let out = exe::run(&["--alpha", "0.01", path]);
+    assert::exit_ok(&out);
+
+    let out = exe::run(&["--alpha", "1.5", path]);
+    assert::exit_fail(&out);
+    assert::stderr_includes(&out, "The significance level must be between 0 and 1");
+}
+
+#[test]
+fn test_confidence() {
+    let path = &fixture::path("normal_0_1");
+
+    let out = exe::run(&["--confidence", "0.9", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Mean Lo     Mean Hi");
+    assert::stdout_includes(&out, "-0.1512176  0.15609405");
+
+    let out = exe::run(&["summary", "--fields", "mean_ci,median_ci", "--format", "csv", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Source,MeanLower,MeanUpper,MedianLower,MedianUpper\n");
+
+    // Without --confidence, the default fields have no intervals.
+    let out = exe::run(&["summary", path]);
+    assert!(!String::from_utf8_lossy(&out.stdout).contains("Mean Lo"));
+
+    let out = exe::run(&["--confidence", "95", path]);
+    assert::exit_fail(&out);
+    assert::stderr_includes(&out, "The confidence level must be between 0 and 1");
+
+    // The level applies to comparisons as well.
+    let paths = [fixture::path("normal_0_1"), fixture::path("lognormal_0_1")];
+    let out = exe::run(&["--confidence", "0.9", &paths[0], &paths[1]]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Change, with 90% confidence intervals:\n");
+
+    let out = exe::run(&["--confidence", "0.9", "--format", "json", &paths[0], &paths[1]]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"confidence\": 0.9\n");
+
+    let out = exe::run(&["--confidence", "0.9", "--style", "ministat", &paths[0], &paths[1]]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Difference at 90.0% confidence\n");
+}
+
+#[test]
+fn test_verdict() {
+    let (a, b) = (&fixture::path("normal_0_1"), &fixture::path("normal_5_2"));
+
+    let out = exe::run(&["--alpha", "0.01", a, b]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Difference in means is significant at α = 0.01 (p ≈ 0)");
+
+    let out = exe::run(&["--alpha", "0.01", a, a]);
+    assert::stdout_includes(&out, "Difference in means is not significant at α = 0.01 (p = 1.0)");
+
+    let out = exe::run(&["--from", "hyperfine", "--time-unit", "ms",
+                         &fixture::path("hyperfine.json")]);
+    assert::stdout_includes(&out, "; sleep 0.12 is 19.1% slower than sleep 0.1");
+}
+
+#[test]
+fn test_completions() {
+    for shell in &["bash", "zsh", "fish", "powershell", "elvish"] {
+        let out = exe::run(&["completions", shell]);
+
+        assert::exit_ok(&out);
+        assert::stderr_is_empty(&out);
+        assert::stdout_includes(&out, "alpha");
+    }
+
+    let out = exe::run(&["completions", "fish"]);
+    assert::stdout_includes(&out, "-l format -d 'Output format for summaries and test results' \
+                                   -r -f -a \"text markdown json long csv tsv\"");
+
+    let out = exe::run(&["completions", "tcsh"]);
+    assert::exit_fail(&out);
+}
+
+#[test]
+fn test_clean() {
+    use std::process::Output;
+
+    let path = &fixture::path("normal_0_1_ext_outlier");
+    let data = support::fs::read_data(path);
+
+    let values = |out: &Output| -> Vec<f64> {
+        String::from_utf8_lossy(&out.stdout).lines().map(|l| l.parse().unwrap()).collect()
+    };
+
+    // Values are written as read, in full precision.
+    let out = exe::run(&["clean", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert_eq!(values(&out), data);
+
+    // The extreme outlier, and a milder one, are dropped.
+    let out = exe::run(&["clean", "--drop-outliers", path]);
+    assert::exit_ok(&out);
+    let cleaned = values(&out);
+    assert_eq!(cleaned.len(), data.len() - 2);
+    assert!(cleaned.iter().all(|x| data.contains(x)));
+
+    let out = exe::run(&["clean", "--lax", "--transform", "log", "--precision", "3",
+                         &fixture::path("units_mixed")]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "3.01\n");
+
+    assert::exit_code(&exe::run(&["clean", &fixture::path("units_mixed")]), 2);
+}
+
+#[test]
+fn test_freq() {
+    use std::env;
+    use std::fs;
+
+    let codes = env::temp_dir().join("dent_test_freq");
+    fs::write(&codes, "200\n200\n404\n200\n500\n404\n200\n301\n").unwrap();
+    let path = codes.to_str().unwrap();
+
+    let out = exe::run(&["freq", "-w", "50", "--ascii", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert_eq!(String::from_utf8_lossy(&out.stdout),
+               "Value  Count  Percent  Cumulative\n  \
+                  200      4    50.0%       50.0%  ###############\n  \
+                  301      1    12.5%       62.5%  ####\n  \
+                  404      2    25.0%       87.5%  ########\n  \
+                  500      1    12.5%        100%  ####\n");
+
+    let out = exe::run(&["freq", "--format", "json", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"frequencies\": [");
+    assert::stdout_includes(&out, "\"cumulative\": 0.875");
+
+    assert::exit_code(&exe::run(&["freq", "--format", "csv", path]), 2);
+}
+
+#[test]
+fn test_kappa() {
+    use std::env;
+    use std::fs;
+
+    let ratings = env::temp_dir().join("dent_test_kappa");
+    fs::write(&ratings, "id,first,second\n1,yes,yes\n2,no,yes\n3,yes,yes\n4,no,no\n5,yes,no\n")
+        .unwrap();
+    let path = ratings.to_str().unwrap();
+
+    let out = exe::run(&["kappa", "--csv", "--cols", "first,second", "--precision", "3", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "Kappa = 0.167");
+    assert::stdout_includes(&out, "Observed = 0.600");
+
+    let matrix = env::temp_dir().join("dent_test_kappa_matrix");
+    fs::write(&matrix, "75 1 4\n5 4 1\n0 0 10\n").unwrap();
+    let path = matrix.to_str().unwrap();
+
+    let out = exe::run(&["kappa", "--matrix", "--weights", "linear", "--format", "json", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"kappa\": 0.722");
+    assert::stdout_includes(&out, "\"weights\": \"linear\"");
+
+    fs::write(&matrix, "1 2\n3 4 5\n").unwrap();
+    assert::exit_code(&exe::run(&["kappa", "--matrix", path]), 2);
+}
+
+#[test]
+fn test_roc() {
+    use std::env;
+    use std::fs;
+
+    let scores = env::temp_dir().join("dent_test_roc");
+    fs::write(&scores, "score,label\n0.9,1\n0.8,1\n0.7,0\n0.6,1\n0.4,0\n0.2,0\n").unwrap();
+    let path = scores.to_str().unwrap();
+
+    let out = exe::run(&["roc", "--csv", "--precision", "3", "-p", "-w", "30", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "AUC = 0.889");
+    assert::stdout_includes(&out, "Positives = 3");
+
+    let out = exe::run(&["roc", "--csv", "--cols", "score,label", "--format", "json", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"points\": [");
+    assert::stdout_includes(&out, "\"negatives\": 3");
+
+    fs::write(&scores, "0.9 1\n0.8 2\n").unwrap();
+    assert::exit_code(&exe::run(&["roc", path]), 2);
+}
+
+#[test]
+fn test_slo() {
+    use std::env;
+    use std::fs;
+
+    let latencies = env::temp_dir().join("dent_test_slo");
+    let values: Vec<String> = (1..=200).map(|i| format!("{}ms", i)).collect();
+    fs::write(&latencies, values.join("\n")).unwrap();
+    let path = latencies.to_str().unwrap();
+
+    let out = exe::run(&["slo", "--target", "p99<250ms", "--time-unit", "ms", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "Unit: ms");
+    assert::stdout_includes(&out, "p99.9");
+    assert::stdout_includes(&out, "95% CI");
+    assert::stdout_includes(&out, "p99<250ms  198.01    pass         no");
+
+    let out = exe::run(&["slo", "--percentiles", "50", "--target", "p50<=0.1,p90<1s", "--format",
+                         "json", path]);
+    assert::exit_code(&out, 1);
+    assert::stdout_includes(&out, "\"target\": \"p50<=0.1\"");
+    assert::stdout_includes(&out, "\"met\": false");
+
+    assert::exit_code(&exe::run(&["slo", "--target", "p99=250", path]), 2);
+    assert::exit_code(&exe::run(&["slo", "--target", "p99<250B", path]), 2);
+}
+
+#[test]
+fn test_changepoint() {
+    use std::env;
+    use std::fs;
+
+    let series = env::temp_dir().join("dent_test_changepoint");
+    let values: Vec<String> = (0..40)
+        .map(|i| format!("{}", if i < 25 { 100 } else { 120 } + i % 3))
+        .collect();
+    fs::write(&series, values.join("\n")).unwrap();
+    let path = series.to_str().unwrap();
+
+    let out = exe::run(&["changepoint", "--precision", "3", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "Changes = 1");
+    assert::stdout_includes(&out, "+19.8%");
+
+    let out = exe::run(&["changepoint", "--annotate", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "# change at 26:");
+
+    let out = exe::run(&["changepoint", "--format", "json", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"changes\": [\n      26");
+
+    assert::exit_code(&exe::run(&["changepoint", "--penalty", "x", path]), 2);
+}
+
+#[test]
+fn test_survival() {
+    use std::env;
+    use std::fs;
+
+    let a = env::temp_dir().join("dent_test_survival_a");
+    let b = env::temp_dir().join("dent_test_survival_b");
+    fs::write(&a, "hours,failed\n9,1\n13,1\n13,0\n18,1\n23,1\n28,0\n31,1\n").unwrap();
+    fs::write(&b, "5 1\n5 1\n8 1\n8 1\n12 1\n16 0\n23 1\n").unwrap();
+    let (a, b) = (a.to_str().unwrap(), b.to_str().unwrap());
+
+    let out = exe::run(&["survival", "--csv", "--cols", "hours,failed", a]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_includes(&out, "At risk");
+    assert::stdout_includes(&out, "Median = 23");
+
+    let out = exe::run(&["survival", "--label", "a", "--label", "b", "--format", "json", b, b]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"source\": \"a\"");
+    assert::stdout_includes(&out, "\"log_rank\": {");
+    assert::stdout_includes(&out, "\"median_upper\": null");
+
+    let out = exe::run(&["survival", b, b]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Log-rank test:");
+
+    fs::write(a, "1 2\n").unwrap();
+    assert::exit_code(&exe::run(&["survival", a]), 2);
+}
+
+#[test]
+fn test_bucket() {
+    use std::env;
+    use std::fs;
+
+    let log = env::temp_dir().join("dent_test_bucket");
+    fs::write(&log, "time latency\n\
+                     2024-05-01T12:00:05Z 12ms\n\
+                     2024-05-01T12:00:40Z 16ms\n\
+                     2024-05-01T14:01:59+02:00 30ms\n\
+                     1714564980 20ms\n\
+                     2024-05-01T12:03:00.5Z 11ms\n").unwrap();
+    let path = log.to_str().unwrap();
+
+    let out = exe::run(&["summary", "--bucket", "1m", "--time-unit", "ms", "--fields", "n,mean",
+                         path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert_eq!(String::from_utf8_lossy(&out.stdout),
+               "Unit: ms\n\n\
+                Bucket                Size  Mean\n\
+                2024-05-01T12:00:00Z     2    14\n\
+                2024-05-01T12:01:00Z     1    30\n\
+                2024-05-01T12:03:00Z     2  15.5\n");
+
+    let out = exe::run(&["summary", "--bucket", "1h", "--format", "csv", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "Source,Unit,Size,Mean,p95\n2024-05-01T12:00:00Z,s,5,");
+
+    assert::exit_code(&exe::run(&["summary", "--bucket", "soon", path]), 2);
+
+    fs::write(&log, "2024-02-30T00:00:00Z 1\n").unwrap();
+    assert::exit_code(&exe::run(&["summary", "--bucket", "1d", path]), 2);
+}
+
+#[test]
+fn test_gen() {
+    let out = exe::run(&["gen", "normal", "--mean", "5", "-n", "50", "--seed", "1"]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+
+    let stdout = String::from_utf8_lossy(&out.stdout).into_owned();
+    let data: Vec<f64> = stdout.lines().map(|l| l.parse().unwrap()).collect();
+    assert_eq!(data.len(), 50);
+
+    // The same seed gives the same samples.
+    let again = exe::run(&["gen", "normal", "--mean", "5", "-n", "50", "--seed", "1"]);
+    assert_eq!(out.stdout, again.stdout);
+
+    let out = exe::run(&["gen", "uniform", "--min", "2", "--max", "3", "-n", "20", "--seed", "2"]);
+    assert::exit_ok(&out);
+    for x in String::from_utf8_lossy(&out.stdout).lines() {
+        let x: f64 = x.parse().unwrap();
+        assert!((2.0..=3.0).contains(&x));
+    }
+
+    let out = exe::run(&["gen", "exponential", "--rate", "0"]);
+    assert::exit_code(&out, 2);
+    assert::stderr_includes(&out, "The rate must be positive");
+
+    let out = exe::run(&["gen", "cauchy"]);
+    assert::exit_code(&out, 2);
+}
+
+#[test]
+fn test_fit() {
+    let path = &fixture::path("lognormal_0_1");
+
+    let out = exe::run(&["--fit", path]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "fit.out");
+
+    let out = exe::run(&["summary", "--fit", "--format", "json", path]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"best\": \"lognormal\"");
+
+    let out = exe::run(&["--fit", "--format", "csv", path]);
+    assert::exit_code(&out, 2);
+    assert::stderr_includes(&out, "Fits are only printed as text, Markdown, or JSON");
+}
+
+#[test]
+fn test_auto() {
+    let paths = [fixture::path("paired_a"), fixture::path("paired_b")];
+
+    let out = exe::run(&["--auto", &paths[0], &paths[1]]);
+    assert::exit_ok(&out);
+    assert::stderr_is_empty(&out);
+    assert::stdout_eq_file(&out, "auto.out");
+
+    let paths = [fixture::path("normal_0_1"), fixture::path("lognormal_2_1")];
+    let out = exe::run(&["ttest", "--auto", "--format", "json", &paths[0], &paths[1]]);
+    assert::exit_ok(&out);
+    assert::stdout_includes(&out, "\"test\": \"Welch's t-test\"");
+    assert::stdout_includes(&out, "at least 30 values");
+
+    let out = exe::run(&["--auto", &paths[0]]);
+    assert::exit_code(&out, 2);
+    assert::stderr_includes(&out, "--auto compares exactly two samples, not 1");
+}
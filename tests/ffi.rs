@@ -0,0 +1,69 @@
+#![cfg(feature = "ffi")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use std::ffi::CStr;
+use std::ptr;
+
+use dent::ffi::*;
+use dent::summary::Summary;
+use dent::t_test::welch_t_test;
+
+
+const A: [f64; 6] = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0];
+const B: [f64; 6] = [2.0, 6.0, 5.0, 3.0, 5.0, 8.0];
+
+fn summarize(data: &[f64]) -> DentSummary {
+    let mut out = DentSummary {
+        size: 0, min: 0.0, min_adjacent: 0.0, lower_quartile: 0.0, median: 0.0,
+        upper_quartile: 0.0, max_adjacent: 0.0, max: 0.0, mean: 0.0, unbiased_variance: 0.0,
+        standard_deviation: 0.0, standard_error: 0.0,
+    };
+
+    let status = unsafe { dent_summary(data.as_ptr(), data.len(), &mut out) };
+    assert_eq!(status, DentStatus::Ok);
+
+    out
+}
+
+#[test]
+fn test_summary() {
+    let s = summarize(&A);
+    let t = Summary::new(&A).unwrap();
+
+    assert_eq!(s.size, 6);
+    assert_eq!(s.mean, t.mean());
+    assert_eq!(s.median, t.median());
+    assert_eq!(s.standard_error, t.standard_error());
+}
+
+#[test]
+fn test_welch_t_test() {
+    let (a, b) = (summarize(&A), summarize(&B));
+    let mut out = DentTTest { p: 0.0, t: 0.0, df: 0.0 };
+
+    let status = unsafe { dent_welch_t_test(&a, &b, &mut out) };
+    assert_eq!(status, DentStatus::Ok);
+
+    let t = welch_t_test(&Summary::new(&A).unwrap(), &Summary::new(&B).unwrap()).unwrap();
+    assert_appx_eq!("p", 1e-12, t.p, out.p);
+    assert_appx_eq!("t", 1e-12, t.t, out.t);
+    assert_appx_eq!("DF", 1e-12, t.df, out.df);
+}
+
+#[test]
+fn test_errors() {
+    let mut a = summarize(&A);
+
+    unsafe {
+        assert_eq!(dent_summary(ptr::null(), 0, &mut a), DentStatus::EmptySample);
+        assert_eq!(dent_summary(ptr::null(), 1, &mut a), DentStatus::NullPointer);
+        assert_eq!(dent_summary([f64::NAN].as_ptr(), 1, &mut a), DentStatus::NonFinite);
+        assert_eq!(dent_welch_t_test(&a, &a, ptr::null_mut()), DentStatus::NullPointer);
+    }
+
+    let message = unsafe { CStr::from_ptr(dent_status_message(DentStatus::NonFinite)) };
+    assert_eq!(message.to_str().unwrap(), "All sample data must be finite");
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::error::Error;
+use dent::fit::{Family, fit, fit_all};
+
+
+const DATA: [f64; 8] = [1.2, 0.8, 2.5, 3.1, 1.7, 0.9, 2.2, 4.0];
+
+#[test]
+fn test_fit_normal() {
+    let f = fit(&DATA, Family::Normal).unwrap();
+    let mean = 2.05;
+    let sd = (DATA.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / 8.0).sqrt();
+
+    assert_appx_eq!("mean", 1e-12, mean, f.parameters[0]);
+    assert_appx_eq!("sd", 1e-12, sd, f.parameters[1]);
+    assert_appx_eq!("aic", 1e-10, 4.0 - 2.0 * f.log_likelihood, f.aic);
+}
+
+#[test]
+fn test_fit_log_normal_and_exponential() {
+    let f = fit(&DATA, Family::LogNormal).unwrap();
+    let mu = DATA.iter().map(|x| x.ln()).sum::<f64>() / 8.0;
+    assert_appx_eq!("mu", 1e-12, mu, f.parameters[0]);
+
+    let f = fit(&DATA, Family::Exponential).unwrap();
+    assert_appx_eq!("rate", 1e-12, 1.0 / 2.05, f.parameters[0]);
+    assert_appx_eq!("aic", 1e-10, 2.0 - 2.0 * f.log_likelihood, f.aic);
+}
+
+#[test]
+fn test_fit_gamma() {
+    let f = fit(&DATA, Family::Gamma).unwrap();
+
+    // Computed with mpmath, solving `ln k - ψ(k) = ln(mean) - mean(ln x)`.
+    assert_appx_eq!("shape", 1e-9, 3.680015469663303, f.parameters[0]);
+    assert_appx_eq!("rate", 1e-9, 1.795129497396733, f.parameters[1]);
+    assert_appx_eq!("log-likelihood", 1e-9, -11.1073171950042, f.log_likelihood);
+    assert_appx_eq!("aic", 1e-9, 26.21463439000839, f.aic);
+    assert_appx_eq!("ks", 1e-9, 0.1519176801847803, f.ks);
+}
+
+#[test]
+fn test_fit_all() {
+    let fits = fit_all(&DATA).unwrap();
+
+    assert_eq!(fits.len(), 4);
+    for pair in fits.windows(2) {
+        assert!(pair[0].aic <= pair[1].aic);
+    }
+
+    // Only the normal family admits data which are not all positive.
+    let fits = fit_all(&[-1.0, 0.5, 2.0, 3.5]).unwrap();
+    assert_eq!(fits.len(), 1);
+    assert_eq!(fits[0].family, Family::Normal);
+
+    // Only the exponential family admits data without variation.
+    let fits = fit_all(&[2.0, 2.0, 2.0]).unwrap();
+    assert_eq!(fits.len(), 1);
+    assert_eq!(fits[0].family, Family::Exponential);
+}
+
+#[test]
+fn test_fit_errors() {
+    match fit(&[-1.0, 2.0], Family::Gamma) {
+        Err(Error::BadSample) => {},
+        r => panic!("Expected BadSample, got {:?}", r),
+    }
+    match fit(&[], Family::Normal) {
+        Err(Error::EmptySample) => {},
+        r => panic!("Expected EmptySample, got {:?}", r),
+    }
+    match fit(&[1.0, f64::NAN], Family::Normal) {
+        Err(Error::NonFinite) => {},
+        r => panic!("Expected NonFinite, got {:?}", r),
+    }
+}
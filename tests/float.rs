@@ -0,0 +1,45 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::lr::LinearRegression;
+use dent::summary::{Summarizer, Summary};
+use dent::t_test::{paired_t_test, paired_t_test_f32};
+
+
+const DATA: [f32; 8] = [1.2, 0.8, 2.5, 3.1, 1.7, 0.9, 2.2, 4.0];
+
+fn widen(data: &[f32]) -> Vec<f64> {
+    data.iter().map(|&x| f64::from(x)).collect()
+}
+
+#[test]
+fn test_summary_f32() {
+    let s32 = Summary::from_f32(&DATA).unwrap();
+    let s64 = Summary::new(&widen(&DATA)).unwrap();
+
+    assert_eq!(s32.mean(), s64.mean());
+    assert_eq!(s32.standard_deviation(), s64.standard_deviation());
+    assert_eq!(s32.median(), s64.median());
+
+    let s = Summarizer::from_f32(&DATA).unwrap();
+    assert_eq!(s.as_slice()[0], f64::from(0.8f32));
+
+    assert!(Summary::from_f32(&[]).is_err());
+    assert!(Summary::from_f32(&[1.0, f32::NAN]).is_err());
+}
+
+#[test]
+fn test_paired_f32() {
+    let pairs: Vec<(f32, f32)> = DATA.iter().map(|&x| (x, 2.0 * x + 0.5)).collect();
+    let wide: Vec<(f64, f64)> = pairs.iter().map(|&(x, y)| (f64::from(x), f64::from(y))).collect();
+
+    let lr = LinearRegression::from_f32(&pairs).unwrap();
+    assert_eq!(lr.slope(), LinearRegression::new(&wide).unwrap().slope());
+    assert_appx_eq!("Slope", 1e-6, 2.0, lr.slope());
+
+    let t = paired_t_test_f32(&pairs).unwrap();
+    assert_eq!(t.p, paired_t_test(&wide).unwrap().p);
+}
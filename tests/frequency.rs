@@ -0,0 +1,27 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+use dent::frequency::frequencies;
+
+
+#[test]
+fn test_frequencies() {
+    let f = frequencies(&[200.0, 404.0, 200.0, 500.0, 200.0, 404.0, 301.0, 200.0]).unwrap();
+
+    let values: Vec<f64> = f.iter().map(|f| f.value).collect();
+    assert_eq!(values, [200.0, 301.0, 404.0, 500.0]);
+
+    let counts: Vec<usize> = f.iter().map(|f| f.count).collect();
+    assert_eq!(counts, [4, 1, 2, 1]);
+
+    assert_eq!(f[0].proportion, 0.5);
+    assert_eq!(f[2].cumulative, 0.875);
+    assert_eq!(f[3].cumulative, 1.0);
+}
+
+#[test]
+fn test_frequencies_errors() {
+    assert!(frequencies(&[]).is_err());
+    assert!(frequencies(&[1.0, f64::NAN]).is_err());
+}
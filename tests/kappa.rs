@@ -0,0 +1,71 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+use dent::kappa::{ConfusionMatrix, Weights, cohens_kappa};
+
+
+/// Diagnoses of 100 patients by two psychiatrists, from Fleiss, Cohen, and
+/// Everitt (1969).
+fn diagnoses() -> Vec<Vec<f64>> {
+    vec![
+        vec![75.0, 1.0, 4.0],
+        vec![5.0, 4.0, 1.0],
+        vec![0.0, 0.0, 10.0],
+    ]
+}
+
+#[test]
+fn test_cohens_kappa() {
+    let k = cohens_kappa(&diagnoses(), Weights::Unweighted).unwrap();
+
+    assert!((k.observed - 0.89).abs() < 1e-12);
+    assert!((k.expected - 0.66).abs() < 1e-12);
+    assert!((k.kappa - 0.6764706).abs() < 1e-7);
+    assert!((k.standard_error - 0.0877030).abs() < 1e-7);
+    assert!((k.z - 8.879052).abs() < 1e-6);
+    assert!(k.p < 1e-15);
+    assert_eq!(k.size, 100.0);
+}
+
+#[test]
+fn test_weighted_kappa() {
+    let k = cohens_kappa(&diagnoses(), Weights::Linear).unwrap();
+    assert!((k.kappa - 0.7222222).abs() < 1e-7);
+    assert!((k.standard_error - 0.0843010).abs() < 1e-7);
+
+    let k = cohens_kappa(&diagnoses(), Weights::Quadratic).unwrap();
+    assert!((k.kappa - 0.7553191).abs() < 1e-7);
+    assert!((k.standard_error - 0.0867071).abs() < 1e-7);
+
+    // With two categories, every weighting is the same.
+    let two = [vec![20.0, 5.0], vec![10.0, 15.0]];
+    let k = cohens_kappa(&two, Weights::Unweighted).unwrap();
+    assert_eq!(k.kappa, cohens_kappa(&two, Weights::Quadratic).unwrap().kappa);
+    assert!((k.kappa - 0.4).abs() < 1e-12);
+}
+
+#[test]
+fn test_cohens_kappa_errors() {
+    assert!(cohens_kappa(&[], Weights::Unweighted).is_err());
+    assert!(cohens_kappa(&[vec![0.0, 0.0], vec![0.0, 0.0]], Weights::Unweighted).is_err());
+    assert!(cohens_kappa(&[vec![1.0, 2.0]], Weights::Unweighted).is_err());
+    assert!(cohens_kappa(&[vec![1.0, -2.0], vec![3.0, 4.0]], Weights::Unweighted).is_err());
+
+    // Chance explains all agreement in a single category.
+    assert!(cohens_kappa(&[vec![5.0]], Weights::Unweighted).is_err());
+}
+
+#[test]
+fn test_confusion_matrix() {
+    let pairs = [("10", "2"), ("2", "2"), ("10", "10"), ("1", "2")];
+    let m = ConfusionMatrix::new(&pairs);
+
+    // Numeric labels are in numeric order.
+    assert_eq!(m.categories, ["1", "2", "10"]);
+    assert_eq!(m.counts, [[0.0, 1.0, 0.0], [0.0, 1.0, 0.0], [0.0, 1.0, 1.0]]);
+
+    let m = ConfusionMatrix::new(&[("b", "a"), ("a", "a")]);
+    assert_eq!(m.categories, ["a", "b"]);
+    assert_eq!(m.counts, [[1.0, 0.0], [1.0, 0.0]]);
+}
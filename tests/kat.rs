@@ -1,3 +1,5 @@
+#![cfg(feature = "std")]
+
 extern crate dent;
 
 #[macro_use] mod support;
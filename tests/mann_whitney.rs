@@ -0,0 +1,90 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::mann_whitney::{hodges_lehmann, mann_whitney};
+use dent::summary::Summarizer;
+
+
+#[test]
+fn test_mann_whitney_ties() {
+    // The example of R's `wilcox.test`, whose `W` is the U of the first sample,
+    // with its greatest value 3.14 lowered to 3.13, which keeps the ranks.
+    let a = Summarizer::new(&[1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30]).unwrap();
+    let b = Summarizer::new(&[0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.13, 1.29]).unwrap();
+
+    let mw = mann_whitney(&a, &b).unwrap();
+
+    assert_eq!(mw.u, 81.0 - 58.0);
+    assert_appx_eq!("p", 1e-4, 0.1329, mw.p);
+    assert!(!mw.exact);
+}
+
+#[test]
+fn test_mann_whitney_exact() {
+    let a = Summarizer::new(&[1.1, 2.2, 3.3]).unwrap();
+    let b = Summarizer::new(&[4.4, 5.5, 6.6, 7.7]).unwrap();
+
+    // Only 1 of the C(7, 3) = 35 orderings is as extreme in each direction.
+    let mw = mann_whitney(&a, &b).unwrap();
+    assert_eq!(mw.u, 12.0);
+    assert_appx_eq!("p", 1e-12, 2.0 / 35.0, mw.p);
+    assert!(mw.exact);
+
+    let mw = mann_whitney(&b, &a).unwrap();
+    assert_eq!(mw.u, 0.0);
+    assert_appx_eq!("p", 1e-12, 2.0 / 35.0, mw.p);
+
+    // The p-value of the most central `U` is capped at 1.
+    let c = Summarizer::new(&[1.0, 4.0]).unwrap();
+    let d = Summarizer::new(&[2.0, 3.0]).unwrap();
+    assert_eq!(mann_whitney(&c, &d).unwrap().p, 1.0);
+}
+
+/// All differences of a value of `b` less one of `a`, sorted.
+fn differences(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut d: Vec<f64> = a.iter().flat_map(|x| b.iter().map(move |y| y - x)).collect();
+    d.sort_by(|x, y| x.total_cmp(y));
+
+    d
+}
+
+#[test]
+fn test_hodges_lehmann_exact() {
+    let (a, b) = ([1.1, 2.2, 3.3], [4.4, 5.5, 6.6, 7.7]);
+    let (s1, s2) = (Summarizer::new(&a).unwrap(), Summarizer::new(&b).unwrap());
+    let d = differences(&a, &b);
+
+    let hl = hodges_lehmann(&s1, &s2, 0.95).unwrap();
+    assert!(hl.exact);
+    assert_appx_eq!("shift", 1e-12, (d[5] + d[6]) / 2.0, hl.shift);
+
+    // No `U` is as unlikely as 2.5%, so the interval spans every difference.
+    assert_eq!((hl.lower, hl.upper), (d[0], d[11]));
+
+    // P(U <= 2) = 4/35 is the first at least 10%, so the bounds are the
+    // second least and greatest differences.
+    let hl = hodges_lehmann(&s1, &s2, 0.8).unwrap();
+    assert_eq!((hl.lower, hl.upper), (d[1], d[10]));
+
+    assert!(hodges_lehmann(&s1, &s2, 1.0).is_err());
+}
+
+#[test]
+fn test_hodges_lehmann_approximate() {
+    // R's `wilcox.test` example again, as in `test_mann_whitney_ties`.
+    let a = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+    let b = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.13, 1.29];
+    let (s1, s2) = (Summarizer::new(&a).unwrap(), Summarizer::new(&b).unwrap());
+    let d = differences(&a, &b);
+
+    // With ties, the interval is from the normal approximation, whose rank
+    // of the lower bound is ⌈81/2 - 1/2 - 1.96 · 11.6⌉ = 18.
+    let hl = hodges_lehmann(&s1, &s2, 0.95).unwrap();
+    assert!(!hl.exact);
+    assert_eq!(hl.shift, d[40]);
+    assert_eq!((hl.lower, hl.upper), (d[17], d[63]));
+    assert!(hl.lower < hl.shift && hl.shift < hl.upper);
+}
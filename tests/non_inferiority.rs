@@ -0,0 +1,73 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::non_inferiority::{Margin, non_inferiority};
+use dent::summary::Summary;
+use dent::t_test::{Alternative, welch_t_test};
+
+
+// Cushny and Peebles' hours of extra sleep for two drugs, as in R's `sleep`
+// data set, here taken as independent samples.
+const A: [f64; 10] = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+const B: [f64; 10] = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+
+#[test]
+fn test_non_inferiority_absolute() {
+    let a = Summary::new(&A).unwrap();
+    let b = Summary::new(&B).unwrap();
+
+    let ni = non_inferiority(&a, &b, Margin::Absolute(3.5), 0.95).unwrap();
+    assert_appx_eq!("change", 1e-14, 1.58, ni.change);
+    assert!(ni.non_inferior());
+
+    // With an absolute margin, it is a one-sided Welch t-test of the new
+    // sample shifted down by the margin.
+    let shifted: Vec<f64> = B.iter().map(|x| x - 3.5).collect();
+    let t_test = welch_t_test(&a, &Summary::new(&shifted).unwrap()).unwrap()
+        .with_alternative(Alternative::Less)
+        .unwrap();
+    assert_appx_eq!("p", 1e-12, t_test.p, ni.p);
+    assert_appx_eq!("df", 1e-12, t_test.df, ni.df);
+
+    // A margin at the bound of the interval is just significant.
+    let bound = non_inferiority(&a, &b, Margin::Absolute(ni.upper), 0.95).unwrap();
+    assert_appx_eq!("p", 1e-9, 0.05, bound.p);
+
+    let ni = non_inferiority(&a, &b, Margin::Absolute(1.0), 0.95).unwrap();
+    assert!(!ni.non_inferior());
+    assert!(ni.p > 0.05);
+}
+
+#[test]
+fn test_non_inferiority_relative() {
+    let a = Summary::new(&B).unwrap();
+    let b = Summary::new(&A).unwrap();
+
+    // The new sample is better by 68%, with a wide interval.
+    let ni = non_inferiority(&a, &b, Margin::Relative(0.05), 0.95).unwrap();
+    assert_appx_eq!("change", 1e-14, 0.75 / 2.33 - 1.0, ni.change);
+    assert!(ni.non_inferior());
+    assert!(ni.p < 0.05);
+
+    let bound = non_inferiority(&a, &b, Margin::Relative(ni.upper), 0.95).unwrap();
+    assert_appx_eq!("p", 1e-9, 0.05, bound.p);
+
+    // A stricter level widens the interval.
+    let strict = non_inferiority(&a, &b, Margin::Relative(0.05), 0.999).unwrap();
+    assert!(strict.upper > ni.upper);
+    assert_eq!(ni.p, strict.p);
+}
+
+#[test]
+fn test_non_inferiority_undefined() {
+    let a = Summary::new(&A).unwrap();
+    let b = Summary::new(&B).unwrap();
+
+    assert!(non_inferiority(&a, &b, Margin::Absolute(1.0), 0.4).is_err());
+    assert!(non_inferiority(&a, &b, Margin::Relative(f64::NAN), 0.95).is_err());
+    assert!(non_inferiority(&Summary::new(&[0.0, 0.0]).unwrap(), &b, Margin::Relative(0.05),
+                            0.95).is_err());
+}
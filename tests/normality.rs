@@ -0,0 +1,53 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::normality::shapiro_wilk;
+use dent::summary::Summarizer;
+
+
+#[test]
+fn test_shapiro_wilk() {
+    // Values from R's `shapiro.test`.
+    let s = Summarizer::new(&[148.0, 154.0, 158.0, 160.0, 161.0, 162.0, 166.0, 170.0, 182.0,
+                              195.0, 236.0]).unwrap();
+    let sw = shapiro_wilk(&s).unwrap();
+    assert_appx_eq!("W", 1e-5, 0.78881, sw.w);
+    assert_appx_eq!("p", 1e-6, 0.006704, sw.p);
+
+    let s = Summarizer::new(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+    let sw = shapiro_wilk(&s).unwrap();
+    assert_appx_eq!("W", 1e-5, 0.98676, sw.w);
+    assert_appx_eq!("p", 1e-4, 0.9672, sw.p);
+}
+
+#[test]
+fn test_shapiro_wilk_three() {
+    // With 3 values, the distribution of W is known exactly.
+    let s = Summarizer::new(&[1.0, 2.0, 4.0]).unwrap();
+    let sw = shapiro_wilk(&s).unwrap();
+
+    assert_appx_eq!("W", 1e-12, 27.0 / 28.0, sw.w);
+    assert_appx_eq!("p", 1e-4, 0.6369, sw.p);
+}
+
+#[test]
+fn test_shapiro_wilk_large() {
+    // Evenly spaced quantiles of the normal distribution look normal.
+    let z = dent::dist::Normal::standard();
+    let data: Vec<f64> = (1..=200)
+        .map(|i| dent::dist::Distribution::quantile(&z, i as f64 / 201.0).unwrap())
+        .collect();
+    let sw = shapiro_wilk(&Summarizer::new(&data).unwrap()).unwrap();
+
+    assert!(sw.w > 0.99);
+    assert!(sw.p > 0.5);
+}
+
+#[test]
+fn test_shapiro_wilk_undefined() {
+    assert!(shapiro_wilk(&Summarizer::new(&[1.0, 2.0]).unwrap()).is_err());
+    assert!(shapiro_wilk(&Summarizer::new(&[3.0, 3.0, 3.0, 3.0]).unwrap()).is_err());
+}
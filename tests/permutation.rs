@@ -0,0 +1,37 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::permutation::permutation_test;
+use dent::summary::Summarizer;
+
+
+#[test]
+fn test_permutation_test() {
+    let a = Summarizer::new(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]).unwrap();
+    let b = Summarizer::new(&[11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0]).unwrap();
+
+    let perm = permutation_test(&a, &b, 1000).unwrap();
+    assert_eq!(perm.difference, 10.0);
+    assert_eq!(perm.resamples, 1000);
+
+    // Of the C(16, 8) = 12870 labelings, only 2 are as extreme.
+    assert!(perm.p < 0.01);
+
+    // The generator is seeded, so the result is reproducible.
+    assert_eq!(perm.p, permutation_test(&a, &b, 1000).unwrap().p);
+}
+
+#[test]
+fn test_permutation_test_no_difference() {
+    let a = Summarizer::new(&[1.0, 2.0, 3.0]).unwrap();
+    let b = Summarizer::new(&[3.0, 1.0, 2.0]).unwrap();
+
+    let perm = permutation_test(&a, &b, 100).unwrap();
+    assert_eq!(perm.difference, 0.0);
+    assert_eq!(perm.p, 1.0);
+
+    assert!(permutation_test(&a, &b, 0).is_err());
+}
@@ -0,0 +1,130 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+mod support;
+
+use dent::plot::{self, PlotOptions};
+use dent::summary::Summary;
+use support::fixture;
+use support::fs::read_data;
+
+
+fn summary(name: &str) -> Summary {
+    Summary::new(&read_data(&fixture::path(name))).unwrap()
+}
+
+#[test]
+fn test_comparison_plot_labels() {
+    let s1 = summary("normal_0_1");
+    let s2 = summary("normal_5_2");
+
+    let opts = PlotOptions::new().width(60).labels(&["old", "new"]);
+    let p = plot::comparison_plot(&[&s1, &s2], &opts).unwrap();
+
+    assert_eq!(p, fixture::read("plot_labels.out"));
+}
+
+#[test]
+fn test_comparison_plot_label_count() {
+    let s1 = summary("normal_0_1");
+    let s2 = summary("normal_5_2");
+
+    let opts = PlotOptions::new().labels(&["old"]);
+
+    assert!(plot::comparison_plot(&[&s1, &s2], &opts).is_err());
+}
+
+#[test]
+fn test_comparison_plot_to() {
+    let s1 = summary("normal_0_1");
+    let s2 = summary("normal_5_2");
+
+    let opts = PlotOptions::new().width(60).labels(&["old", "new"]);
+    let mut buf: Vec<u8> = vec![];
+    plot::comparison_plot_to(&mut buf, &[&s1, &s2], &opts).unwrap();
+
+    let expected = format!("{}\n", fixture::read("plot_labels.out"));
+    assert_eq!(String::from_utf8(buf).unwrap(), expected);
+}
+
+#[test]
+fn test_summary_plot_to_error() {
+    let s = summary("normal_0_1");
+
+    let opts = PlotOptions::new().log_scale(true);
+    let mut buf: Vec<u8> = vec![];
+
+    assert!(plot::summary_plot_to(&mut buf, &s, &opts).is_err());
+    assert!(buf.is_empty());
+}
+
+#[test]
+fn test_svg_comparison_plot() {
+    let s1 = summary("normal_0_1");
+    let s2 = summary("normal_5_2");
+
+    let opts = PlotOptions::new().width(90);
+    let svg = plot::svg::comparison_plot(&[&s1, &s2], &opts).unwrap();
+
+    assert_eq!(svg, fixture::read("comparison_plot.svg"));
+}
+
+#[test]
+fn test_svg_histogram_plot() {
+    use dent::histogram::Histogram;
+
+    let hist = Histogram::new(&[1.0, 2.0, 2.5, 3.0, 4.0], 3).unwrap();
+    let svg = plot::svg::histogram_plot(&hist, &PlotOptions::new()).unwrap();
+
+    // One bar per bin, the densest spanning the plot area, each labeled by the
+    // lower edge of its bin, as in a text histogram.
+    assert_eq!(svg.matches("<rect x=\"65.0\"").count(), 3);
+    assert!(svg.contains("width=\"538.0\" height=\"14.0\""));
+    assert!(svg.contains(">2.000</text>"));
+    assert!(svg.ends_with("</svg>\n"));
+
+    assert!(plot::svg::histogram_plot(&hist, &PlotOptions::new().width(5)).is_err());
+}
+
+#[test]
+fn test_svg_scatter_plot() {
+    let points = [(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)];
+    let svg = plot::svg::scatter_plot(&points, &PlotOptions::new().height(8)).unwrap();
+
+    assert_eq!(svg.matches("<circle").count(), 3);
+    // The least and greatest y are at the bottom and top of the plot area.
+    assert!(svg.contains("cy=\"148.0\""));
+    assert!(svg.contains("cy=\"20.0\""));
+    assert!(!svg.contains("stroke-dasharray"));
+
+    assert!(plot::svg::scatter_plot(&[], &PlotOptions::new()).is_err());
+    assert!(plot::svg::scatter_plot(&[(0.0, f64::NAN)], &PlotOptions::new()).is_err());
+}
+
+#[test]
+fn test_svg_bland_altman_plot() {
+    use dent::bland_altman::BlandAltman;
+
+    let pairs = [(1.0, 1.5), (2.0, 2.25), (3.0, 3.0), (4.0, 4.5)];
+    let ba = BlandAltman::new(&pairs).unwrap();
+    let svg = plot::svg::bland_altman_plot(&ba, &PlotOptions::new()).unwrap();
+
+    assert_eq!(svg.matches("<circle").count(), 4);
+    // Rules at the bias and the limits of agreement.
+    let rules = svg.split("stroke-dasharray").nth(1).unwrap().split("</g>").next().unwrap();
+    assert_eq!(rules.matches("<line").count(), 3);
+}
+
+#[test]
+fn test_histogram_plot_precision() {
+    use dent::histogram::Histogram;
+
+    let hist = Histogram::new(&[1.23456, 2.0, 3.0, 4.0], 2).unwrap();
+
+    let p = plot::histogram_plot(&hist, &PlotOptions::new().width(40)).unwrap();
+    assert!(p.starts_with("1.235 "));
+
+    let p = plot::histogram_plot(&hist, &PlotOptions::new().width(40).precision(2)).unwrap();
+    assert!(p.starts_with("1.2 "));
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "python")]
+
+extern crate dent;
+
+use dent::lr;
+use dent::python::{LinearRegression, Summary, welch_t_test};
+use dent::summary;
+use dent::t_test;
+
+
+const A: [f64; 6] = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0];
+const B: [f64; 6] = [2.0, 6.0, 5.0, 3.0, 5.0, 8.0];
+
+#[test]
+fn test_summary() {
+    let s = Summary::new(A.to_vec()).unwrap();
+    let t = summary::Summary::new(&A).unwrap();
+
+    assert_eq!(s.size(), t.size());
+    assert_eq!(s.mean(), t.mean());
+    assert_eq!(s.upper_quartile(), t.upper_quartile());
+
+    assert!(Summary::new(vec![]).is_err());
+}
+
+#[test]
+fn test_welch_t_test() {
+    let (a, b) = (Summary::new(A.to_vec()).unwrap(), Summary::new(B.to_vec()).unwrap());
+    let (s1, s2) = (summary::Summary::new(&A).unwrap(), summary::Summary::new(&B).unwrap());
+
+    let t = welch_t_test(&a, &b).unwrap();
+    assert_eq!(t.p, t_test::welch_t_test(&s1, &s2).unwrap().p);
+}
+
+#[test]
+fn test_linear_regression() {
+    let data: Vec<(f64, f64)> = A.iter().cloned().zip(B.iter().cloned()).collect();
+    let l = LinearRegression::new(data.clone()).unwrap();
+
+    assert_eq!(l.slope(), lr::LinearRegression::new(&data).unwrap().slope());
+}
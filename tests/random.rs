@@ -0,0 +1,57 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::dist::{Exponential, Normal, Uniform};
+use dent::random::Rng;
+use dent::summary::Summary;
+
+
+#[test]
+fn test_seed_reproduces_samples() {
+    let mut a = Rng::new(42);
+    let mut b = Rng::new(42);
+    let mut c = Rng::new(43);
+
+    let xs: Vec<u64> = (0..100).map(|_| a.next_u64()).collect();
+    let ys: Vec<u64> = (0..100).map(|_| b.next_u64()).collect();
+    let zs: Vec<u64> = (0..100).map(|_| c.next_u64()).collect();
+
+    assert_eq!(xs, ys);
+    assert_ne!(xs, zs);
+}
+
+#[test]
+fn test_next_f64_is_in_open_unit_interval() {
+    let mut rng = Rng::new(0);
+
+    for _ in 0..10000 {
+        let u = rng.next_f64();
+        assert!(0.0 < u && u < 1.0, "{} is not in (0, 1)", u);
+    }
+}
+
+#[test]
+fn test_sample_moments() {
+    let mut rng = Rng::new(7);
+    let n = 20000;
+
+    let normal = Normal::new(10.0, 2.0).unwrap();
+    let data: Vec<f64> = (0..n).map(|_| rng.sample(&normal).unwrap()).collect();
+    let s = Summary::new(&data).unwrap();
+    assert_appx_eq!("normal mean", 0.1, 10.0, s.mean());
+    assert_appx_eq!("normal sd", 0.1, 2.0, s.standard_deviation());
+
+    let exp = Exponential::new(4.0).unwrap();
+    let data: Vec<f64> = (0..n).map(|_| rng.sample(&exp).unwrap()).collect();
+    let s = Summary::new(&data).unwrap();
+    assert_appx_eq!("exponential mean", 0.01, 0.25, s.mean());
+
+    let uniform = Uniform::new(-1.0, 3.0).unwrap();
+    let data: Vec<f64> = (0..n).map(|_| rng.sample(&uniform).unwrap()).collect();
+    let s = Summary::new(&data).unwrap();
+    assert!(-1.0 <= s.min() && s.max() <= 3.0);
+    assert_appx_eq!("uniform mean", 0.05, 1.0, s.mean());
+}
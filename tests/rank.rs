@@ -0,0 +1,25 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+use dent::rank::{joint_ranks, ranks};
+
+
+#[test]
+fn test_ranks() {
+    assert_eq!(ranks(&[]), Vec::<f64>::new());
+    assert_eq!(ranks(&[3.0, 1.0, 2.0]), vec![3.0, 1.0, 2.0]);
+
+    // Ties share the mean of their ranks.
+    assert_eq!(ranks(&[10.0, 20.0, 10.0, 30.0, 10.0]), vec![2.0, 4.0, 2.0, 5.0, 2.0]);
+    assert_eq!(ranks(&[-1.5, 7.0, 7.0, -2.0]), vec![2.0, 3.5, 3.5, 1.0]);
+}
+
+#[test]
+fn test_joint_ranks() {
+    let a = [1.2, 3.4, 0.5];
+    let b = [3.4, 2.0];
+
+    assert_eq!(joint_ranks(&[&a, &b]), vec![vec![2.0, 4.5, 1.0], vec![4.5, 3.0]]);
+    assert_eq!(joint_ranks(&[&a]), vec![ranks(&a)]);
+}
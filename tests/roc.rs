@@ -0,0 +1,62 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+use dent::roc::Roc;
+
+
+fn detections() -> Vec<(f64, bool)> {
+    vec![
+        (0.9, true), (0.8, true), (0.7, false), (0.6, true), (0.55, true), (0.54, false),
+        (0.53, true), (0.52, false), (0.51, true), (0.505, false), (0.4, true), (0.39, false),
+        (0.38, true), (0.37, false), (0.36, false), (0.35, false), (0.34, true), (0.33, false),
+        (0.3, true), (0.1, false), (0.7, true),
+    ]
+}
+
+#[test]
+fn test_roc() {
+    let roc = Roc::new(&detections(), 0.95).unwrap();
+
+    assert_eq!((roc.positives, roc.negatives), (11, 10));
+    assert!((roc.auc - 0.6954545).abs() < 1e-7);
+    assert!((roc.standard_error - 0.1201698).abs() < 1e-7);
+    assert!((roc.lower - 0.4599261).abs() < 1e-7);
+    assert!((roc.upper - 0.9309829).abs() < 1e-7);
+
+    // At 90%, the interval is ±1.644854 standard errors.
+    let roc = Roc::new(&detections(), 0.9).unwrap();
+    assert_eq!(roc.confidence, 0.9);
+    assert!((roc.lower - 0.4977928).abs() < 1e-6);
+    assert!((roc.upper - 0.8931163).abs() < 1e-6);
+}
+
+#[test]
+fn test_roc_curve() {
+    let roc = Roc::new(&detections(), 0.95).unwrap();
+    let first = roc.points[0];
+    let last = roc.points[roc.points.len() - 1];
+
+    assert_eq!((first.false_positive_rate, first.true_positive_rate), (0.0, 0.0));
+    assert_eq!((last.false_positive_rate, last.true_positive_rate), (1.0, 1.0));
+    assert_eq!(last.threshold, 0.1);
+
+    // The tied scores of 0.7 are one point, flagging a positive and a
+    // negative at once.
+    assert_eq!(roc.points.len(), 21);
+    let tied = roc.points.iter().find(|p| p.threshold == 0.7).unwrap();
+    assert_eq!((tied.false_positive_rate, tied.true_positive_rate), (0.1, 3.0 / 11.0));
+
+    // A perfect detector.
+    let roc = Roc::new(&[(1.0, true), (2.0, true), (0.5, false), (0.0, false)], 0.95).unwrap();
+    assert_eq!(roc.auc, 1.0);
+    assert_eq!(roc.upper, 1.0);
+}
+
+#[test]
+fn test_roc_errors() {
+    assert!(Roc::new(&[], 0.95).is_err());
+    assert!(Roc::new(&detections(), 1.0).is_err());
+    assert!(Roc::new(&[(1.0, true), (2.0, true), (0.5, false)], 0.95).is_err());
+    assert!(Roc::new(&[(1.0, true), (f64::NAN, true), (0.5, false), (0.0, false)], 0.95).is_err());
+}
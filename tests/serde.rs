@@ -0,0 +1,51 @@
+#![cfg(feature = "serde")]
+
+extern crate dent;
+extern crate serde_json;
+
+use dent::lr::LinearRegression;
+use dent::summary::Summary;
+use dent::t_test::{TTest, welch_t_test};
+
+
+#[test]
+fn test_summary_round_trip() {
+    let s = Summary::new(&[3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0]).unwrap();
+
+    let json = serde_json::to_string(&s).unwrap();
+    let t: Summary = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(s.size(), t.size());
+    assert_eq!(s.mean(), t.mean());
+    assert_eq!(s.median(), t.median());
+    assert_eq!(s.standard_deviation(), t.standard_deviation());
+    assert_eq!(s.max_adjacent(), t.max_adjacent());
+}
+
+#[test]
+fn test_t_test_round_trip() {
+    let s1 = Summary::new(&[1.0, 2.0, 3.0, 4.0]).unwrap();
+    let s2 = Summary::new(&[2.0, 4.0, 6.0, 8.0]).unwrap();
+    let t_test = welch_t_test(&s1, &s2).unwrap();
+
+    let json = serde_json::to_value(&t_test).unwrap();
+    assert_eq!(json["p"].as_f64(), Some(t_test.p));
+    assert_eq!(json["t"].as_f64(), Some(t_test.t));
+    assert_eq!(json["df"].as_f64(), Some(t_test.df));
+
+    let u: TTest = serde_json::from_value(json).unwrap();
+    assert_eq!(u.p, t_test.p);
+}
+
+#[test]
+fn test_linear_regression_round_trip() {
+    let lr = LinearRegression::new(&[(0.0, 1.0), (1.0, 3.1), (2.0, 4.9), (3.0, 7.2)]).unwrap();
+
+    let json = serde_json::to_string(&lr).unwrap();
+    let lr2: LinearRegression = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(lr.slope(), lr2.slope());
+    assert_eq!(lr.intercept(), lr2.intercept());
+    assert_eq!(lr.r(), lr2.r());
+    assert_eq!(lr.standard_error(), lr2.standard_error());
+}
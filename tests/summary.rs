@@ -0,0 +1,164 @@
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::summary::{Summarizer, Summary};
+
+
+#[test]
+fn test_geometric_mean() {
+    let s = Summarizer::new(&[8.0, 1.0, 4.0, 2.0]).unwrap();
+    assert_appx_eq!("Geomean", 1e-15, 8f64.sqrt(), s.geometric_mean().unwrap());
+
+    let s = Summarizer::new(&[0.0, 1.0, 2.0]).unwrap();
+    assert!(s.geometric_mean().is_err());
+}
+
+#[test]
+fn test_harmonic_mean() {
+    // The overall rate of equal work at 40 and 60 per second is 48 per second.
+    let s = Summarizer::new(&[40.0, 60.0]).unwrap();
+    assert_appx_eq!("Hmean", 1e-12, 48.0, s.harmonic_mean().unwrap());
+
+    let s = Summarizer::new(&[-1.0, 1.0, 2.0]).unwrap();
+    assert!(s.harmonic_mean().is_err());
+}
+
+#[test]
+fn test_interquartile_mean() {
+    let s = Summarizer::new(&[5.0, 8.0, 4.0, 38.0, 8.0, 6.0, 9.0, 7.0, 7.0, 3.0, 1.0, 6.0])
+        .unwrap();
+    assert_appx_eq!("IQM", 1e-15, 6.5, s.interquartile_mean());
+
+    // Weights of 3/4 for the values at the quartiles.
+    let s = Summarizer::new(&[1.0, 3.0, 5.0, 7.0, 9.0, 11.0, 13.0, 15.0, 17.0]).unwrap();
+    assert_appx_eq!("IQM", 1e-15, 9.0, s.interquartile_mean());
+
+    let s = Summarizer::new(&[3.0]).unwrap();
+    assert_eq!(s.interquartile_mean(), 3.0);
+
+    let data = [1.0, 2.0, 4.0, 8.0, 16.0];
+    let iqm = Summarizer::new(&data).unwrap().interquartile_mean();
+    assert_eq!(Summary::with_data(&data).unwrap().interquartile_mean(), Some(iqm));
+    assert_eq!(Summary::new(&data).unwrap().interquartile_mean(), None);
+}
+
+#[test]
+fn test_midhinge() {
+    let data = [1.0, 2.0, 4.0, 8.0, 16.0];
+    let s = Summarizer::new(&data).unwrap();
+
+    assert_eq!(s.midhinge(), (2.0 + 8.0) / 2.0);
+    assert_eq!(Summary::new(&data).unwrap().midhinge(), s.midhinge());
+}
+
+#[test]
+fn test_median_absolute_deviation() {
+    let s = Summarizer::new(&[1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0]).unwrap();
+    assert_eq!(s.median_absolute_deviation(), 1.0);
+
+    let s = Summarizer::new(&[3.0]).unwrap();
+    assert_eq!(s.median_absolute_deviation(), 0.0);
+}
+
+#[test]
+fn test_summary_from_summarizer() {
+    let data = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0];
+    let s = Summarizer::new(&data).unwrap();
+
+    let from = Summary::from(&s);
+    let new = Summary::new(&data).unwrap();
+
+    assert_eq!(format!("{:?}", from), format!("{:?}", new));
+}
+
+#[test]
+fn test_summary_with_data() {
+    let data = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0];
+    let s = Summary::with_data(&data).unwrap();
+
+    assert_eq!(s.sorted(), Some(&[1.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 9.0][..]));
+    assert_eq!(s.mean(), Summary::new(&data).unwrap().mean());
+    assert_eq!(Summary::new(&data).unwrap().sorted(), None);
+
+    let s = Summary::from(Summarizer::new(&data).unwrap());
+    assert_eq!(s.sorted().map(|d| d.len()), Some(8));
+}
+
+#[test]
+fn test_summary_from_parts() {
+    let data = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0];
+    let s = Summary::new(&data).unwrap();
+
+    let parts = Summary::from_parts(
+        8,
+        s.mean(),
+        s.unbiased_variance(),
+        (s.min(), s.max()),
+        (s.min_adjacent(), s.max_adjacent()),
+        (s.lower_quartile(), s.median(), s.upper_quartile()),
+    ).unwrap();
+
+    assert_eq!(format!("{:?}", parts), format!("{:?}", s));
+
+    assert!(Summary::from_parts(0, 0.0, 0.0, (0.0, 0.0), (0.0, 0.0), (0.0, 0.0, 0.0)).is_err());
+}
+
+#[test]
+fn test_percentile_interval() {
+    let ranks = |n: usize, p: f64| {
+        let data: Vec<f64> = (1..=n).map(|i| i as f64).collect();
+        Summarizer::new(&data).unwrap().percentile_interval(p, 0.95).unwrap()
+    };
+
+    // The ranks of the order statistics, from the binomial distribution.
+    assert_eq!(ranks(10, 0.5), (Some(2.0), Some(9.0)));
+    assert_eq!(ranks(100, 0.5), (Some(40.0), Some(61.0)));
+    assert_eq!(ranks(1000, 0.99), (Some(983.0), Some(997.0)));
+    assert_eq!(ranks(2000, 0.5), (Some(956.0), Some(1045.0)));
+
+    // Too few values bound the 99th percentile only from below.
+    assert_eq!(ranks(100, 0.99), (Some(97.0), None));
+    assert_eq!(ranks(3, 0.5), (None, None));
+
+    let s = Summarizer::new(&[1.0, 2.0, 3.0]).unwrap();
+    assert!(s.percentile_interval(1.5, 0.95).is_err());
+    assert!(s.percentile_interval(0.5, 1.0).is_err());
+}
+
+#[test]
+fn test_mean_ci() {
+    let data = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0, 6.0];
+    let (lower, upper) = Summary::new(&data).unwrap().mean_ci(0.95).unwrap();
+
+    // R: t.test(c(3, 1, 4, 1, 5, 9, 2, 6))$conf.int
+    assert_appx_eq!("Lower", 1e-6, 1.577300, lower);
+    assert_appx_eq!("Upper", 1e-6, 6.172700, upper);
+    assert_eq!(Summarizer::new(&data).unwrap().mean_ci(0.95).unwrap(), (lower, upper));
+
+    assert!(Summary::new(&[1.0]).unwrap().mean_ci(0.95).is_err());
+    assert!(Summary::new(&data).unwrap().mean_ci(1.0).is_err());
+}
+
+#[test]
+fn test_median_ci() {
+    let data: Vec<f64> = (1..=100).map(|i| i as f64).collect();
+    assert_eq!(Summarizer::new(&data).unwrap().median_ci(0.95).unwrap(), (40.0, 61.0));
+
+    assert!(Summarizer::new(&[1.0, 2.0, 3.0]).unwrap().median_ci(0.95).is_err());
+}
+
+#[test]
+fn test_pool() {
+    let shards = [
+        Summarizer::new(&[3.0, 1.0]).unwrap(),
+        Summarizer::new(&[2.0]).unwrap(),
+        Summarizer::new(&[5.0, 4.0, 0.5]).unwrap(),
+    ];
+
+    let pooled = Summarizer::pool(&shards).unwrap();
+    assert_eq!(pooled.as_slice(), &[0.5, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(pooled.median(), 2.5);
+
+    assert!(Summarizer::pool(&[]).is_err());
+}
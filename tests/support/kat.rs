@@ -160,7 +160,7 @@ macro_rules! assert_appx_eq {
         let err = format!("{}: {} and {} differ by {} > {}",
                           $name, $known, $actual, d, $tolerance);
 
-        assert!(d < $tolerance, err);
+        assert!(d < $tolerance, "{}", err);
     };
 }
 
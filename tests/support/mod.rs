@@ -3,10 +3,14 @@
 
 pub mod exe {
     use std::fs::File;
-    use std::process::{Command, Output};
+    use std::process::{Command, Output, Stdio};
 
     fn exe_cmd() -> Command {
-        Command::new("./target/debug/dent")
+        let mut cmd = Command::new("./target/debug/dent");
+
+        // Keep any configuration of the user running the tests out of them.
+        cmd.env("XDG_CONFIG_HOME", "tests/support/fixture/no_config");
+        cmd
     }
 
     pub fn run_with_stdin(f: File, args: &[&str]) -> Output {
@@ -25,6 +29,28 @@ pub mod exe {
             .output()
             .expect("Unable to run command in test")
     }
+
+    /// Run with stdout piped to a reader that has already stopped reading.
+    pub fn run_to_closed_pipe(args: &[&str]) -> Output {
+        let mut child = exe_cmd()
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .expect("Unable to run command in test");
+
+        drop(child.stdout.take());
+        child.wait_with_output().expect("Unable to run command in test")
+    }
+
+    pub fn run_with_env(vars: &[(&str, &str)], args: &[&str]) -> Output {
+        let mut cmd = exe_cmd();
+
+        cmd.envs(vars.iter().cloned())
+            .args(args)
+            .output()
+            .expect("Unable to run command in test")
+    }
 }
 
 pub mod fs {
@@ -83,6 +109,10 @@ pub mod assert {
         assert!(!output.status.success(), "Expected nonzero exit code");
     }
 
+    pub fn exit_code(output: &Output, code: i32) {
+        assert_eq!(output.status.code(), Some(code), "Expected exit code {}", code);
+    }
+
     pub fn stdout_eq_file(output: &Output, path: &str) {
         let stdout = String::from_utf8_lossy(&output.stdout);
         assert_eq!(stdout, fixture::read(path),
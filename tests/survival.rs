@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+extern crate dent;
+
+use dent::survival::{KaplanMeier, log_rank};
+
+
+/// Weeks of remission of patients with acute myelogenous leukemia, and
+/// whether they relapsed, from R's `survival::aml`.
+fn maintained() -> Vec<(f64, bool)> {
+    vec![
+        (9.0, true), (13.0, true), (13.0, false), (18.0, true), (23.0, true), (28.0, false),
+        (31.0, true), (34.0, true), (45.0, false), (48.0, true), (161.0, false),
+    ]
+}
+
+fn nonmaintained() -> Vec<(f64, bool)> {
+    vec![
+        (5.0, true), (5.0, true), (8.0, true), (8.0, true), (12.0, true), (16.0, false),
+        (23.0, true), (27.0, true), (30.0, true), (33.0, true), (43.0, true), (45.0, true),
+    ]
+}
+
+#[test]
+fn test_kaplan_meier() {
+    let km = KaplanMeier::new(&maintained(), 0.95).unwrap();
+
+    assert_eq!((km.size, km.events), (11, 7));
+    assert_eq!(km.steps.len(), 10);
+
+    let s = km.steps[1];
+    assert_eq!((s.time, s.at_risk, s.events, s.censored), (13.0, 10, 1, 1));
+    assert!((s.survival - 0.8181818).abs() < 1e-7);
+    assert!((s.lower - 0.6192490).abs() < 1e-7);
+    assert_eq!(s.upper, 1.0);
+
+    assert_eq!(km.survival(0.0), 1.0);
+    assert!((km.survival(30.0) - 0.6136364).abs() < 1e-7);
+    assert!((km.survival(1000.0) - 0.1840909).abs() < 1e-7);
+
+    assert_eq!(km.median, Some(31.0));
+    assert_eq!(km.median_lower, Some(18.0));
+    assert_eq!(km.median_upper, None);
+
+    // A lower level narrows the intervals.
+    let narrow = KaplanMeier::new(&maintained(), 0.8).unwrap();
+    assert_eq!(narrow.confidence, 0.8);
+    assert!(narrow.steps[1].lower > s.lower);
+}
+
+#[test]
+fn test_kaplan_meier_median() {
+    let km = KaplanMeier::new(&nonmaintained(), 0.95).unwrap();
+    assert_eq!((km.median, km.median_lower, km.median_upper), (Some(23.0), Some(8.0), None));
+
+    // The survival is exactly one half from 2 until 3.
+    let km = KaplanMeier::new(&[(1.0, true), (2.0, true), (3.0, true), (4.0, true)], 0.95).unwrap();
+    assert_eq!(km.median, Some(2.5));
+
+    // The bounds are undefined once no subject survives.
+    let last = km.steps[3];
+    assert_eq!(last.survival, 0.0);
+    assert!(last.lower.is_nan() && last.upper.is_nan());
+}
+
+#[test]
+fn test_log_rank() {
+    let test = log_rank(&maintained(), &nonmaintained()).unwrap();
+
+    assert!((test.chi_squared - 3.396389).abs() < 1e-6);
+    assert!((test.p - 0.06533932).abs() < 1e-7);
+    assert_eq!(test.observed, [7.0, 11.0]);
+    assert!((test.expected[0] - 10.68934).abs() < 1e-5);
+    assert!((test.expected[1] - 7.31066).abs() < 1e-5);
+}
+
+#[test]
+fn test_survival_errors() {
+    assert!(KaplanMeier::new(&[], 0.95).is_err());
+    assert!(KaplanMeier::new(&maintained(), 0.0).is_err());
+    assert!(KaplanMeier::new(&[(-1.0, true)], 0.95).is_err());
+    assert!(KaplanMeier::new(&[(f64::INFINITY, false)], 0.95).is_err());
+
+    // Without events, there is nothing to compare.
+    assert!(log_rank(&[(1.0, false)], &[(2.0, false)]).is_err());
+}
@@ -0,0 +1,104 @@
+extern crate dent;
+
+#[macro_use] mod support;
+
+use dent::summary::Summary;
+use dent::t_test::{Alternative, paired_t_test, pooled_sd, regression_t_test, student_t_test,
+                   t_critical, welch_t_test};
+
+
+#[test]
+fn test_paired_t_test() {
+    // Cushny and Peebles' hours of extra sleep for two drugs, from Student's
+    // 1908 paper, as in R's `sleep` data set.
+    let a = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+    let b = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+    let pairs: Vec<(f64, f64)> = a.iter().cloned().zip(b.iter().cloned()).collect();
+
+    let t_test = paired_t_test(&pairs).unwrap();
+
+    // Computed with R's `t.test(a, b, paired = TRUE)`.
+    assert_appx_eq!("t", 1e-6, -4.062128, t_test.t);
+    assert_appx_eq!("df", 1e-14, 9.0, t_test.df);
+    assert_appx_eq!("p", 1e-8, 0.002832890, t_test.p);
+}
+
+#[test]
+fn test_alternative() {
+    let a = [0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0];
+    let b = [1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4];
+    let (s1, s2) = (Summary::new(&a).unwrap(), Summary::new(&b).unwrap());
+
+    // Computed with R's `t.test(a, b, alternative = "less")`, whose
+    // alternative is that the mean of `a` is less: that of `b` is greater.
+    let t_test = welch_t_test(&s1, &s2).unwrap();
+    let greater = t_test.with_alternative(Alternative::Greater).unwrap();
+    assert_appx_eq!("t", 1e-4, -1.860813, greater.t);
+    assert_appx_eq!("p", 1e-6, 0.03969707, greater.p);
+
+    let less = t_test.with_alternative(Alternative::Less).unwrap();
+    assert_appx_eq!("p", 1e-6, 1.0 - 0.03969707, less.p);
+
+    let two_sided = t_test.with_alternative(Alternative::TwoSided).unwrap();
+    assert_eq!(t_test.p, two_sided.p);
+
+    // Computed with R's `t.test(a, b, paired = TRUE, alternative = "less")`.
+    let pairs: Vec<(f64, f64)> = a.iter().cloned().zip(b.iter().cloned()).collect();
+    let paired = paired_t_test(&pairs).unwrap().with_alternative(Alternative::Greater).unwrap();
+    assert_appx_eq!("p", 1e-8, 0.001416445, paired.p);
+
+    assert_eq!(Some(Alternative::Less), Alternative::parse("less"));
+    assert_eq!("two-sided", Alternative::TwoSided.name());
+    assert_eq!(None, Alternative::parse("both"));
+}
+
+#[test]
+fn test_paired_t_test_undefined() {
+    assert!(paired_t_test(&[]).is_err());
+    assert!(paired_t_test(&[(1.0, 2.0)]).is_err());
+}
+
+#[test]
+fn test_regression_t_test() {
+    // The sleep data again, as independent samples.
+    let a = Summary::new(&[0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0]).unwrap();
+    let b = Summary::new(&[1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4]).unwrap();
+
+    // Computed with R's `t.test(a, b, alternative = "less")`.
+    let t_test = regression_t_test(&a, &b, 0.0).unwrap();
+    assert_appx_eq!("t", 1e-4, -1.860813, t_test.t);
+    assert_appx_eq!("df", 1e-3, 17.77647, t_test.df);
+    assert_appx_eq!("p", 1e-6, 0.03969707, t_test.p);
+
+    // A mean of 2.33 is less than 300% more than one of 0.75.
+    let t_test = regression_t_test(&a, &b, 3.0).unwrap();
+    assert!(t_test.t > 0.0);
+    assert!(t_test.p > 0.5);
+}
+
+#[test]
+fn test_student_t_test() {
+    let a = Summary::new(&[0.7, -1.6, -0.2, -1.2, -0.1, 3.4, 3.7, 0.8, 0.0, 2.0]).unwrap();
+    let b = Summary::new(&[1.9, 0.8, 1.1, 0.1, -0.1, 4.4, 5.5, 1.6, 4.6, 3.4]).unwrap();
+
+    // Computed with R's `t.test(a, b, var.equal = TRUE)`.
+    let t_test = student_t_test(&a, &b).unwrap();
+    assert_appx_eq!("t", 1e-6, -1.860813, t_test.t);
+    assert_appx_eq!("df", 1e-14, 18.0, t_test.df);
+    assert_appx_eq!("p", 1e-6, 0.07918671, t_test.p);
+    assert_appx_eq!("pooled sd", 1e-6, 1.898625, pooled_sd(&a, &b));
+
+    let one = Summary::new(&[1.0]).unwrap();
+    assert!(student_t_test(&one, &one).is_err());
+}
+
+#[test]
+fn test_t_critical() {
+    // From R's `qt(0.975, df)`.
+    assert_appx_eq!("df 1", 1e-6, 12.706205, t_critical(0.95, 1.0).unwrap());
+    assert_appx_eq!("df 8", 1e-6, 2.306004, t_critical(0.95, 8.0).unwrap());
+    assert_appx_eq!("df 30", 1e-6, 2.042272, t_critical(0.95, 30.0).unwrap());
+
+    assert!(t_critical(1.0, 8.0).is_err());
+    assert!(t_critical(0.95, 0.0).is_err());
+}
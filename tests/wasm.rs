@@ -0,0 +1,35 @@
+#![cfg(feature = "wasm")]
+
+extern crate dent;
+
+use dent::summary;
+use dent::t_test;
+use dent::wasm::{Summary, paired_t_test, welch_t_test};
+
+
+const A: [f64; 6] = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0];
+const B: [f64; 6] = [2.0, 6.0, 5.0, 3.0, 5.0, 8.0];
+
+#[test]
+fn test_summary() {
+    let s = Summary::new(&A).unwrap();
+    let t = summary::Summary::new(&A).unwrap();
+
+    assert_eq!(s.size(), t.size());
+    assert_eq!(s.mean(), t.mean());
+    assert_eq!(s.lower_quartile(), t.lower_quartile());
+    assert_eq!(s.standard_error(), t.standard_error());
+}
+
+#[test]
+fn test_t_tests() {
+    let (a, b) = (Summary::new(&A).unwrap(), Summary::new(&B).unwrap());
+    let (s1, s2) = (summary::Summary::new(&A).unwrap(), summary::Summary::new(&B).unwrap());
+
+    let t = welch_t_test(&a, &b).unwrap();
+    assert_eq!(t.p, t_test::welch_t_test(&s1, &s2).unwrap().p);
+
+    let pairs = t_test::pairs(&A, &B).unwrap();
+    let t = paired_t_test(&A, &B).unwrap();
+    assert_eq!(t.t, t_test::paired_t_test(&pairs).unwrap().t);
+}